@@ -2,7 +2,12 @@
 use clap::{Parser, Subcommand};
 use pkroam_backend::{
     app_paths::get_app_paths,
-    cli_handlers::{handle_deposit, handle_list_mons, handle_list_saves, handle_withdraw},
+    cli_handlers::{
+        handle_deposit, handle_find_dupes, handle_free_slots, handle_list_boxes, handle_list_mons,
+        handle_list_saves, handle_move_mon, handle_reconnect_save, handle_release,
+        handle_rename_box, handle_repair_saves, handle_set_default_box, handle_update_save_path,
+        handle_withdraw, Format,
+    },
     database::DbConn,
     //logging,
 };
@@ -27,25 +32,103 @@ enum Commands {
         box_number: u8,
         #[arg(long)]
         box_position: u8,
+        /// Defaults to the box set with set-default-box
+        #[arg(long)]
+        dest_box: Option<u32>,
+        /// Defaults to the first free position in the destination box
+        #[arg(long)]
+        dest_position: Option<u32>,
+        /// Store the mon even if a copy of it is already stored
+        #[arg(long)]
+        allow_duplicates: bool,
+    },
+    ListSaves {
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Connect a disconnected save again, if its file still belongs to the same trainer
+    ReconnectSave {
+        #[arg(long)]
+        save: u32,
+    },
+    /// Point a save at its file after it has been moved
+    UpdateSavePath {
         #[arg(long)]
-        dest_box: u32,
+        save: u32,
         #[arg(long)]
-        dest_position: u32,
+        path: PathBuf,
     },
-    ListSaves,
+    /// Fix the section checksums of every connected save, backing up any save which changes
+    RepairSaves,
     ListMons {
         #[arg(long)]
         save: Option<u32>,
+        /// Number mons by the regional dex of their game instead of the national dex
+        #[arg(long)]
+        regional: bool,
+        /// Add a column with the moves each mon knows
+        #[arg(long)]
+        show_moves: bool,
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Show the roam storage box by box, including empty positions
+    ListBoxes {
+        #[arg(long = "box")]
+        box_number: Option<u32>,
+    },
+    /// List mons stored more than once, such as accidental clones
+    FindDupes,
+    /// Show which roam box positions are available to deposit into
+    FreeSlots {
+        #[arg(long = "box")]
+        box_number: Option<u32>,
+    },
+    SetDefaultBox {
+        #[arg(long)]
+        box_number: u32,
+    },
+    RenameBox {
+        #[arg(long)]
+        box_number: u32,
+        #[arg(long)]
+        name: String,
     },
     Withdraw {
+        #[arg(long, required_unless_present = "from_box")]
+        mon_id: Option<u64>,
+        /// Select the mon by its roam box instead of its id
+        #[arg(long, conflicts_with = "mon_id", requires = "from_position")]
+        from_box: Option<u32>,
+        #[arg(long, requires = "from_box")]
+        from_position: Option<u32>,
+        /// Defaults to the save the mon was deposited from
+        #[arg(long)]
+        save_id: Option<u32>,
+        #[arg(long, required_unless_present = "to_party", requires = "box_position")]
+        box_number: Option<u8>,
+        #[arg(long, requires = "box_number")]
+        box_position: Option<u8>,
+        /// Add the mon to the end of the party instead of a box
+        #[arg(long, conflicts_with = "box_number")]
+        to_party: bool,
+        /// Make the destination save's trainer the mon's OT
+        #[arg(long)]
+        rehome: bool,
+    },
+    /// Move a stored mon to an empty roam box position
+    MoveMon {
         #[arg(long)]
         mon_id: u64,
         #[arg(long)]
-        save_id: u32,
+        box_number: u32,
         #[arg(long)]
-        box_number: u8,
+        box_position: u32,
+    },
+    /// Permanently delete a mon from the roam storage
+    Release {
         #[arg(long)]
-        box_position: u8,
+        mon_id: u64,
     },
 }
 
@@ -55,7 +138,7 @@ fn main() -> anyhow::Result<()> {
 
     let app_paths = get_app_paths(args.config_dir)?;
     //logging::initialize(args.enable_debug, &app_paths.get_log_path())?;
-    let db_handle = DbConn::new(&app_paths.get_database_path())?;
+    let db_handle = DbConn::new(app_paths.get_database_path())?;
 
     match args.command {
         Commands::Deposit {
@@ -64,22 +147,55 @@ fn main() -> anyhow::Result<()> {
             box_position,
             dest_box,
             dest_position,
+            allow_duplicates,
         } => handle_deposit(
             db_handle,
             save,
-            box_number,
-            box_position,
+            (box_number, box_position),
             dest_box,
             dest_position,
+            allow_duplicates,
+            &app_paths.get_backup_path(),
         ),
-        Commands::ListSaves => handle_list_saves(db_handle),
-        Commands::ListMons { save } => handle_list_mons(db_handle, save),
+        Commands::ListSaves { format } => handle_list_saves(db_handle, format),
+        Commands::ReconnectSave { save } => handle_reconnect_save(db_handle, save),
+        Commands::UpdateSavePath { save, path } => handle_update_save_path(db_handle, save, path),
+        Commands::RepairSaves => handle_repair_saves(db_handle, &app_paths.get_backup_path()),
+        Commands::ListMons {
+            save,
+            regional,
+            show_moves,
+            format,
+        } => handle_list_mons(db_handle, save, regional, show_moves, format),
+        Commands::ListBoxes { box_number } => handle_list_boxes(db_handle, box_number),
+        Commands::FindDupes => handle_find_dupes(db_handle),
+        Commands::FreeSlots { box_number } => handle_free_slots(db_handle, box_number),
+        Commands::SetDefaultBox { box_number } => handle_set_default_box(db_handle, box_number),
+        Commands::RenameBox { box_number, name } => handle_rename_box(db_handle, box_number, name),
         Commands::Withdraw {
             mon_id,
+            from_box,
+            from_position,
             save_id,
             box_number,
             box_position,
-        } => handle_withdraw(db_handle, mon_id, save_id, box_number, box_position),
+            to_party: _,
+            rehome,
+        } => handle_withdraw(
+            db_handle,
+            mon_id,
+            from_box.zip(from_position),
+            save_id,
+            box_number.zip(box_position),
+            rehome,
+            &app_paths.get_backup_path(),
+        ),
+        Commands::MoveMon {
+            mon_id,
+            box_number,
+            box_position,
+        } => handle_move_mon(db_handle, mon_id, box_number, box_position),
+        Commands::Release { mon_id } => handle_release(db_handle, mon_id),
     }
     .map_err(|err| {
         eprintln!("Failed to execute command: {err}");
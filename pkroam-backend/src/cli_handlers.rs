@@ -1,40 +1,64 @@
 use crate::{
     database::DbConn,
-    types::{BoxLocation, DataFormat, MonsterData},
+    maintenance::{repair_save, RepairOutcome},
+    transfer::{withdraw_to_party, withdraw_to_save},
+    types::{BoxLocation, DataFormat, GameSaveData, MonListing, MonsterData, SaveListing},
+};
+use pkroam::save::GameCode;
+use prettytable::{format, row, Cell, Table};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
 };
-use prettytable::{format, row, Table};
 
 pub fn handle_deposit(
     mut db_handle: DbConn,
     save_id: u32,
-    box_number: u8,
-    box_position: u8,
-    dest_box: u32,
-    dest_position: u32,
+    (box_number, box_position): (u8, u8),
+    dest_box: Option<u32>,
+    dest_position: Option<u32>,
+    allow_duplicates: bool,
+    backup_dir: &Path,
 ) -> anyhow::Result<()> {
     let game_save = db_handle.get_save(save_id)?;
-    let mut save_file = pkroam::save::SaveFile::new(game_save.save_path.as_path())?;
-    let dest = BoxLocation::new(dest_box, dest_position, None)?;
+    let mut save_file = open_save(&game_save)?;
+    save_file.set_backup_dir(Some(backup_dir.to_path_buf()));
+    warn_if_blocks_inconsistent(&save_file);
+    let dest = db_handle.resolve_deposit_location(dest_box, dest_position)?;
     if let Some(pokemon) = save_file.take_pokemon_from_box(box_number, box_position)? {
+        let pk3_data = pokemon.to_pk3();
+        let mut mon = MonsterData::from_pk3(&pk3_data)?;
+        mon.source_save_id = Some(save_id);
+        if let Some(existing_id) = db_handle.find_stored_copy(&mon)? {
+            if !allow_duplicates {
+                // The save file hasn't been written yet, so the mon stays in its box
+                log::warn!(
+                    "This {} is already stored with ID: {existing_id}, pass --allow-duplicates to store it again",
+                    mon.species
+                );
+                return Ok(());
+            }
+            log::warn!(
+                "Storing another copy of the {} stored with ID: {existing_id}",
+                mon.species
+            );
+        }
         match save_file.write_in_place() {
-            Ok(()) => {
-                let pk3_data = pokemon.to_pk3();
-                match db_handle.insert_new_mon(&MonsterData::from_pk3(&pk3_data)?, dest) {
-                    Ok(pkmn_id) => {
-                        log::info!("Added with ID: {pkmn_id}");
-                    }
-                    Err(err) => {
-                        log::error!("Failed to insert mon into database: {err}");
-                        save_file
-                            .put_pokemon_in_box(box_number, box_position, &pk3_data, true)
-                            .map_err(|err| {
-                                log::error!("Failed to replace mon into save file: {err}");
-                                err
-                            })?;
-                        save_file.write_in_place()?;
-                    }
+            Ok(()) => match db_handle.insert_new_mon(&mon, dest) {
+                Ok(pkmn_id) => {
+                    log::info!("Added with ID: {pkmn_id}");
                 }
-            }
+                Err(err) => {
+                    log::error!("Failed to insert mon into database: {err}");
+                    save_file
+                        .put_pokemon_in_box(box_number, box_position, &pk3_data, true)
+                        .map_err(|err| {
+                            log::error!("Failed to replace mon into save file: {err}");
+                            err
+                        })?;
+                    save_file.write_in_place()?;
+                }
+            },
             Err(err) => {
                 log::error!("Unable to update save file: {err}");
             }
@@ -46,8 +70,25 @@ pub fn handle_deposit(
     Ok(())
 }
 
-pub fn handle_list_saves(db_handle: DbConn) -> anyhow::Result<()> {
-    let saves = db_handle.get_saves()?;
+/// How list commands print their results.
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+pub fn handle_list_saves(db_handle: DbConn, output_format: Format) -> anyhow::Result<()> {
+    let saves = db_handle
+        .get_saves()?
+        .iter()
+        .filter(|save| save.connected)
+        .map(SaveListing::from)
+        .collect::<Vec<_>>();
+    if output_format == Format::Json {
+        println!("{}", serde_json::to_string_pretty(&saves)?);
+        return Ok(());
+    }
+
     let mut table = Table::new();
     table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
     table.add_row(row![
@@ -59,14 +100,39 @@ pub fn handle_list_saves(db_handle: DbConn) -> anyhow::Result<()> {
         "PATH"
     ]);
 
-    for save in saves.iter().filter(|save| save.connected) {
+    for save in saves {
         table.add_row(row![
-            save.id.expect("Saves coming from the database have an id"),
+            save.id,
             save.game,
             save.trainer_name,
             save.trainer_id,
-            format!("{:02}:{:02}", save.playtime.hours, save.playtime.minutes),
+            format!("{:02}:{:02}", save.playtime_hours, save.playtime_minutes),
+            save.save_path,
+        ]);
+    }
+
+    table.printstd();
+    Ok(())
+}
+
+pub fn handle_repair_saves(db_handle: DbConn, backup_dir: &Path) -> anyhow::Result<()> {
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["ID", "PATH", "STATUS"]);
+
+    for save in db_handle.get_saves()?.iter().filter(|save| save.connected) {
+        let status = match repair_save(save, backup_dir) {
+            Ok(RepairOutcome::AlreadyValid) => "OK".to_string(),
+            Ok(RepairOutcome::Repaired { sections, backup }) => format!(
+                "Repaired {sections} sections, backup at {}",
+                backup.display()
+            ),
+            Err(err) => format!("Skipped: {err}"),
+        };
+        table.add_row(row![
+            save.id.expect("Saves coming from the database have an id"),
             save.save_path.display(),
+            status
         ]);
     }
 
@@ -74,86 +140,436 @@ pub fn handle_list_saves(db_handle: DbConn) -> anyhow::Result<()> {
     Ok(())
 }
 
-pub fn handle_list_mons(db_handle: DbConn, save_id: Option<u32>) -> anyhow::Result<()> {
-    if let Some(save_id) = save_id {
-        let game_save = db_handle.get_save(save_id)?;
-        let save_file = pkroam::save::SaveFile::new(game_save.save_path.as_path())?;
-        let mut table = Table::new();
-        table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
-        table.add_row(row!["BOX", "SLOT", "POKEMON"]);
+pub fn handle_list_mons(
+    db_handle: DbConn,
+    save_id: Option<u32>,
+    regional: bool,
+    show_moves: bool,
+    output_format: Format,
+) -> anyhow::Result<()> {
+    let mons = match save_id {
+        Some(save_id) => save_mon_listings(&db_handle, save_id, regional, show_moves)?,
+        // Default to check the roam boxes
+        None => roam_mon_listings(&db_handle, regional, show_moves)?,
+    };
+    if output_format == Format::Json {
+        println!("{}", serde_json::to_string_pretty(&mons)?);
+        return Ok(());
+    }
 
-        for (idx, pkmn) in save_file.get_party()?.iter().enumerate() {
-            table.add_row(row!["P", idx + 1, pkmn.species]);
+    let dex_header = if regional { "REGIONAL DEX" } else { "NATL DEX" };
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    if save_id.is_some() {
+        let mut header = row!["BOX", "SLOT", dex_header, "POKEMON", "LEVEL"];
+        if show_moves {
+            header.add_cell(Cell::new("MOVES"));
         }
+        table.add_row(header);
 
-        for box_number in 1..14 {
-            let box_pkmn = save_file.get_box(box_number).map_err(|err| {
-                log::error!("Failed to get Pokemon from box {box_number}: {err}");
-                err
-            })?;
-            for (position, pkmn) in box_pkmn {
-                table.add_row(row![box_number, position, pkmn.species]);
+        for mon in &mons {
+            let mut row = row![
+                mon.box_number
+                    .map(|box_number| box_number.to_string())
+                    .unwrap_or_else(|| "P".to_string()),
+                optional_label(mon.box_position),
+                optional_label(mon.dex_number),
+                mon.species,
+                mon.level
+            ];
+            if let Some(moves) = &mon.moves {
+                row.add_cell(Cell::new(&moves.join(", ")));
             }
+            table.add_row(row);
         }
-
-        table.printstd();
     } else {
-        // Default to check the roam boxes
-        let mons = db_handle.get_all_mons()?;
-        let mut table = Table::new();
-        table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
-        table.add_row(row!["ID", "NATL DEX", "POKEMON"]);
+        let mut header = row![
+            "ID",
+            "BOX",
+            "SLOT",
+            dex_header,
+            "POKEMON",
+            "LEVEL",
+            "SOURCE SAVE",
+            "STORED"
+        ];
+        if show_moves {
+            header.add_cell(Cell::new("MOVES"));
+        }
+        table.add_row(header);
 
-        for mon in mons.iter() {
-            if let DataFormat::PK3 = mon.data_format {
-                let pkmn = pkroam::pk3::Pokemon::from_pk3(&mon.data)?;
-                table.add_row(row![
-                    mon.id.expect("Monster data from database must have an id"),
-                    pkmn.species.national_dex_number()?,
-                    pkmn.species
-                ]);
+        for mon in &mons {
+            let box_label = match (&mon.box_name, mon.box_number) {
+                (Some(box_name), _) => box_name.clone(),
+                (None, Some(box_number)) => box_number.to_string(),
+                (None, None) => String::new(),
+            };
+            let mut row = row![
+                optional_label(mon.id),
+                box_label,
+                mon.box_position
+                    .map(|box_position| box_position.to_string())
+                    .unwrap_or_default(),
+                optional_label(mon.dex_number),
+                mon.species,
+                mon.level,
+                optional_label(mon.source_save_id),
+                match mon.days_stored {
+                    Some(1) => "1 day".to_string(),
+                    Some(days) => format!("{days} days"),
+                    None => "-".to_string(),
+                }
+            ];
+            if let Some(moves) = &mon.moves {
+                row.add_cell(Cell::new(&moves.join(", ")));
             }
+            table.add_row(row);
         }
-
-        table.printstd();
     }
+
+    table.printstd();
     Ok(())
 }
 
-pub fn handle_withdraw(
-    mut db_handle: DbConn,
-    monster_id: u64,
+/// The party, as box "P", followed by the boxes of a save.
+fn save_mon_listings(
+    db_handle: &DbConn,
     save_id: u32,
-    box_number: u8,
-    box_position: u8,
-) -> anyhow::Result<()> {
+    regional: bool,
+    show_moves: bool,
+) -> anyhow::Result<Vec<MonListing>> {
     let game_save = db_handle.get_save(save_id)?;
-    let mut save_file = pkroam::save::SaveFile::new(game_save.save_path.as_path())?;
-
-    match save_file.get_pokemon_from_box(box_number, box_position)? {
-        Some(_) => {
-            Err(anyhow::anyhow!("The selected save file has a pokemon in box {box_number} position {box_position} already"))
-        },
-        None => {
-            let (pkmn_data, location) = db_handle.withdraw_mon(monster_id)?;
-            let pkmn = pkroam::pk3::Pokemon::from_pk3(&pkmn_data.data)?;
-            let res = {
-                save_file.put_pokemon_in_box(box_number, box_position, &pkmn_data.data, false)?;
-            save_file.write_in_place()?;
-            Ok(())
+    let save_file = open_save(&game_save)?;
+    let game = regional.then(|| game_save.game.game_code());
+    let listing =
+        |box_number: Option<u32>, box_position: u32, pkmn: &pkroam::pk3::Pokemon| MonListing {
+            id: None,
+            box_number,
+            box_name: None,
+            box_position: Some(box_position),
+            dex_number: dex_number(&pkmn.species, game),
+            species: pkmn.species.to_string(),
+            level: pkmn.level(),
+            source_save_id: None,
+            days_stored: None,
+            moves: show_moves.then(|| move_names(pkmn)),
+        };
+
+    let mut mons = Vec::new();
+    for (idx, pkmn) in save_file.get_party()?.iter().enumerate() {
+        mons.push(listing(None, idx as u32 + 1, pkmn));
+    }
+    for box_number in 1..=pkroam::save::NUMBER_OF_BOXES {
+        let box_pkmn = save_file.get_box(box_number).map_err(|err| {
+            log::error!("Failed to get Pokemon from box {box_number}: {err}");
+            err
+        })?;
+        for (position, pkmn) in box_pkmn {
+            mons.push(listing(Some(box_number.into()), position.into(), &pkmn));
+        }
+    }
+    Ok(mons)
+}
+
+fn roam_mon_listings(
+    db_handle: &DbConn,
+    regional: bool,
+    show_moves: bool,
+) -> anyhow::Result<Vec<MonListing>> {
+    let entries = db_handle.get_box_entries()?;
+    let now = std::time::SystemTime::now();
+    let box_names = db_handle
+        .get_roam_box_names()?
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+    let mut mons = Vec::new();
+    for mon in db_handle.get_all_mons()? {
+        if let DataFormat::PK3 = mon.data_format {
+            let (_, level) = pkroam::pk3::peek(&mon.data)?;
+            let mon_id = mon.id.expect("Monster data from database must have an id");
+            let entry = entries
+                .iter()
+                .find(|entry| entry.monster_id() == Some(mon_id));
+            let game = match mon.source_save_id.filter(|_| regional) {
+                Some(save_id) => Some(db_handle.get_save(save_id)?.game.game_code()),
+                None => None,
             };
-            match res {
-                Ok(()) => log::info!("Withdrew {}", pkmn.species),
-                Err(err) => {
-                    log::error!("Failed to write mon into save file: {err:?}");
-                    let _ = db_handle.insert_new_mon(&pkmn_data, location).map_err(|err| {
-                        log::error!("Failed to replace mon in database: {err:?}");
-                        err
-                    });
-                    err
+            let moves = if show_moves {
+                let pkmn = pkroam::pk3::Pokemon::from_pk3(&mon.data)?;
+                Some(move_names(&pkmn))
+            } else {
+                None
+            };
+            mons.push(MonListing {
+                id: Some(mon_id),
+                box_number: entry.map(|entry| entry.box_number()),
+                box_name: entry.and_then(|entry| box_names.get(&entry.box_number()).cloned()),
+                box_position: entry.map(|entry| entry.box_position()),
+                dex_number: if regional && game.is_none() {
+                    None
+                } else {
+                    dex_number(&mon.species, game)
+                },
+                species: mon.species.to_string(),
+                level,
+                source_save_id: mon.source_save_id,
+                days_stored: mon.days_stored(now),
+                moves,
+            });
+        }
+    }
+    Ok(mons)
+}
+
+fn optional_label(value: Option<impl ToString>) -> String {
+    value
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Opens the file of a stored save, pointing the user at `update-save-path` if it has moved.
+fn open_save(game_save: &GameSaveData) -> anyhow::Result<pkroam::save::SaveFile> {
+    if !game_save.save_path.exists() {
+        let save_id = game_save.id.unwrap_or_default();
+        return Err(anyhow::anyhow!(
+            "Save file {} is missing, if it was moved run `update-save-path --save {save_id} --path <new path>`",
+            game_save.save_path.display()
+        ));
+    }
+    Ok(pkroam::save::SaveFile::new(game_save.save_path.as_path())?)
+}
+
+fn warn_if_blocks_inconsistent(save_file: &pkroam::save::SaveFile) {
+    if !save_file.blocks_consistent() {
+        log::warn!(
+            "The save blocks in this file disagree on the trainer, it may be merged or corrupt"
+        );
+    }
+}
+
+fn move_names(pkmn: &pkroam::pk3::Pokemon) -> Vec<String> {
+    pkmn.moves
+        .iter()
+        .filter(|move_id| **move_id != 0)
+        .map(|move_id| pkroam::pk3::moves::move_display_name(*move_id))
+        .collect()
+}
+
+/// The dex number of `species`, using the regional dex of `game` if one is given.
+fn dex_number(species: &pkroam::pk3::species::Species, game: Option<GameCode>) -> Option<u16> {
+    match game {
+        Some(game) => species.regional_dex_number(game),
+        None => species
+            .national_dex_number()
+            .ok()
+            .map(|number| number.get()),
+    }
+}
+
+pub fn handle_free_slots(db_handle: DbConn, box_number: Option<u32>) -> anyhow::Result<()> {
+    let free_slots = db_handle.get_free_slots()?;
+    let box_names = db_handle
+        .get_roam_box_names()?
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+    let box_numbers = match box_number {
+        Some(box_number) => vec![box_number],
+        None => (1..=db_handle.get_box_dimensions()?.box_count).collect(),
+    };
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["BOX", "FREE", "SLOTS"]);
+    for box_number in box_numbers {
+        let slots = free_slots
+            .iter()
+            .filter(|slot| slot.box_number() == box_number)
+            .map(|slot| slot.box_position().to_string())
+            .collect::<Vec<_>>();
+        table.add_row(row![
+            box_names
+                .get(&box_number)
+                .cloned()
+                .unwrap_or_else(|| box_number.to_string()),
+            slots.len(),
+            slots.join(" ")
+        ]);
+    }
+
+    table.printstd();
+    Ok(())
+}
+
+/// Prints the roam storage box by box, including the empty positions.
+pub fn handle_list_boxes(db_handle: DbConn, box_number: Option<u32>) -> anyhow::Result<()> {
+    let box_names = db_handle
+        .get_roam_box_names()?
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+    let box_numbers = match box_number {
+        Some(box_number) => vec![box_number],
+        None => (1..=db_handle.get_box_dimensions()?.box_count).collect(),
+    };
+
+    for box_number in box_numbers {
+        let slots = db_handle.get_roam_box(box_number)?;
+        let free_count = slots.iter().filter(|(_, mon)| mon.is_none()).count();
+        match box_names.get(&box_number) {
+            Some(name) => println!("Box {box_number}: {name} ({free_count} free)"),
+            None => println!("Box {box_number} ({free_count} free)"),
+        }
+
+        let mut table = Table::new();
+        table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+        table.add_row(row!["SLOT", "ID", "POKEMON", "LEVEL"]);
+        for (location, mon) in &slots {
+            match mon {
+                Some(mon) => {
+                    let level = match mon.data_format {
+                        DataFormat::PK3 => Some(pkroam::pk3::peek(&mon.data)?.1),
+                        DataFormat::PK4 => None,
+                    };
+                    table.add_row(row![
+                        location.box_position(),
+                        optional_label(mon.id),
+                        mon.species,
+                        optional_label(level)
+                    ]);
+                }
+                None => {
+                    table.add_row(row![location.box_position(), "-", "(empty)", "-"]);
                 }
             }
-            Ok(())
         }
+        table.printstd();
+    }
+    Ok(())
+}
+
+pub fn handle_find_dupes(db_handle: DbConn) -> anyhow::Result<()> {
+    let groups = db_handle.find_duplicate_mons()?;
+    if groups.is_empty() {
+        println!("No duplicate mons found");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["POKEMON", "OT ID", "COPIES", "IDS"]);
+    for group in groups {
+        let mon = db_handle.get_mon(group[0])?;
+        table.add_row(row![
+            mon.species,
+            format!("{:05}", mon.original_trainer_id),
+            group.len(),
+            group
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        ]);
     }
+
+    table.printstd();
+    Ok(())
+}
+
+pub fn handle_reconnect_save(db_handle: DbConn, save_id: u32) -> anyhow::Result<()> {
+    db_handle.set_save_connected(save_id)?;
+    log::info!("Reconnected save {save_id}");
+    Ok(())
+}
+
+pub fn handle_update_save_path(
+    db_handle: DbConn,
+    save_id: u32,
+    new_path: PathBuf,
+) -> anyhow::Result<()> {
+    db_handle.update_save_path(save_id, &new_path)?;
+    log::info!("Save {save_id} now points at {}", new_path.display());
+    Ok(())
+}
+
+pub fn handle_set_default_box(db_handle: DbConn, box_number: u32) -> anyhow::Result<()> {
+    db_handle.set_default_box(box_number)?;
+    log::info!("Deposits will default to roam box {box_number}");
+    Ok(())
+}
+
+pub fn handle_rename_box(db_handle: DbConn, box_number: u32, name: String) -> anyhow::Result<()> {
+    db_handle.set_roam_box_name(box_number, &name)?;
+    log::info!("Renamed roam box {box_number} to {name}");
+    Ok(())
+}
+
+pub fn handle_release(mut db_handle: DbConn, monster_id: u64) -> anyhow::Result<()> {
+    let mon = db_handle.release_mon(monster_id)?;
+    log::info!("Released {} (id {monster_id})", mon.species);
+    Ok(())
+}
+
+pub fn handle_move_mon(
+    mut db_handle: DbConn,
+    monster_id: u64,
+    box_number: u32,
+    box_position: u32,
+) -> anyhow::Result<()> {
+    let dimensions = db_handle.get_box_dimensions()?;
+    let dest = BoxLocation::new(box_number, box_position, None, &dimensions)?;
+    db_handle.move_mon(monster_id, dest)?;
+    log::info!("Moved mon {monster_id} to roam box {box_number} position {box_position}");
+    Ok(())
+}
+
+pub fn handle_withdraw(
+    mut db_handle: DbConn,
+    monster_id: Option<u64>,
+    from_location: Option<(u32, u32)>,
+    save_id: Option<u32>,
+    destination: Option<(u8, u8)>,
+    rehome: bool,
+    backup_dir: &Path,
+) -> anyhow::Result<()> {
+    let monster_id = match (monster_id, from_location) {
+        (Some(monster_id), _) => monster_id,
+        (None, Some((from_box, from_position))) => db_handle
+            .get_mon_at(from_box, from_position)?
+            .map(|(_, monster_id)| monster_id)
+            .ok_or_else(|| {
+                anyhow::anyhow!("No mon stored in roam box {from_box} position {from_position}")
+            })?,
+        (None, None) => return Err(anyhow::anyhow!("A mon id or roam box location is required")),
+    };
+    let save_id = match save_id {
+        Some(save_id) => save_id,
+        None => db_handle
+            .get_mon(monster_id)?
+            .source_save_id
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No source save recorded for mon {monster_id}, a save must be specified"
+                )
+            })?,
+    };
+    let game_save = db_handle.get_save(save_id)?;
+    let mut save_file = open_save(&game_save)?;
+    save_file.set_backup_dir(Some(backup_dir.to_path_buf()));
+    warn_if_blocks_inconsistent(&save_file);
+
+    let rehome_to = rehome.then_some(&game_save);
+    let pkmn_data = match destination {
+        Some((box_number, box_position)) => withdraw_to_save(
+            &mut db_handle,
+            monster_id,
+            &mut save_file,
+            box_number,
+            box_position,
+            rehome_to,
+        )?,
+        None => withdraw_to_party(&mut db_handle, monster_id, &mut save_file, rehome_to)?,
+    };
+    let pkmn = pkroam::pk3::Pokemon::from_pk3(&pkmn_data.data)?;
+    log::info!("Withdrew {}", pkmn.species);
+    Ok(())
 }
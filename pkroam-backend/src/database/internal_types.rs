@@ -15,6 +15,7 @@ pub struct Save {
     pub playtime_frames: u64,
     pub save_path: String,
     pub connected: u64,
+    pub trainer_name_data: Option<Vec<u8>>,
 }
 
 impl Save {
@@ -30,6 +31,7 @@ impl Save {
             playtime_frames: row.get(7)?,
             save_path: row.get(8)?,
             connected: row.get(9)?,
+            trainer_name_data: row.get(10)?,
         })
     }
 }
@@ -41,6 +43,7 @@ impl TryInto<crate::types::GameSaveData> for Save {
             id: Some(self.id),
             game: crate::types::Game::try_from(self.game)?,
             trainer_name: self.trainer_name,
+            trainer_name_data: self.trainer_name_data,
             trainer_id: self.trainer_id.try_into()?,
             secret_id: self.secret_id.try_into()?,
             playtime: crate::types::Playtime::new(
@@ -60,6 +63,7 @@ impl From<crate::types::GameSaveData> for Save {
             id: value.id.unwrap_or(0),
             game: value.game.into(),
             trainer_name: value.trainer_name,
+            trainer_name_data: value.trainer_name_data,
             trainer_id: value.trainer_id.into(),
             secret_id: value.secret_id.into(),
             playtime_hours: value.playtime.hours.into(),
@@ -79,6 +83,9 @@ pub struct Monster {
     pub personality_value: u64,
     pub data_format: u32,
     pub data: Vec<u8>,
+    pub source_save_id: Option<u64>,
+    pub deposited_at: Option<u64>,
+    pub species: Option<u16>,
 }
 
 impl Monster {
@@ -90,6 +97,9 @@ impl Monster {
             personality_value: row.get(3)?,
             data_format: row.get(4)?,
             data: row.get(5)?,
+            source_save_id: row.get(6)?,
+            deposited_at: row.get(7)?,
+            species: row.get(8)?,
         })
     }
 }
@@ -98,6 +108,10 @@ impl TryInto<crate::types::MonsterData> for Monster {
     type Error = anyhow::Error;
 
     fn try_into(self) -> Result<crate::types::MonsterData, Self::Error> {
+        let species = match self.species {
+            Some(species) => pkroam::pk3::species::Species::try_from(species)?,
+            None => pkroam::pk3::peek(&self.data)?.0,
+        };
         Ok(crate::types::MonsterData {
             id: Some(self.id),
             original_trainer_id: self.original_trainer_id.try_into()?,
@@ -105,6 +119,9 @@ impl TryInto<crate::types::MonsterData> for Monster {
             personality_value: self.personality_value.try_into()?,
             data_format: crate::types::DataFormat::try_from(self.data_format)?,
             data: self.data,
+            source_save_id: self.source_save_id.map(u32::try_from).transpose()?,
+            deposited_at: self.deposited_at,
+            species,
         })
     }
 }
@@ -118,6 +135,9 @@ impl From<crate::types::MonsterData> for Monster {
             personality_value: value.personality_value.into(),
             data_format: value.data_format.into(),
             data: value.data,
+            source_save_id: value.source_save_id.map(u64::from),
+            deposited_at: value.deposited_at,
+            species: Some(value.species as u16),
         }
     }
 }
@@ -139,10 +159,17 @@ impl BoxEntry {
     }
 }
 
-impl TryInto<crate::types::BoxLocation> for BoxEntry {
-    type Error = anyhow::Error;
-    fn try_into(self) -> Result<crate::types::BoxLocation, Self::Error> {
-        crate::types::BoxLocation::new(self.box_number, self.box_position, Some(self.monster_id))
+impl BoxEntry {
+    pub fn try_into_location(
+        self,
+        dimensions: &crate::types::BoxDimensions,
+    ) -> anyhow::Result<crate::types::BoxLocation> {
+        crate::types::BoxLocation::new(
+            self.box_number,
+            self.box_position,
+            Some(self.monster_id),
+            dimensions,
+        )
     }
 }
 
@@ -151,7 +178,7 @@ impl From<(crate::types::BoxLocation, u64)> for BoxEntry {
         Self {
             box_number: location.box_number(),
             box_position: location.box_position(),
-            monster_id: monster_id,
+            monster_id,
         }
     }
 }
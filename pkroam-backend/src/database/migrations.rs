@@ -6,6 +6,12 @@ pub fn perform_migration(
         1 => migrate_from_1_to_2(txn),
         2 => migrate_from_2_to_3(txn),
         3 => migrate_from_3_to_4(txn),
+        4 => migrate_from_4_to_5(txn),
+        5 => migrate_from_5_to_6(txn),
+        6 => migrate_from_6_to_7(txn),
+        7 => migrate_from_7_to_8(txn),
+        8 => migrate_from_8_to_9(txn),
+        9 => migrate_from_9_to_10(txn),
         ver => {
             log::error!("Request to migrate invalid database version {ver}");
             Err(rusqlite::Error::InvalidQuery)
@@ -13,6 +19,74 @@ pub fn perform_migration(
     }
 }
 
+fn migrate_from_9_to_10(txn: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    log::debug!("Beginning migration 9 to 10");
+    let _row_changed = txn.execute("ALTER TABLE saves ADD COLUMN trainer_name_data BLOB", ())?;
+    Ok(())
+}
+
+fn migrate_from_8_to_9(txn: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    log::debug!("Beginning migration 8 to 9");
+    let _row_changed = txn.execute("ALTER TABLE monsters ADD COLUMN species INTEGER", ())?;
+    let _ = txn.execute("CREATE INDEX monsters_species ON monsters (species)", ())?;
+
+    // Backfill from the stored data, rows which don't parse are left for reads to fall back on
+    let mons = txn
+        .prepare("SELECT id, data FROM monsters")?
+        .query_map((), |row| {
+            Ok((row.get::<_, u64>(0)?, row.get::<_, Vec<u8>>(1)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    for (id, data) in mons {
+        match pkroam::pk3::peek(&data) {
+            Ok((species, _)) => {
+                let _row_changed = txn.execute(
+                    "UPDATE monsters SET species = ?1 WHERE id = ?2",
+                    (species as u16, id),
+                )?;
+            }
+            Err(err) => log::warn!("Could not determine the species of mon {id}: {err}"),
+        }
+    }
+    Ok(())
+}
+
+fn migrate_from_7_to_8(txn: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    log::debug!("Beginning migration 7 to 8");
+    let _row_changed = txn.execute("ALTER TABLE monsters ADD COLUMN deposited_at INTEGER", ())?;
+    Ok(())
+}
+
+fn migrate_from_6_to_7(txn: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    log::debug!("Beginning migration 6 to 7");
+    let _ = txn.execute(
+        "CREATE TABLE settings (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        )",
+        (),
+    )?;
+    Ok(())
+}
+
+fn migrate_from_5_to_6(txn: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    log::debug!("Beginning migration 5 to 6");
+    let _row_changed = txn.execute("ALTER TABLE monsters ADD COLUMN source_save_id INTEGER", ())?;
+    Ok(())
+}
+
+fn migrate_from_4_to_5(txn: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    log::debug!("Beginning migration 4 to 5");
+    let _ = txn.execute(
+        "CREATE TABLE box_names (
+            box_number INTEGER PRIMARY KEY,
+            name TEXT NOT NULL
+        )",
+        (),
+    )?;
+    Ok(())
+}
+
 fn migrate_from_3_to_4(txn: &rusqlite::Transaction) -> rusqlite::Result<()> {
     log::debug!("Beginning migration 3 to 4");
     let _ = txn.execute(
@@ -1,12 +1,21 @@
-use crate::types::{BoxLocation, GameSaveData, MonsterData};
-use rusqlite::Connection;
-use std::path::Path;
+use crate::types::{
+    BoxDimensions, BoxLocation, GameSaveData, MergeSummary, MonsterData, MonsterExport,
+};
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::Path,
+};
 
 mod internal_types;
 mod migrations;
 mod statements;
 
-const CURRENT_DATABASE_SCHEMA_VERSION: i32 = 4;
+const CURRENT_DATABASE_SCHEMA_VERSION: i32 = 10;
+
+const SETTING_ROAM_BOX_COUNT: &str = "roam_box_count";
+const SETTING_ROAM_BOX_SIZE: &str = "roam_box_size";
+const SETTING_DEFAULT_BOX: &str = "default_box";
 
 pub struct DbConn {
     conn: Connection,
@@ -15,6 +24,8 @@ pub struct DbConn {
 impl DbConn {
     pub fn new(db_path: impl AsRef<Path>) -> anyhow::Result<Self> {
         let conn = Connection::open(db_path)?;
+        // Box entries rely on the cascade to be removed along with their mon
+        conn.pragma_update(None, "foreign_keys", true)?;
         let schema_version = get_schema_version(&conn)?;
         log::debug!("Schema version at start: {schema_version}");
 
@@ -36,7 +47,10 @@ impl DbConn {
         self.with_transaction(|txn| {
             txn.execute(statements::CREATE_TABLE_SAVES, ())?;
             txn.execute(statements::CREATE_TABLE_ROAM_POKEMON, ())?;
+            txn.execute(statements::CREATE_INDEX_MONSTERS_SPECIES, ())?;
             txn.execute(statements::CREATE_TABLE_BOX_ENTRIES, ())?;
+            txn.execute(statements::CREATE_TABLE_BOX_NAMES, ())?;
+            txn.execute(statements::CREATE_TABLE_SETTINGS, ())?;
 
             set_schema_version(txn, CURRENT_DATABASE_SCHEMA_VERSION)?;
             Ok(())
@@ -103,6 +117,7 @@ impl DbConn {
                 &save.playtime_frames,
                 &save.save_path,
                 &save.connected,
+                &save.trainer_name_data,
             ),
         )?;
         Ok(())
@@ -115,46 +130,453 @@ impl DbConn {
         Ok(())
     }
 
+    /// Marks a save as connected again after checking its file is still there and belongs to
+    /// the same trainer.
+    pub fn set_save_connected(&self, save_id: u32) -> anyhow::Result<()> {
+        let save = self.get_save(save_id)?;
+        verify_save_trainer(&save, &save.save_path)?;
+        let _rows_changed = self
+            .conn
+            .execute(statements::UPDATE_SAVE_CONNECTED, (1, save_id))?;
+        Ok(())
+    }
+
+    /// Points a save at its file's new location. The file must belong to the same trainer so
+    /// the record can't be pointed at another trainer's save.
+    pub fn update_save_path(&self, save_id: u32, new_path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let save = self.get_save(save_id)?;
+        let new_path = new_path.as_ref();
+        verify_save_trainer(&save, new_path)?;
+        let _rows_changed = self.conn.execute(
+            statements::UPDATE_SAVE_PATH,
+            (new_path.to_string_lossy(), save_id),
+        )?;
+        Ok(())
+    }
+
     pub fn insert_new_mon(
         &mut self,
         mon: &MonsterData,
         location: BoxLocation,
     ) -> anyhow::Result<u64> {
+        self.with_transaction(|txn| insert_mon(txn, mon, location))
+    }
+
+    /// Puts a previously withdrawn mon back under its original id and location.
+    pub fn restore_mon(&mut self, mon: &MonsterData, location: BoxLocation) -> anyhow::Result<()> {
+        let id = mon
+            .id
+            .ok_or_else(|| anyhow::anyhow!("Only mons from the database can be restored"))?;
         let mon = internal_types::Monster::from(mon.clone());
         self.with_transaction(|txn| {
             let _rows_changed = txn.execute(
-                statements::INSERT_MON_INTO_MONS,
+                statements::INSERT_MON_WITH_ID_INTO_MONS,
                 (
+                    &mon.id,
                     &mon.original_trainer_id,
                     &mon.original_secret_id,
                     &mon.personality_value,
                     &mon.data_format,
                     mon.data.as_slice(),
+                    &mon.source_save_id,
+                    &mon.deposited_at,
+                    &mon.species,
                 ),
             )?;
-            let row_id = txn.last_insert_rowid();
-            let location = internal_types::BoxEntry::from((location.clone(), row_id as u64));
             let _ = txn.execute(
                 statements::INSERT_BOX_ENTRY,
-                (
-                    location.box_number,
-                    location.box_position,
-                    location.monster_id,
-                ),
+                (location.box_number(), location.box_position(), id),
             )?;
-            Ok(row_id as u64)
+            Ok(())
         })
     }
 
+    /// Copies the mons of the database at `other` into free box slots of this one, skipping
+    /// any mon which is already stored here. The other database is only read, so it must
+    /// exist and be at the current schema version.
+    pub fn merge_from(&mut self, other: &Path) -> anyhow::Result<MergeSummary> {
+        if !other.exists() {
+            return Err(anyhow::anyhow!(
+                "No database to merge at {}",
+                other.display()
+            ));
+        }
+        let other_conn = Connection::open_with_flags(other, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        let other_version = get_schema_version(&other_conn)?;
+        if other_version != CURRENT_DATABASE_SCHEMA_VERSION {
+            return Err(anyhow::anyhow!(
+                "Database at {} has schema version {other_version}, expected {CURRENT_DATABASE_SCHEMA_VERSION}",
+                other.display()
+            ));
+        }
+
+        let other_mons = select_all_mons(&other_conn)?;
+        let summary = self.store_new_mons(other_mons)?;
+        log::info!(
+            "Merged {} mons from {}, skipped {} duplicates",
+            summary.merged,
+            other.display(),
+            summary.skipped_duplicates
+        );
+        Ok(summary)
+    }
+
+    /// Serializes every stored mon to JSON for moving the collection to another database.
+    pub fn export_all(&self) -> anyhow::Result<String> {
+        let exported = self
+            .get_all_mons()?
+            .iter()
+            .map(MonsterExport::from)
+            .collect::<Vec<_>>();
+        Ok(serde_json::to_string_pretty(&exported)?)
+    }
+
+    /// Adds the mons of a JSON export into free box slots, skipping any mon which is already
+    /// stored. Nothing is imported if any of the mons fails validation.
+    pub fn import_all(&mut self, json: &str) -> anyhow::Result<MergeSummary> {
+        let mons = serde_json::from_str::<Vec<MonsterExport>>(json)?
+            .into_iter()
+            .enumerate()
+            .map(|(idx, exported)| {
+                MonsterData::try_from(exported)
+                    .map_err(|err| anyhow::anyhow!("Exported mon {idx} is invalid: {err}"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let summary = self.store_new_mons(mons)?;
+        log::info!(
+            "Imported {} mons, skipped {} duplicates",
+            summary.merged,
+            summary.skipped_duplicates
+        );
+        Ok(summary)
+    }
+
+    fn store_new_mons(&mut self, mons: Vec<MonsterData>) -> anyhow::Result<MergeSummary> {
+        let mut known_mons = self
+            .get_all_mons()?
+            .iter()
+            .map(MonsterData::fingerprint)
+            .collect::<HashSet<_>>();
+        let mut free_slots = self.get_free_slots()?.into_iter();
+
+        let mut summary = MergeSummary::default();
+        let mut to_insert = vec![];
+        for mut mon in mons {
+            if !known_mons.insert(mon.fingerprint()) {
+                summary.skipped_duplicates += 1;
+                continue;
+            }
+            let location = free_slots
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("Not enough free box slots for the new mons"))?;
+            // Save ids are local to the other database
+            mon.id = None;
+            mon.source_save_id = None;
+            to_insert.push((mon, location));
+            summary.merged += 1;
+        }
+
+        self.with_transaction(|txn| {
+            for (mon, location) in to_insert {
+                insert_mon(txn, &mon, location)?;
+            }
+            Ok(())
+        })?;
+        Ok(summary)
+    }
+
+    /// Rows which can't be converted, such as those with an unknown data format, are logged and
+    /// left out rather than failing the whole listing.
     pub fn get_all_mons(&self) -> anyhow::Result<Vec<MonsterData>> {
-        let mut stmt = self.conn.prepare(statements::SELECT_ALL_MONS)?;
-        let mons = stmt
-            .query_map([], internal_types::Monster::from_row)?
+        select_all_mons(&self.conn)
+    }
+
+    /// The id of a stored mon with the same fingerprint as `mon`, if there is one.
+    pub fn find_stored_copy(&self, mon: &MonsterData) -> anyhow::Result<Option<u64>> {
+        let (personality_value, original_trainer_id, original_secret_id) = mon.fingerprint();
+        Ok(self
+            .conn
+            .query_row(
+                statements::SELECT_MON_ID_WITH_FINGERPRINT,
+                (personality_value, original_trainer_id, original_secret_id),
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Groups the ids of mons which share a fingerprint, such as accidental clones. Only groups of
+    /// more than one mon are returned.
+    pub fn find_duplicate_mons(&self) -> anyhow::Result<Vec<Vec<u64>>> {
+        let mut stmt = self.conn.prepare(statements::SELECT_DUPLICATE_MONS)?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, u64>(0)?,
+                    (
+                        row.get::<_, u64>(1)?,
+                        row.get::<_, u64>(2)?,
+                        row.get::<_, u64>(3)?,
+                    ),
+                ))
+            })?
             .collect::<rusqlite::Result<Vec<_>>>()?;
-        mons.into_iter().map(|mon| mon.try_into()).collect()
+
+        let mut groups: Vec<Vec<u64>> = vec![];
+        let mut last_fingerprint = None;
+        for (id, fingerprint) in rows {
+            match groups.last_mut() {
+                Some(group) if last_fingerprint == Some(fingerprint) => group.push(id),
+                _ => groups.push(vec![id]),
+            }
+            last_fingerprint = Some(fingerprint);
+        }
+        Ok(groups)
+    }
+
+    /// Lists each distinct original trainer in the collection as (trainer id, secret id, OT
+    /// name, number of mons). The name is parsed from one of the trainer's mons.
+    pub fn list_trainers(&self) -> anyhow::Result<Vec<(u32, u32, String, u64)>> {
+        let mut trainers = BTreeMap::<(u32, u32), (String, u64)>::new();
+        for mon in self.get_all_mons()? {
+            let (name, count) = trainers
+                .entry((mon.original_trainer_id, mon.original_secret_id))
+                .or_default();
+            if name.is_empty() {
+                if let Ok(pkmn) = pkroam::pk3::Pokemon::from_pk3(&mon.data) {
+                    *name = pkmn.original_trainer_name;
+                }
+            }
+            *count += 1;
+        }
+
+        Ok(trainers
+            .into_iter()
+            .map(|((trainer_id, secret_id), (name, count))| (trainer_id, secret_id, name, count))
+            .collect())
+    }
+
+    pub fn get_mon(&self, id: u64) -> anyhow::Result<MonsterData> {
+        self.conn
+            .query_row_and_then(
+                statements::SELECT_MON_WITH_ID,
+                (id,),
+                internal_types::Monster::from_row,
+            )?
+            .try_into()
+    }
+
+    /// Looks up the mon stored at a roam box location, along with its id.
+    pub fn get_mon_at(
+        &self,
+        box_number: u32,
+        box_position: u32,
+    ) -> anyhow::Result<Option<(MonsterData, u64)>> {
+        let mon = self
+            .conn
+            .query_row(
+                statements::SELECT_MON_AT_BOX_LOCATION,
+                (box_number, box_position),
+                internal_types::Monster::from_row,
+            )
+            .optional()?;
+        mon.map(|mon| {
+            let id = mon.id;
+            Ok((mon.try_into()?, id))
+        })
+        .transpose()
+    }
+
+    pub fn get_box_entries(&self) -> anyhow::Result<Vec<BoxLocation>> {
+        let dimensions = self.get_box_dimensions()?;
+        let mut stmt = self.conn.prepare(statements::SELECT_ALL_BOX_ENTRIES)?;
+        let entries = stmt
+            .query_map([], internal_types::BoxEntry::from_row)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        entries
+            .into_iter()
+            .map(|entry| entry.try_into_location(&dimensions))
+            .collect()
+    }
+
+    /// Every position of a roam box in order, along with the mon stored there if any. Mons
+    /// which can't be read are logged and their position shown as empty.
+    pub fn get_roam_box(
+        &self,
+        box_number: u32,
+    ) -> anyhow::Result<Vec<(BoxLocation, Option<MonsterData>)>> {
+        let dimensions = self.get_box_dimensions()?;
+        let mut stmt = self.conn.prepare(statements::SELECT_MONS_IN_BOX)?;
+        let mut mons = stmt
+            .query_map((box_number,), |row| {
+                Ok((
+                    row.get::<_, u32>(9)?,
+                    internal_types::Monster::from_row(row)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<BTreeMap<_, _>>>()?;
+
+        (1..=dimensions.box_size)
+            .map(|box_position| {
+                let mon: Option<MonsterData> = mons.remove(&box_position).and_then(|mon| {
+                    let mon_id = mon.id;
+                    mon.try_into()
+                        .map_err(|err| {
+                            log::warn!("Skipping mon {mon_id} which can't be read: {err}")
+                        })
+                        .ok()
+                });
+                let location = BoxLocation::new(
+                    box_number,
+                    box_position,
+                    mon.as_ref().and_then(|mon| mon.id),
+                    &dimensions,
+                )?;
+                Ok((location, mon))
+            })
+            .collect()
+    }
+
+    /// All unoccupied locations in the roam storage, in box order.
+    pub fn get_free_slots(&self) -> anyhow::Result<Vec<BoxLocation>> {
+        let dimensions = self.get_box_dimensions()?;
+        let occupied = self
+            .get_box_entries()?
+            .iter()
+            .map(|entry| (entry.box_number(), entry.box_position()))
+            .collect::<HashSet<_>>();
+        (1..=dimensions.box_count)
+            .flat_map(|box_number| {
+                (1..=dimensions.box_size).map(move |box_position| (box_number, box_position))
+            })
+            .filter(|slot| !occupied.contains(slot))
+            .map(|(box_number, box_position)| {
+                BoxLocation::new(box_number, box_position, None, &dimensions)
+            })
+            .collect()
+    }
+
+    /// Reads a raw value from the settings table. Prefer the typed getters for known settings.
+    pub fn get_setting(&self, key: &str) -> anyhow::Result<Option<String>> {
+        Ok(read_setting(&self.conn, key)?)
+    }
+
+    pub fn set_setting(&self, key: &str, value: &str) -> anyhow::Result<()> {
+        Ok(write_setting(&self.conn, key, value)?)
+    }
+
+    pub fn get_box_dimensions(&self) -> anyhow::Result<BoxDimensions> {
+        let defaults = BoxDimensions::default();
+        let box_count = self.get_setting(SETTING_ROAM_BOX_COUNT)?;
+        let box_size = self.get_setting(SETTING_ROAM_BOX_SIZE)?;
+        Ok(BoxDimensions {
+            box_count: box_count
+                .map(|count| count.parse())
+                .transpose()?
+                .unwrap_or(defaults.box_count),
+            box_size: box_size
+                .map(|size| size.parse())
+                .transpose()?
+                .unwrap_or(defaults.box_size),
+        })
+    }
+
+    /// Resizes the roam storage. Fails if any stored mon would fall outside of the new dimensions.
+    pub fn set_box_dimensions(&mut self, dimensions: BoxDimensions) -> anyhow::Result<()> {
+        if dimensions.box_count == 0 || dimensions.box_size == 0 {
+            return Err(anyhow::anyhow!(
+                "Box dimensions must be non-zero, got {dimensions:?}"
+            ));
+        }
+
+        self.with_transaction(|txn| {
+            let (max_box_number, max_box_position): (Option<u32>, Option<u32>) =
+                txn.query_row(statements::SELECT_MAX_BOX_ENTRY_EXTENTS, (), |row| {
+                    Ok((row.get(0)?, row.get(1)?))
+                })?;
+            if max_box_number.unwrap_or(0) > dimensions.box_count
+                || max_box_position.unwrap_or(0) > dimensions.box_size
+            {
+                return Err(anyhow::anyhow!(
+                    "Stored mons are located outside of the requested dimensions {dimensions:?}"
+                ));
+            }
+
+            write_setting(
+                txn,
+                SETTING_ROAM_BOX_COUNT,
+                &dimensions.box_count.to_string(),
+            )?;
+            write_setting(txn, SETTING_ROAM_BOX_SIZE, &dimensions.box_size.to_string())?;
+            Ok(())
+        })
+    }
+
+    pub fn get_default_box(&self) -> anyhow::Result<Option<u32>> {
+        Ok(self
+            .get_setting(SETTING_DEFAULT_BOX)?
+            .map(|box_number| box_number.parse())
+            .transpose()?)
+    }
+
+    pub fn set_default_box(&self, box_number: u32) -> anyhow::Result<()> {
+        self.check_box_number(box_number)?;
+        self.set_setting(SETTING_DEFAULT_BOX, &box_number.to_string())
+    }
+
+    /// Picks where a deposit should go, filling in the first free slot of the requested box (or
+    /// the default box) when no position is given.
+    pub fn resolve_deposit_location(
+        &self,
+        dest_box: Option<u32>,
+        dest_position: Option<u32>,
+    ) -> anyhow::Result<BoxLocation> {
+        let dest_box = match dest_box {
+            Some(dest_box) => dest_box,
+            None => self.get_default_box()?.ok_or_else(|| {
+                anyhow::anyhow!("No destination box given and no default box is set")
+            })?,
+        };
+        self.check_box_number(dest_box)?;
+        match dest_position {
+            Some(dest_position) => {
+                BoxLocation::new(dest_box, dest_position, None, &self.get_box_dimensions()?)
+            }
+            None => self
+                .get_free_slots()?
+                .into_iter()
+                .find(|slot| slot.box_number() == dest_box)
+                .ok_or_else(|| anyhow::anyhow!("Roam box {dest_box} is full")),
+        }
+    }
+
+    pub fn set_roam_box_name(&self, box_number: u32, name: &str) -> anyhow::Result<()> {
+        self.check_box_number(box_number)?;
+        let _rows_changed = self
+            .conn
+            .execute(statements::INSERT_OR_REPLACE_BOX_NAME, (box_number, name))?;
+        Ok(())
+    }
+
+    fn check_box_number(&self, box_number: u32) -> anyhow::Result<()> {
+        let box_count = self.get_box_dimensions()?.box_count;
+        if box_number == 0 || box_number > box_count {
+            return Err(anyhow::anyhow!(
+                "Invalid box number {box_number}, must be in [1, {box_count}]"
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn get_roam_box_names(&self) -> anyhow::Result<Vec<(u32, String)>> {
+        let mut stmt = self.conn.prepare(statements::SELECT_BOX_NAMES)?;
+        let names = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(names)
     }
 
     pub fn withdraw_mon(&mut self, id: u64) -> anyhow::Result<(MonsterData, BoxLocation)> {
+        let dimensions = self.get_box_dimensions()?;
         let (monster, entry) = self.with_transaction(|txn| {
             let monster = txn.query_row_and_then(
                 statements::SELECT_MON_WITH_ID,
@@ -170,8 +592,153 @@ impl DbConn {
             Ok((monster, entry))
         })?;
 
-        Ok((monster.try_into()?, entry.try_into()?))
+        Ok((monster.try_into()?, entry.try_into_location(&dimensions)?))
+    }
+
+    /// Moves a stored mon to another roam box position, which must be empty.
+    pub fn move_mon(&mut self, id: u64, dest: BoxLocation) -> anyhow::Result<()> {
+        self.with_transaction(|txn| {
+            let _current = txn
+                .query_row_and_then(
+                    statements::SELECT_BOX_ENTRY_WITH_MONSTER_ID,
+                    (id,),
+                    internal_types::BoxEntry::from_row,
+                )
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("No mon with id {id} is stored"))?;
+            let occupant = txn
+                .query_row_and_then(
+                    statements::SELECT_BOX_ENTRY_AT_LOCATION,
+                    (dest.box_number(), dest.box_position()),
+                    internal_types::BoxEntry::from_row,
+                )
+                .optional()?;
+            match occupant {
+                Some(occupant) if occupant.monster_id == id => return Ok(()),
+                Some(occupant) => {
+                    return Err(anyhow::anyhow!(
+                        "Roam box {} position {} is already occupied by mon {}",
+                        dest.box_number(),
+                        dest.box_position(),
+                        occupant.monster_id
+                    ))
+                }
+                None => {}
+            }
+            let _rows_changed = txn.execute(
+                statements::UPDATE_BOX_ENTRY_LOCATION,
+                (dest.box_number(), dest.box_position(), id),
+            )?;
+            Ok(())
+        })
     }
+
+    /// Permanently deletes a mon from the roam storage along with its box entry.
+    pub fn release_mon(&mut self, id: u64) -> anyhow::Result<MonsterData> {
+        let monster = self.with_transaction(|txn| {
+            let monster = txn.query_row_and_then(
+                statements::SELECT_MON_WITH_ID,
+                (id,),
+                internal_types::Monster::from_row,
+            )?;
+            let _rows_changed = txn.execute(statements::DELETE_MON_WITH_ID, (id,))?;
+            Ok(monster)
+        })?;
+
+        monster.try_into()
+    }
+}
+
+/// Checks that the save file at `path` is readable and has the same trainer as `save`.
+fn verify_save_trainer(save: &GameSaveData, path: &Path) -> anyhow::Result<()> {
+    if !path.exists() {
+        return Err(anyhow::anyhow!(
+            "Save file {} does not exist",
+            path.display()
+        ));
+    }
+    let trainer = pkroam::save::SaveFile::new(path)?.get_trainer_info();
+    if (
+        u32::from(trainer.id.public_id),
+        u32::from(trainer.id.secret_id),
+    ) != (save.trainer_id, save.secret_id)
+    {
+        return Err(anyhow::anyhow!(
+            "Save file {} belongs to trainer {} ({}), expected {} ({})",
+            path.display(),
+            trainer.player_name,
+            trainer.id.public_id,
+            save.trainer_name,
+            save.trainer_id
+        ));
+    }
+    Ok(())
+}
+
+fn insert_mon(
+    txn: &rusqlite::Transaction,
+    mon: &MonsterData,
+    location: BoxLocation,
+) -> anyhow::Result<u64> {
+    let mon = internal_types::Monster::from(mon.clone());
+    let deposited_at = mon.deposited_at.unwrap_or_else(unix_time_now);
+    let _rows_changed = txn.execute(
+        statements::INSERT_MON_INTO_MONS,
+        (
+            &mon.original_trainer_id,
+            &mon.original_secret_id,
+            &mon.personality_value,
+            &mon.data_format,
+            mon.data.as_slice(),
+            &mon.source_save_id,
+            deposited_at,
+            &mon.species,
+        ),
+    )?;
+    let row_id = txn.last_insert_rowid();
+    let location = internal_types::BoxEntry::from((location, row_id as u64));
+    let _ = txn.execute(
+        statements::INSERT_BOX_ENTRY,
+        (
+            location.box_number,
+            location.box_position,
+            location.monster_id,
+        ),
+    )?;
+    Ok(row_id as u64)
+}
+
+fn unix_time_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+fn read_setting(conn: &Connection, key: &str) -> rusqlite::Result<Option<String>> {
+    conn.query_row(statements::SELECT_SETTING, (key,), |row| row.get(0))
+        .optional()
+}
+
+fn write_setting(conn: &Connection, key: &str, value: &str) -> rusqlite::Result<()> {
+    let _rows_changed = conn.execute(statements::INSERT_OR_REPLACE_SETTING, (key, value))?;
+    Ok(())
+}
+
+fn select_all_mons(conn: &Connection) -> anyhow::Result<Vec<MonsterData>> {
+    let mut stmt = conn.prepare(statements::SELECT_ALL_MONS)?;
+    let mons = stmt
+        .query_map([], internal_types::Monster::from_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(mons
+        .into_iter()
+        .filter_map(|mon| {
+            let mon_id = mon.id;
+            mon.try_into()
+                .map_err(|err| log::warn!("Skipping mon {mon_id} which can't be read: {err}"))
+                .ok()
+        })
+        .collect())
 }
 
 fn get_schema_version(conn: &Connection) -> rusqlite::Result<i32> {
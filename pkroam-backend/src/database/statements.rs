@@ -8,41 +8,76 @@ pub const CREATE_TABLE_SAVES: &str = "CREATE TABLE saves (
     playtime_minutes INTEGER,
     playtime_frames INTEGER,
     save_path TEXT NOT NULL,
-    connected INTEGER
+    connected INTEGER,
+    trainer_name_data BLOB
 )";
 
 pub const SELECT_SAVES: &str =
-    "SELECT id, game, trainer_name, trainer_id, secret_id, playtime_hours, playtime_minutes, playtime_frames, save_path, connected FROM saves";
+    "SELECT id, game, trainer_name, trainer_id, secret_id, playtime_hours, playtime_minutes, playtime_frames, save_path, connected, trainer_name_data FROM saves";
 
 pub const SELECT_SAVE: &str =
-    "SELECT id, game, trainer_name, trainer_id, secret_id, playtime_hours, playtime_minutes, playtime_frames, save_path, connected FROM saves
+    "SELECT id, game, trainer_name, trainer_id, secret_id, playtime_hours, playtime_minutes, playtime_frames, save_path, connected, trainer_name_data FROM saves
     WHERE id = (?1)";
 
 pub const INSERT_SAVE_INTO_SAVES: &str = "INSERT INTO saves (
     game, trainer_name, trainer_id, secret_id, 
-    playtime_hours, playtime_minutes, playtime_frames, save_path, connected) 
-    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)";
+    playtime_hours, playtime_minutes, playtime_frames, save_path, connected, trainer_name_data) 
+    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)";
 
 pub const UPDATE_SAVE_CONNECTED: &str = "UPDATE saves SET connected = ? WHERE id = ?";
 
+pub const UPDATE_SAVE_PATH: &str = "UPDATE saves SET save_path = ? WHERE id = ?";
+
 pub const CREATE_TABLE_ROAM_POKEMON: &str = "CREATE TABLE monsters (
     id INTEGER PRIMARY KEY,
     original_trainer_id INTEGER,
     original_secret_id INTEGER,
     personality_value INTEGER,
     data_format INTEGER,
-    data BLOB
+    data BLOB,
+    source_save_id INTEGER,
+    deposited_at INTEGER,
+    species INTEGER
 )";
 
+pub const CREATE_INDEX_MONSTERS_SPECIES: &str =
+    "CREATE INDEX monsters_species ON monsters (species)";
+
 pub const INSERT_MON_INTO_MONS: &str = "INSERT INTO monsters (
-    original_trainer_id, original_secret_id, personality_value, data_format, data)
-    VALUES (?1, ?2, ?3, ?4, ?5)";
+    original_trainer_id, original_secret_id, personality_value, data_format, data, source_save_id, deposited_at, species)
+    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)";
 
-pub const SELECT_ALL_MONS: &str = "SELECT id, original_trainer_id, original_secret_id, personality_value, data_format, data FROM monsters";
+pub const INSERT_MON_WITH_ID_INTO_MONS: &str = "INSERT INTO monsters (
+    id, original_trainer_id, original_secret_id, personality_value, data_format, data, source_save_id, deposited_at, species)
+    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)";
+
+pub const SELECT_ALL_MONS: &str = "SELECT id, original_trainer_id, original_secret_id, personality_value, data_format, data, source_save_id, deposited_at, species FROM monsters";
 
-pub const SELECT_MON_WITH_ID: &str = "SELECT id, original_trainer_id, original_secret_id, personality_value, data_format, data FROM monsters
+pub const SELECT_MON_WITH_ID: &str = "SELECT id, original_trainer_id, original_secret_id, personality_value, data_format, data, source_save_id, deposited_at, species FROM monsters
     WHERE id = ?";
 
+pub const SELECT_MON_AT_BOX_LOCATION: &str = "SELECT monsters.id, original_trainer_id, original_secret_id, personality_value, data_format, data, source_save_id, deposited_at, species FROM monsters
+    INNER JOIN box_entries ON box_entries.monster_id = monsters.id
+    WHERE box_entries.box_number = ?1 AND box_entries.box_position = ?2";
+
+pub const SELECT_MONS_IN_BOX: &str = "SELECT monsters.id, original_trainer_id, original_secret_id, personality_value, data_format, data, source_save_id, deposited_at, species, box_entries.box_position FROM monsters
+    INNER JOIN box_entries ON box_entries.monster_id = monsters.id
+    WHERE box_entries.box_number = ?
+    ORDER BY box_entries.box_position";
+
+pub const SELECT_MON_ID_WITH_FINGERPRINT: &str = "SELECT id FROM monsters
+    WHERE personality_value = ?1 AND original_trainer_id = ?2 AND original_secret_id = ?3
+    ORDER BY id LIMIT 1";
+
+/// Mons sharing a fingerprint with at least one other mon, ordered so that each group is adjacent.
+pub const SELECT_DUPLICATE_MONS: &str =
+    "SELECT id, personality_value, original_trainer_id, original_secret_id FROM monsters
+    WHERE (personality_value, original_trainer_id, original_secret_id) IN (
+        SELECT personality_value, original_trainer_id, original_secret_id FROM monsters
+        GROUP BY personality_value, original_trainer_id, original_secret_id
+        HAVING COUNT(*) > 1)
+    ORDER BY personality_value, original_trainer_id, original_secret_id, id";
+
 pub const DELETE_MON_WITH_ID: &str = "DELETE FROM monsters WHERE id = ?";
 
 pub const CREATE_TABLE_BOX_ENTRIES: &str = "CREATE TABLE box_entries (
@@ -61,3 +96,35 @@ pub const INSERT_BOX_ENTRY: &str =
 
 pub const SELECT_BOX_ENTRY_WITH_MONSTER_ID: &str =
     "SELECT box_number, box_position, monster_id FROM box_entries WHERE monster_id = ?";
+
+pub const SELECT_BOX_ENTRY_AT_LOCATION: &str =
+    "SELECT box_number, box_position, monster_id FROM box_entries WHERE box_number = ?1 AND box_position = ?2";
+
+pub const UPDATE_BOX_ENTRY_LOCATION: &str =
+    "UPDATE box_entries SET box_number = ?1, box_position = ?2 WHERE monster_id = ?3";
+
+pub const SELECT_ALL_BOX_ENTRIES: &str =
+    "SELECT box_number, box_position, monster_id FROM box_entries ORDER BY box_number, box_position";
+
+pub const CREATE_TABLE_BOX_NAMES: &str = "CREATE TABLE box_names (
+    box_number INTEGER PRIMARY KEY,
+    name TEXT NOT NULL
+)";
+
+pub const INSERT_OR_REPLACE_BOX_NAME: &str =
+    "INSERT OR REPLACE INTO box_names (box_number, name) VALUES (?1, ?2)";
+
+pub const SELECT_MAX_BOX_ENTRY_EXTENTS: &str =
+    "SELECT MAX(box_number), MAX(box_position) FROM box_entries";
+
+pub const CREATE_TABLE_SETTINGS: &str = "CREATE TABLE settings (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+)";
+
+pub const SELECT_SETTING: &str = "SELECT value FROM settings WHERE key = ?";
+
+pub const INSERT_OR_REPLACE_SETTING: &str =
+    "INSERT OR REPLACE INTO settings (key, value) VALUES (?1, ?2)";
+
+pub const SELECT_BOX_NAMES: &str = "SELECT box_number, name FROM box_names ORDER BY box_number";
@@ -1,6 +1,8 @@
 pub mod app_paths;
 pub mod database;
 pub mod logging;
+pub mod maintenance;
+pub mod transfer;
 pub mod types;
 
 #[cfg(feature = "cli")]
@@ -2,15 +2,28 @@ use log::LevelFilter;
 use std::io;
 use std::path::Path;
 
+const MAX_LOG_FILE_INDEX: u64 = 2;
+
 pub fn initialize(enable_debug: bool, log_dir: impl AsRef<Path>) -> io::Result<()> {
     let log_level = if enable_debug {
         LevelFilter::Debug
     } else {
         LevelFilter::Info
     };
-    const MAX_LOG_FILE_INDEX: u64 = 2;
+    rotate_log_files(&log_dir)?;
 
-    (MAX_LOG_FILE_INDEX..=0)
+    let mut current_log_file_path = log_dir.as_ref().to_path_buf();
+    current_log_file_path.push("pkroam.log");
+    std::fs::File::create(&current_log_file_path)?;
+    println!("Logging to file: {}", current_log_file_path.display());
+    simple_logging::log_to_file(&current_log_file_path, log_level)
+}
+
+/// Shifts `pkroam.log.N` to `pkroam.log.N+1` from the highest index down, then moves the last
+/// run's `pkroam.log` to `pkroam.log.0`. Only `MAX_LOG_FILE_INDEX + 1` old logs are kept.
+pub fn rotate_log_files(log_dir: impl AsRef<Path>) -> io::Result<()> {
+    (0..=MAX_LOG_FILE_INDEX)
+        .rev()
         .map(|idx| {
             let mut log_file_path = log_dir.as_ref().to_path_buf();
             let mut next_log_file_path = log_dir.as_ref().to_path_buf();
@@ -29,12 +42,12 @@ pub fn initialize(enable_debug: bool, log_dir: impl AsRef<Path>) -> io::Result<(
     let _ = std::fs::remove_file(&log_file_path);
 
     let mut current_log_file_path = log_dir.as_ref().to_path_buf();
-    current_log_file_path.push(format!("pkroam.log"));
+    current_log_file_path.push("pkroam.log");
     let mut last_log_file_path = log_dir.as_ref().to_path_buf();
-    last_log_file_path.push(format!("pkroam.log.0"));
-    std::fs::copy(&current_log_file_path, last_log_file_path)?;
-
-    std::fs::File::create(&current_log_file_path)?;
-    println!("Logging to file: {}", current_log_file_path.display());
-    simple_logging::log_to_file(&current_log_file_path, log_level)
+    last_log_file_path.push("pkroam.log.0");
+    // There's nothing to keep on the first run
+    if current_log_file_path.exists() {
+        std::fs::copy(&current_log_file_path, last_log_file_path)?;
+    }
+    Ok(())
 }
@@ -0,0 +1,43 @@
+//! Operations for keeping connected saves healthy after they've been touched by other tools.
+
+use crate::types::GameSaveData;
+use pkroam::save::SaveFile;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RepairOutcome {
+    AlreadyValid,
+    Repaired { sections: usize, backup: PathBuf },
+}
+
+/// Fixes the section checksums of a connected save, first copying the original into
+/// `backup_dir`. Refuses to touch a file which no longer holds the trainer the save was
+/// registered with.
+pub fn repair_save(save: &GameSaveData, backup_dir: &Path) -> anyhow::Result<RepairOutcome> {
+    let mut save_file = SaveFile::new(&save.save_path)?;
+    let trainer_id = save_file.get_trainer_info().id;
+    if u32::from(trainer_id.public_id) != save.trainer_id
+        || u32::from(trainer_id.secret_id) != save.secret_id
+    {
+        return Err(anyhow::anyhow!(
+            "Save at {} belongs to trainer {:05}, expected {:05}",
+            save.save_path.display(),
+            trainer_id.public_id,
+            save.trainer_id
+        ));
+    }
+
+    let sections = save_file.repair_checksums()?;
+    if sections == 0 {
+        return Ok(RepairOutcome::AlreadyValid);
+    }
+
+    let backup = save_file.backup_to(backup_dir)?;
+    save_file.set_backup_dir(None);
+    save_file.write_in_place()?;
+    log::info!(
+        "Repaired {sections} section checksums in {}",
+        save.save_path.display()
+    );
+    Ok(RepairOutcome::Repaired { sections, backup })
+}
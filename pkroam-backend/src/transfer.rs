@@ -0,0 +1,102 @@
+//! Operations which move mons between the roam database and game saves.
+
+use crate::{
+    database::DbConn,
+    types::{GameSaveData, MonsterData},
+};
+use pkroam::{
+    pk3::Pokemon,
+    save::{SaveFile, MAX_PARTY_SIZE},
+};
+
+/// Moves a mon out of the database and into a box slot of the save, then writes the save. If
+/// the save can't be written the mon is restored to the database where it was.
+pub fn withdraw_to_save(
+    db_handle: &mut DbConn,
+    monster_id: u64,
+    save_file: &mut SaveFile,
+    box_number: u8,
+    box_position: u8,
+    rehome_to: Option<&GameSaveData>,
+) -> anyhow::Result<MonsterData> {
+    if save_file
+        .get_pokemon_from_box(box_number, box_position)?
+        .is_some()
+    {
+        return Err(anyhow::anyhow!(
+            "The selected save file has a pokemon in box {box_number} position {box_position} already"
+        ));
+    }
+
+    withdraw_with(
+        db_handle,
+        monster_id,
+        save_file,
+        rehome_to,
+        |save_file, pk3_data| {
+            save_file.put_pokemon_in_box(box_number, box_position, pk3_data, false)?;
+            Ok(())
+        },
+    )
+}
+
+/// Like `withdraw_to_save`, but adds the mon to the end of the save's party.
+pub fn withdraw_to_party(
+    db_handle: &mut DbConn,
+    monster_id: u64,
+    save_file: &mut SaveFile,
+    rehome_to: Option<&GameSaveData>,
+) -> anyhow::Result<MonsterData> {
+    if save_file.party_size()? >= MAX_PARTY_SIZE {
+        return Err(anyhow::anyhow!("The party is full"));
+    }
+
+    withdraw_with(
+        db_handle,
+        monster_id,
+        save_file,
+        rehome_to,
+        |save_file, pk3_data| {
+            let party_pk3 = Pokemon::from_pk3(pk3_data)?.to_party_pk3();
+            save_file.add_to_party(&party_pk3)?;
+            Ok(())
+        },
+    )
+}
+
+fn withdraw_with<F>(
+    db_handle: &mut DbConn,
+    monster_id: u64,
+    save_file: &mut SaveFile,
+    rehome_to: Option<&GameSaveData>,
+    put: F,
+) -> anyhow::Result<MonsterData>
+where
+    F: FnOnce(&mut SaveFile, &[u8]) -> std::io::Result<()>,
+{
+    // Check the stored data fits a box slot before taking it out of the database
+    let stored = db_handle.get_mon(monster_id)?;
+    let pk3_data = match rehome_to {
+        Some(game_save) => stored.rehomed_pk3(game_save)?,
+        None => stored.box_pk3()?,
+    };
+
+    let (pkmn_data, location) = db_handle.withdraw_mon(monster_id)?;
+    let res = put(save_file, &pk3_data)
+        .and_then(|_| save_file.write_in_place())
+        .map_err(anyhow::Error::from);
+
+    match res {
+        Ok(()) => Ok(pkmn_data),
+        Err(err) => {
+            log::error!("Failed to write mon into save file: {err:?}");
+            db_handle
+                .restore_mon(&pkmn_data, location)
+                .map_err(|restore_err| {
+                    log::error!("Failed to replace mon in database: {restore_err:?}");
+                    restore_err
+                })?;
+            Err(err)
+        }
+    }
+}
@@ -1,6 +1,7 @@
 /// This module contains data types for concepts used throughout the program.
 /// They are intended to be strongly-typed such that they cannot contain invalid
 /// state (i.e. a meaningless save id, a too-large vector of data)
+use serde::{Deserialize, Serialize};
 use std::{
     io,
     path::{Path, PathBuf},
@@ -11,6 +12,8 @@ pub struct GameSaveData {
     pub id: Option<u64>,
     pub game: Game,
     pub trainer_name: String,
+    /// The name as stored in the save, unknown for saves added before it was tracked.
+    pub trainer_name_data: Option<Vec<u8>>,
     pub trainer_id: u32,
     pub secret_id: u32,
     pub playtime: Playtime,
@@ -19,13 +22,20 @@ pub struct GameSaveData {
 }
 
 impl GameSaveData {
-    pub fn from_path(p: impl AsRef<Path>, game_id: u32) -> anyhow::Result<Self> {
+    /// Reads the trainer of the save at `p`. The game is detected from the save unless a
+    /// `game_id` is given.
+    pub fn from_path(p: impl AsRef<Path>, game_id: Option<u32>) -> anyhow::Result<Self> {
         let save_file = pkroam::save::SaveFile::new(&p)?;
         let trainer_info = save_file.get_trainer_info();
+        let game = match game_id {
+            Some(game_id) => Game::try_from(game_id)?,
+            None => Game::try_from(save_file.detect_game())?,
+        };
         Ok(Self {
             id: None,
-            game: Game::try_from(game_id)?,
+            game,
             trainer_name: trainer_info.player_name,
+            trainer_name_data: Some(trainer_info.player_name_data),
             trainer_id: trainer_info.id.public_id.into(),
             secret_id: trainer_info.id.secret_id.into(),
             playtime: Playtime::new(
@@ -53,6 +63,49 @@ impl std::fmt::Display for GameSaveData {
     }
 }
 
+/// A save as listed for machine-readable output.
+#[derive(Clone, Debug, Serialize)]
+pub struct SaveListing {
+    pub id: u64,
+    pub game: String,
+    pub trainer_name: String,
+    pub trainer_id: u32,
+    pub playtime_hours: u32,
+    pub playtime_minutes: u32,
+    pub save_path: String,
+}
+
+impl From<&GameSaveData> for SaveListing {
+    fn from(save: &GameSaveData) -> Self {
+        Self {
+            id: save.id.expect("Saves coming from the database have an id"),
+            game: save.game.to_string(),
+            trainer_name: save.trainer_name.clone(),
+            trainer_id: save.trainer_id,
+            playtime_hours: save.playtime.hours,
+            playtime_minutes: save.playtime.minutes,
+            save_path: save.save_path.display().to_string(),
+        }
+    }
+}
+
+/// A mon as listed for machine-readable output, either in the roam storage or in a save. Mons
+/// in a save's party have no box number.
+#[derive(Clone, Debug, Serialize)]
+pub struct MonListing {
+    pub id: Option<u64>,
+    pub box_number: Option<u32>,
+    pub box_name: Option<String>,
+    pub box_position: Option<u32>,
+    pub dex_number: Option<u16>,
+    pub species: String,
+    pub level: u8,
+    pub source_save_id: Option<u32>,
+    pub days_stored: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moves: Option<Vec<String>>,
+}
+
 #[derive(Clone, Debug)]
 pub struct Playtime {
     pub hours: u32,
@@ -85,6 +138,17 @@ pub enum Game {
     LeafGreen = 4,
 }
 
+impl Game {
+    /// The save layout shared by this game and its pair.
+    pub fn game_code(&self) -> pkroam::save::GameCode {
+        match self {
+            Game::Ruby | Game::Sapphire => pkroam::save::GameCode::RubySapphire,
+            Game::Emerald => pkroam::save::GameCode::Emerald,
+            Game::FireRed | Game::LeafGreen => pkroam::save::GameCode::FireRedLeafGreen,
+        }
+    }
+}
+
 impl std::fmt::Display for Game {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match *self {
@@ -112,9 +176,27 @@ impl TryFrom<u32> for Game {
     }
 }
 
-impl Into<u32> for Game {
-    fn into(self) -> u32 {
-        match self {
+impl TryFrom<pkroam::pk3::OriginGame> for Game {
+    type Error = io::Error;
+
+    fn try_from(value: pkroam::pk3::OriginGame) -> Result<Self, Self::Error> {
+        use pkroam::pk3::OriginGame;
+        match value {
+            OriginGame::Ruby => Ok(Game::Ruby),
+            OriginGame::Sapphire => Ok(Game::Sapphire),
+            OriginGame::Emerald => Ok(Game::Emerald),
+            OriginGame::FireRed => Ok(Game::FireRed),
+            OriginGame::LeafGreen => Ok(Game::LeafGreen),
+            OriginGame::ColosseumXd | OriginGame::Unknown(_) => {
+                Err(io::ErrorKind::InvalidData.into())
+            }
+        }
+    }
+}
+
+impl From<Game> for u32 {
+    fn from(value: Game) -> Self {
+        match value {
             Game::Ruby => 0,
             Game::Sapphire => 1,
             Game::Emerald => 2,
@@ -132,11 +214,23 @@ pub struct MonsterData {
     pub personality_value: u32,
     pub data_format: DataFormat,
     pub data: Vec<u8>,
+    pub source_save_id: Option<u32>,
+    /// Unix timestamp in seconds of when the mon was put into the roam storage, unknown for mons
+    /// stored before it was tracked.
+    pub deposited_at: Option<u64>,
+    pub species: pkroam::pk3::species::Species,
 }
 
 impl MonsterData {
     pub fn from_pk3(pk3_data: &[u8]) -> anyhow::Result<Self> {
-        let pkmn = pkroam::pk3::Pokemon::from_pk3(pk3_data)?;
+        if pk3_data.iter().all(|byte| *byte == 0x00) {
+            log::warn!("Rejecting empty PK3 data");
+            return Err(anyhow::anyhow!("PK3 data is empty"));
+        }
+        let pkmn = pkroam::pk3::Pokemon::from_pk3(pk3_data).map_err(|err| {
+            log::warn!("Rejecting PK3 data which does not parse as a Pokemon: {err}");
+            err
+        })?;
         Ok(MonsterData {
             id: None,
             original_trainer_id: pkmn.original_trainer_id.public_id.into(),
@@ -144,8 +238,138 @@ impl MonsterData {
             personality_value: pkmn.personality_value,
             data_format: DataFormat::PK3,
             data: pk3_data.to_vec(),
+            source_save_id: None,
+            deposited_at: None,
+            species: pkmn.species,
+        })
+    }
+
+    pub fn from_pk4(pk4_data: &[u8]) -> anyhow::Result<Self> {
+        let pkmn = pkroam::pk4::Pokemon::from_pk4(pk4_data).map_err(|err| {
+            log::warn!("Rejecting PK4 data which does not parse as a Pokemon: {err}");
+            err
+        })?;
+        if !pkmn.checksum_is_valid() {
+            return Err(anyhow::anyhow!("PK4 data has an invalid checksum"));
+        }
+        let species = pkmn.species().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Species #{} can't be stored until Gen 4 species are supported",
+                pkmn.national_dex_number
+            )
+        })?;
+        Ok(MonsterData {
+            id: None,
+            original_trainer_id: pkmn.original_trainer_id.public_id.into(),
+            original_secret_id: pkmn.original_trainer_id.secret_id.into(),
+            personality_value: pkmn.personality_value,
+            data_format: DataFormat::PK4,
+            data: pk4_data.to_vec(),
+            source_save_id: None,
+            deposited_at: None,
+            species,
         })
     }
+
+    /// The stored data in the 80 byte format of a PC box slot. Party-format PK3 is cut down to
+    /// its box portion since the game recalculates the party stats.
+    pub fn box_pk3(&self) -> anyhow::Result<Vec<u8>> {
+        use pkroam::pk3::{PK3_SIZE_BOX, PK3_SIZE_PARTY};
+        match (&self.data_format, self.data.len()) {
+            (DataFormat::PK3, PK3_SIZE_BOX) => Ok(self.data.clone()),
+            (DataFormat::PK3, PK3_SIZE_PARTY) => Ok(self.data[..PK3_SIZE_BOX].to_vec()),
+            (DataFormat::PK3, len) => Err(anyhow::anyhow!(
+                "Stored PK3 data is {len} bytes, expected {PK3_SIZE_BOX} or {PK3_SIZE_PARTY}"
+            )),
+            (data_format, _) => Err(anyhow::anyhow!(
+                "Mons stored as {data_format:?} can't be put in a Gen 3 box"
+            )),
+        }
+    }
+
+    /// Produces the PK3 data with the OT rewritten to the trainer of `save`, for intentionally
+    /// transferring ownership on withdraw. The stored original OT fields are left as is.
+    pub fn rehomed_pk3(&self, save: &GameSaveData) -> anyhow::Result<Vec<u8>> {
+        let mut pkmn = pkroam::pk3::Pokemon::from_pk3(&self.box_pk3()?)?;
+        let trainer_id = pkroam::TrainerId {
+            public_id: save.trainer_id.try_into()?,
+            secret_id: save.secret_id.try_into()?,
+        };
+        match &save.trainer_name_data {
+            Some(name_data) => pkmn.set_original_trainer_data(name_data, trainer_id)?,
+            None => pkmn.set_original_trainer(&save.trainer_name, trainer_id)?,
+        }
+        Ok(pkmn.to_pk3())
+    }
+
+    /// Whole days the mon has been in storage as of `now`.
+    pub fn days_stored(&self, now: std::time::SystemTime) -> Option<u64> {
+        let now = now.duration_since(std::time::UNIX_EPOCH).ok()?.as_secs();
+        Some(now.saturating_sub(self.deposited_at?) / (60 * 60 * 24))
+    }
+
+    /// Identifies an individual mon regardless of changes to its stored data.
+    pub fn fingerprint(&self) -> (u32, u32, u32) {
+        (
+            self.personality_value,
+            self.original_trainer_id,
+            self.original_secret_id,
+        )
+    }
+}
+
+/// A stored mon in the portable export format, with the raw data hex encoded. The ids are
+/// informational, on import they're checked against the data.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MonsterExport {
+    pub original_trainer_id: u32,
+    pub original_secret_id: u32,
+    pub personality_value: u32,
+    pub data_format: u32,
+    pub data: String,
+    pub deposited_at: Option<u64>,
+}
+
+impl From<&MonsterData> for MonsterExport {
+    fn from(mon: &MonsterData) -> Self {
+        Self {
+            original_trainer_id: mon.original_trainer_id,
+            original_secret_id: mon.original_secret_id,
+            personality_value: mon.personality_value,
+            data_format: mon.data_format.clone().into(),
+            data: mon.data.iter().map(|byte| format!("{byte:02x}")).collect(),
+            deposited_at: mon.deposited_at,
+        }
+    }
+}
+
+impl TryFrom<MonsterExport> for MonsterData {
+    type Error = anyhow::Error;
+
+    fn try_from(exported: MonsterExport) -> Result<Self, Self::Error> {
+        if !exported.data.len().is_multiple_of(2) || !exported.data.is_ascii() {
+            return Err(anyhow::anyhow!("Data is not a hex string"));
+        }
+        let data = (0..exported.data.len())
+            .step_by(2)
+            .map(|idx| u8::from_str_radix(&exported.data[idx..idx + 2], 16))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut mon = match DataFormat::try_from(exported.data_format)? {
+            DataFormat::PK3 => MonsterData::from_pk3(&data)?,
+            DataFormat::PK4 => MonsterData::from_pk4(&data)?,
+        };
+        if mon.fingerprint()
+            != (
+                exported.personality_value,
+                exported.original_trainer_id,
+                exported.original_secret_id,
+            )
+        {
+            return Err(anyhow::anyhow!("Ids don't match the data"));
+        }
+        mon.deposited_at = exported.deposited_at;
+        Ok(mon)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -165,15 +389,38 @@ impl TryFrom<u32> for DataFormat {
     }
 }
 
-impl Into<u32> for DataFormat {
-    fn into(self) -> u32 {
-        match self {
+impl From<DataFormat> for u32 {
+    fn from(value: DataFormat) -> Self {
+        match value {
             DataFormat::PK3 => 1,
             DataFormat::PK4 => 2,
         }
     }
 }
 
+/// Outcome of merging the mons of another database into this one.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MergeSummary {
+    pub merged: usize,
+    pub skipped_duplicates: usize,
+}
+
+/// The shape of the roam storage, defaulting to the same layout as the PC boxes in a save.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoxDimensions {
+    pub box_count: u32,
+    pub box_size: u32,
+}
+
+impl Default for BoxDimensions {
+    fn default() -> Self {
+        Self {
+            box_count: 14,
+            box_size: 30,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct BoxLocation {
     box_number: u32,
@@ -186,12 +433,17 @@ impl BoxLocation {
         box_number: u32,
         box_position: u32,
         monster_id: Option<u64>,
+        dimensions: &BoxDimensions,
     ) -> anyhow::Result<Self> {
-        if box_number == 0 {
-            Err(anyhow::anyhow!("Box number must be greater than 0"))
-        } else if box_position == 0 || box_position > 30 {
+        if box_number == 0 || box_number > dimensions.box_count {
+            Err(anyhow::anyhow!(
+                "Invalid box number {box_number}, must be in [1, {}]",
+                dimensions.box_count
+            ))
+        } else if box_position == 0 || box_position > dimensions.box_size {
             Err(anyhow::anyhow!(
-                "Invalid box position {box_position}, must be in [1, 30]"
+                "Invalid box position {box_position}, must be in [1, {}]",
+                dimensions.box_size
             ))
         } else {
             Ok(Self {
@@ -0,0 +1,646 @@
+use pkroam::pk3::species::Species;
+use pkroam_backend::{
+    database::DbConn,
+    types::{BoxDimensions, BoxLocation, GameSaveData, MonsterData},
+};
+
+const WURMPLE_PK3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "wurmple.pk3"
+);
+const TREECKO_PK3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "treecko.pk3"
+);
+
+const EMERALD_SAVE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "emerald.sav"
+);
+const RUBY_SAVE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "ruby-with-treecko.sav"
+);
+
+fn create_test_db() -> DbConn {
+    DbConn::new(":memory:").unwrap()
+}
+
+#[test]
+fn test_roam_box_names() {
+    let db = create_test_db();
+    assert!(db.get_roam_box_names().unwrap().is_empty());
+
+    db.set_roam_box_name(2, "Hoenn").unwrap();
+    db.set_roam_box_name(1, "Starters").unwrap();
+    db.set_roam_box_name(2, "Legends").unwrap();
+    assert_eq!(
+        db.get_roam_box_names().unwrap(),
+        vec![(1, "Starters".to_string()), (2, "Legends".to_string())]
+    );
+
+    assert!(db.set_roam_box_name(0, "Invalid").is_err());
+    assert!(db.set_roam_box_name(15, "Invalid").is_err());
+}
+
+#[test]
+fn test_mon_source_save() {
+    let mut db = create_test_db();
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+
+    let mut mon = MonsterData::from_pk3(&wurmple_pk3).unwrap();
+    let unknown_source_id = db
+        .insert_new_mon(
+            &mon,
+            BoxLocation::new(1, 1, None, &BoxDimensions::default()).unwrap(),
+        )
+        .unwrap();
+    mon.source_save_id = Some(3);
+    let known_source_id = db
+        .insert_new_mon(
+            &mon,
+            BoxLocation::new(1, 2, None, &BoxDimensions::default()).unwrap(),
+        )
+        .unwrap();
+
+    assert_eq!(db.get_mon(unknown_source_id).unwrap().source_save_id, None);
+    assert_eq!(db.get_mon(known_source_id).unwrap().source_save_id, Some(3));
+}
+
+#[test]
+fn test_mon_deposited_at() {
+    let mut db = create_test_db();
+    let mon = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let mon_id = db
+        .insert_new_mon(
+            &mon,
+            BoxLocation::new(1, 1, None, &BoxDimensions::default()).unwrap(),
+        )
+        .unwrap();
+
+    let stored = db.get_mon(mon_id).unwrap();
+    assert!(stored.deposited_at.is_some());
+    assert_eq!(stored.days_stored(std::time::SystemTime::now()), Some(0));
+}
+
+#[test]
+fn test_mon_species() {
+    let mut db = create_test_db();
+    let treecko_pk3 = std::fs::read(TREECKO_PK3).unwrap();
+    let mon = MonsterData::from_pk3(&treecko_pk3).unwrap();
+    let parsed = pkroam::pk3::Pokemon::from_pk3(&treecko_pk3).unwrap();
+    assert_eq!(mon.species, parsed.species);
+
+    let mon_id = db
+        .insert_new_mon(
+            &mon,
+            BoxLocation::new(1, 1, None, &BoxDimensions::default()).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(db.get_mon(mon_id).unwrap().species, Species::Treecko);
+}
+
+#[test]
+fn test_get_all_mons_skips_bad_rows() {
+    let db_path = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    let mut db = DbConn::new(&db_path).unwrap();
+    let wurmple = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let treecko = MonsterData::from_pk3(&std::fs::read(TREECKO_PK3).unwrap()).unwrap();
+    let dimensions = BoxDimensions::default();
+    let wurmple_id = db
+        .insert_new_mon(&wurmple, BoxLocation::new(1, 1, None, &dimensions).unwrap())
+        .unwrap();
+    let bad_id = db
+        .insert_new_mon(&wurmple, BoxLocation::new(1, 2, None, &dimensions).unwrap())
+        .unwrap();
+    let treecko_id = db
+        .insert_new_mon(&treecko, BoxLocation::new(1, 3, None, &dimensions).unwrap())
+        .unwrap();
+
+    let conn = rusqlite::Connection::open(&db_path).unwrap();
+    conn.execute(
+        "UPDATE monsters SET data_format = 99 WHERE id = ?1",
+        (bad_id,),
+    )
+    .unwrap();
+
+    let mon_ids = db
+        .get_all_mons()
+        .unwrap()
+        .into_iter()
+        .map(|mon| mon.id.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(mon_ids, [wurmple_id, treecko_id]);
+}
+
+#[test]
+fn test_find_duplicate_mons() {
+    let mut db = create_test_db();
+    let wurmple = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let treecko = MonsterData::from_pk3(&std::fs::read(TREECKO_PK3).unwrap()).unwrap();
+    let dimensions = BoxDimensions::default();
+    let mut insert = |mon: &MonsterData, position| {
+        db.insert_new_mon(
+            mon,
+            BoxLocation::new(1, position, None, &dimensions).unwrap(),
+        )
+        .unwrap()
+    };
+
+    let first_wurmple = insert(&wurmple, 1);
+    let _ = insert(&treecko, 2);
+    let second_wurmple = insert(&wurmple, 3);
+    assert_eq!(
+        db.find_duplicate_mons().unwrap(),
+        vec![vec![first_wurmple, second_wurmple]]
+    );
+}
+
+#[test]
+fn test_settings() {
+    let db = create_test_db();
+    assert_eq!(db.get_setting("compression").unwrap(), None);
+
+    db.set_setting("compression", "on").unwrap();
+    assert_eq!(
+        db.get_setting("compression").unwrap().as_deref(),
+        Some("on")
+    );
+    db.set_setting("compression", "off").unwrap();
+    assert_eq!(
+        db.get_setting("compression").unwrap().as_deref(),
+        Some("off")
+    );
+
+    db.set_default_box(4).unwrap();
+    assert_eq!(db.get_setting("default_box").unwrap().as_deref(), Some("4"));
+}
+
+#[test]
+fn test_box_dimensions() {
+    let mut db = create_test_db();
+    let dimensions = db.get_box_dimensions().unwrap();
+    assert_eq!(dimensions, BoxDimensions::default());
+    assert!(BoxLocation::new(14, 30, None, &dimensions).is_ok());
+    assert!(BoxLocation::new(0, 1, None, &dimensions).is_err());
+    assert!(BoxLocation::new(1, 0, None, &dimensions).is_err());
+    assert!(BoxLocation::new(15, 1, None, &dimensions).is_err());
+    assert!(BoxLocation::new(1, 31, None, &dimensions).is_err());
+
+    db.set_box_dimensions(BoxDimensions {
+        box_count: 32,
+        box_size: 20,
+    })
+    .unwrap();
+    let dimensions = db.get_box_dimensions().unwrap();
+    assert!(BoxLocation::new(32, 20, None, &dimensions).is_ok());
+    assert!(BoxLocation::new(33, 1, None, &dimensions).is_err());
+    assert!(BoxLocation::new(1, 21, None, &dimensions).is_err());
+}
+
+#[test]
+fn test_box_dimensions_cannot_shrink_below_stored_mons() {
+    let mut db = create_test_db();
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    let mon = MonsterData::from_pk3(&wurmple_pk3).unwrap();
+    db.insert_new_mon(
+        &mon,
+        BoxLocation::new(3, 25, None, &BoxDimensions::default()).unwrap(),
+    )
+    .unwrap();
+
+    let too_small = BoxDimensions {
+        box_count: 2,
+        box_size: 30,
+    };
+    assert!(db.set_box_dimensions(too_small).is_err());
+    assert_eq!(db.get_box_dimensions().unwrap(), BoxDimensions::default());
+    assert!(db
+        .set_box_dimensions(BoxDimensions {
+            box_count: 3,
+            box_size: 25,
+        })
+        .is_ok());
+}
+
+#[test]
+fn test_merge_databases() {
+    let dir = tempfile::tempdir().unwrap();
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    let treecko_pk3 = std::fs::read(TREECKO_PK3).unwrap();
+    let wurmple = MonsterData::from_pk3(&wurmple_pk3).unwrap();
+    let treecko = MonsterData::from_pk3(&treecko_pk3).unwrap();
+    let dimensions = BoxDimensions::default();
+
+    let other_path = dir.path().join("other.db");
+    {
+        let mut other = DbConn::new(&other_path).unwrap();
+        other
+            .insert_new_mon(&wurmple, BoxLocation::new(1, 1, None, &dimensions).unwrap())
+            .unwrap();
+        other
+            .insert_new_mon(&treecko, BoxLocation::new(1, 2, None, &dimensions).unwrap())
+            .unwrap();
+    }
+
+    let mut db = DbConn::new(dir.path().join("pkroam.db")).unwrap();
+    db.insert_new_mon(&wurmple, BoxLocation::new(1, 1, None, &dimensions).unwrap())
+        .unwrap();
+
+    let summary = db.merge_from(&other_path).unwrap();
+    assert_eq!(summary.merged, 1);
+    assert_eq!(summary.skipped_duplicates, 1);
+
+    let entries = db.get_box_entries().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!((entries[1].box_number(), entries[1].box_position()), (1, 2));
+    let merged = db.get_mon(entries[1].monster_id().unwrap()).unwrap();
+    assert_eq!(merged.fingerprint(), treecko.fingerprint());
+}
+
+#[test]
+fn test_merge_missing_database() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing_path = dir.path().join("typo.db");
+    let mut db = create_test_db();
+    assert!(db.merge_from(&missing_path).is_err());
+    assert!(!missing_path.exists());
+}
+
+#[test]
+fn test_merge_newer_database() {
+    let dir = tempfile::tempdir().unwrap();
+    let other_path = dir.path().join("other.db");
+    drop(DbConn::new(&other_path).unwrap());
+    let conn = rusqlite::Connection::open(&other_path).unwrap();
+    let version: i32 = conn
+        .pragma_query_value(None, "user_version", |row| row.get(0))
+        .unwrap();
+    conn.pragma_update(None, "user_version", version + 1)
+        .unwrap();
+    drop(conn);
+
+    let mut db = create_test_db();
+    assert!(db.merge_from(&other_path).is_err());
+    // The other database is left as it was
+    let conn = rusqlite::Connection::open(&other_path).unwrap();
+    let after: i32 = conn
+        .pragma_query_value(None, "user_version", |row| row.get(0))
+        .unwrap();
+    assert_eq!(after, version + 1);
+}
+
+#[test]
+fn test_free_slots() {
+    let mut db = create_test_db();
+    db.set_box_dimensions(BoxDimensions {
+        box_count: 2,
+        box_size: 3,
+    })
+    .unwrap();
+    let dimensions = db.get_box_dimensions().unwrap();
+    assert_eq!(db.get_free_slots().unwrap().len(), 6);
+
+    let mon = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    db.insert_new_mon(&mon, BoxLocation::new(1, 2, None, &dimensions).unwrap())
+        .unwrap();
+    let free_slots = db
+        .get_free_slots()
+        .unwrap()
+        .iter()
+        .map(|slot| (slot.box_number(), slot.box_position()))
+        .collect::<Vec<_>>();
+    assert_eq!(free_slots, vec![(1, 1), (1, 3), (2, 1), (2, 2), (2, 3)]);
+}
+
+#[test]
+fn test_get_mon_at() {
+    let mut db = create_test_db();
+    assert!(db.get_mon_at(1, 1).unwrap().is_none());
+
+    let mon = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let mon_id = db
+        .insert_new_mon(
+            &mon,
+            BoxLocation::new(4, 12, None, &BoxDimensions::default()).unwrap(),
+        )
+        .unwrap();
+    let (found, found_id) = db.get_mon_at(4, 12).unwrap().unwrap();
+    assert_eq!(found_id, mon_id);
+    assert_eq!(found.data, mon.data);
+    assert!(db.get_mon_at(4, 13).unwrap().is_none());
+}
+
+#[test]
+fn test_release_mon() {
+    let mut db = create_test_db();
+    let mon = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let dimensions = BoxDimensions::default();
+    let mon_id = db
+        .insert_new_mon(&mon, BoxLocation::new(4, 12, None, &dimensions).unwrap())
+        .unwrap();
+    let free_slots = db.get_free_slots().unwrap().len();
+
+    let released = db.release_mon(mon_id).unwrap();
+    assert_eq!(released.data, mon.data);
+    assert!(db.get_mon(mon_id).is_err());
+    assert!(db.get_mon_at(4, 12).unwrap().is_none());
+    assert!(db.get_box_entries().unwrap().is_empty());
+    assert_eq!(db.get_free_slots().unwrap().len(), free_slots + 1);
+    assert!(db.release_mon(mon_id).is_err());
+
+    // The freed position can be deposited into again
+    db.insert_new_mon(&mon, BoxLocation::new(4, 12, None, &dimensions).unwrap())
+        .unwrap();
+}
+
+#[test]
+fn test_default_deposit_box() {
+    let mut db = create_test_db();
+    assert_eq!(db.get_default_box().unwrap(), None);
+    assert!(db.resolve_deposit_location(None, None).is_err());
+    assert!(db.set_default_box(0).is_err());
+    assert!(db.set_default_box(15).is_err());
+    assert_eq!(
+        db.resolve_deposit_location(Some(15), None)
+            .unwrap_err()
+            .to_string(),
+        "Invalid box number 15, must be in [1, 14]"
+    );
+
+    db.set_default_box(3).unwrap();
+    assert_eq!(db.get_default_box().unwrap(), Some(3));
+    let mon = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    db.insert_new_mon(
+        &mon,
+        BoxLocation::new(3, 1, None, &BoxDimensions::default()).unwrap(),
+    )
+    .unwrap();
+
+    let location = db.resolve_deposit_location(None, None).unwrap();
+    assert_eq!((location.box_number(), location.box_position()), (3, 2));
+    let location = db.resolve_deposit_location(Some(5), None).unwrap();
+    assert_eq!((location.box_number(), location.box_position()), (5, 1));
+    let location = db.resolve_deposit_location(None, Some(10)).unwrap();
+    assert_eq!((location.box_number(), location.box_position()), (3, 10));
+}
+
+#[test]
+fn test_list_trainers() {
+    let mut db = create_test_db();
+    assert!(db.list_trainers().unwrap().is_empty());
+
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    let wurmple = MonsterData::from_pk3(&wurmple_pk3).unwrap();
+    let treecko = MonsterData::from_pk3(&std::fs::read(TREECKO_PK3).unwrap()).unwrap();
+    let mut traded = pkroam::pk3::Pokemon::from_pk3(&wurmple_pk3).unwrap();
+    traded
+        .set_original_trainer(
+            "Brendan",
+            pkroam::TrainerId {
+                public_id: 1,
+                secret_id: 2,
+            },
+        )
+        .unwrap();
+    let traded = MonsterData::from_pk3(&traded.to_pk3()).unwrap();
+
+    let dimensions = BoxDimensions::default();
+    for (position, mon) in [&wurmple, &treecko, &wurmple, &traded]
+        .into_iter()
+        .enumerate()
+    {
+        db.insert_new_mon(
+            mon,
+            BoxLocation::new(1, position as u32 + 1, None, &dimensions).unwrap(),
+        )
+        .unwrap();
+    }
+
+    let trainers = db.list_trainers().unwrap();
+    assert_eq!(trainers.len(), 3);
+    assert_eq!(trainers[0], (1, 2, "Brendan".to_string(), 1));
+    assert!(trainers.contains(&(
+        wurmple.original_trainer_id,
+        wurmple.original_secret_id,
+        "Shane".to_string(),
+        2
+    )));
+    assert!(trainers.contains(&(
+        treecko.original_trainer_id,
+        treecko.original_secret_id,
+        "Shane".to_string(),
+        1
+    )));
+}
+
+#[test]
+fn test_save_trainer_name_data() {
+    let db = create_test_db();
+    let mut save = GameSaveData::from_path(EMERALD_SAVE, None).unwrap();
+    // A name with a byte that has no character decodes lossily but is stored as is
+    save.trainer_name = "Sh*ne".to_string();
+    save.trainer_name_data = Some(vec![0xcd, 0xdc, 0x0a, 0xe2, 0xd9, 0xff, 0xff]);
+    db.add_new_save(&save).unwrap();
+
+    let stored = db.get_saves().unwrap().remove(0);
+    assert_eq!(stored.trainer_name, "Sh*ne");
+    assert_eq!(stored.trainer_name_data, save.trainer_name_data);
+}
+
+#[test]
+fn test_export_import() {
+    let mut db = create_test_db();
+    let dimensions = db.get_box_dimensions().unwrap();
+    let mut wurmple = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    wurmple.deposited_at = Some(1_700_000_000);
+    let treecko = MonsterData::from_pk3(&std::fs::read(TREECKO_PK3).unwrap()).unwrap();
+    db.insert_new_mon(&wurmple, BoxLocation::new(1, 1, None, &dimensions).unwrap())
+        .unwrap();
+    db.insert_new_mon(&treecko, BoxLocation::new(2, 5, None, &dimensions).unwrap())
+        .unwrap();
+    let exported = db.export_all().unwrap();
+
+    let mut other = create_test_db();
+    other
+        .insert_new_mon(&treecko, BoxLocation::new(1, 1, None, &dimensions).unwrap())
+        .unwrap();
+    let summary = other.import_all(&exported).unwrap();
+    assert_eq!(summary.merged, 1);
+    assert_eq!(summary.skipped_duplicates, 1);
+    let (imported, _) = other.get_mon_at(1, 2).unwrap().unwrap();
+    assert_eq!(imported.fingerprint(), wurmple.fingerprint());
+    assert_eq!(imported.data, wurmple.data);
+    assert_eq!(imported.deposited_at, Some(1_700_000_000));
+
+    // A single corrupt mon rejects the whole import
+    let mut fresh = create_test_db();
+    let corrupt = exported.replacen("\"data\": \"", "\"data\": \"00", 1);
+    assert!(fresh.import_all(&corrupt).is_err());
+    let mismatched = exported.replacen(
+        &format!("\"personality_value\": {}", wurmple.personality_value),
+        "\"personality_value\": 1",
+        1,
+    );
+    assert!(fresh.import_all(&mismatched).is_err());
+    assert!(fresh.get_all_mons().unwrap().is_empty());
+}
+
+#[test]
+fn test_move_mon() {
+    let mut db = create_test_db();
+    let dimensions = db.get_box_dimensions().unwrap();
+    let wurmple = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let treecko = MonsterData::from_pk3(&std::fs::read(TREECKO_PK3).unwrap()).unwrap();
+    let wurmple_id = db
+        .insert_new_mon(&wurmple, BoxLocation::new(1, 1, None, &dimensions).unwrap())
+        .unwrap();
+    let treecko_id = db
+        .insert_new_mon(&treecko, BoxLocation::new(1, 2, None, &dimensions).unwrap())
+        .unwrap();
+
+    db.move_mon(
+        wurmple_id,
+        BoxLocation::new(3, 7, None, &dimensions).unwrap(),
+    )
+    .unwrap();
+    assert!(db.get_mon_at(1, 1).unwrap().is_none());
+    assert_eq!(db.get_mon_at(3, 7).unwrap().unwrap().1, wurmple_id);
+
+    // An occupied destination leaves both mons where they were
+    let err = db
+        .move_mon(
+            wurmple_id,
+            BoxLocation::new(1, 2, None, &dimensions).unwrap(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!("Roam box 1 position 2 is already occupied by mon {treecko_id}")
+    );
+    assert_eq!(db.get_mon_at(3, 7).unwrap().unwrap().1, wurmple_id);
+    assert_eq!(db.get_mon_at(1, 2).unwrap().unwrap().1, treecko_id);
+
+    // Moving a mon onto its own position is a no-op
+    db.move_mon(
+        treecko_id,
+        BoxLocation::new(1, 2, None, &dimensions).unwrap(),
+    )
+    .unwrap();
+    assert!(db
+        .move_mon(999, BoxLocation::new(1, 1, None, &dimensions).unwrap())
+        .is_err());
+}
+
+#[test]
+fn test_get_roam_box() {
+    let mut db = create_test_db();
+    db.set_box_dimensions(BoxDimensions {
+        box_count: 2,
+        box_size: 4,
+    })
+    .unwrap();
+    let dimensions = db.get_box_dimensions().unwrap();
+    let wurmple = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let treecko = MonsterData::from_pk3(&std::fs::read(TREECKO_PK3).unwrap()).unwrap();
+    let treecko_id = db
+        .insert_new_mon(&treecko, BoxLocation::new(1, 3, None, &dimensions).unwrap())
+        .unwrap();
+    let wurmple_id = db
+        .insert_new_mon(&wurmple, BoxLocation::new(1, 1, None, &dimensions).unwrap())
+        .unwrap();
+    db.insert_new_mon(&wurmple, BoxLocation::new(2, 2, None, &dimensions).unwrap())
+        .unwrap();
+
+    let slots = db
+        .get_roam_box(1)
+        .unwrap()
+        .iter()
+        .map(|(location, mon)| {
+            (
+                location.box_position(),
+                location.monster_id(),
+                mon.as_ref().map(|mon| mon.species),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        slots,
+        vec![
+            (1, Some(wurmple_id), Some(Species::Wurmple)),
+            (2, None, None),
+            (3, Some(treecko_id), Some(Species::Treecko)),
+            (4, None, None),
+        ]
+    );
+    assert!(db.get_roam_box(3).is_err());
+}
+
+#[test]
+fn test_reconnect_save() {
+    let dir = tempfile::tempdir().unwrap();
+    let save_path = dir.path().join("emerald.sav");
+    std::fs::copy(EMERALD_SAVE, &save_path).unwrap();
+    let db = create_test_db();
+    db.add_new_save(&GameSaveData::from_path(&save_path, None).unwrap())
+        .unwrap();
+    let save_id = db.get_saves().unwrap()[0].id.unwrap() as u32;
+
+    db.set_save_disconnected(save_id).unwrap();
+    assert!(!db.get_save(save_id).unwrap().connected);
+    db.set_save_connected(save_id).unwrap();
+    assert!(db.get_save(save_id).unwrap().connected);
+
+    // Another trainer's save at the same path isn't reconnected
+    db.set_save_disconnected(save_id).unwrap();
+    std::fs::copy(RUBY_SAVE, &save_path).unwrap();
+    assert!(db.set_save_connected(save_id).is_err());
+    std::fs::remove_file(&save_path).unwrap();
+    assert!(db.set_save_connected(save_id).is_err());
+    assert!(!db.get_save(save_id).unwrap().connected);
+}
+
+#[test]
+fn test_update_save_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let old_path = dir.path().join("emerald.sav");
+    let new_path = dir.path().join("moved.sav");
+    let ruby_path = dir.path().join("ruby.sav");
+    std::fs::copy(EMERALD_SAVE, &old_path).unwrap();
+    std::fs::copy(RUBY_SAVE, &ruby_path).unwrap();
+    let db = create_test_db();
+    db.add_new_save(&GameSaveData::from_path(&old_path, None).unwrap())
+        .unwrap();
+    let save_id = db.get_saves().unwrap()[0].id.unwrap() as u32;
+    std::fs::rename(&old_path, &new_path).unwrap();
+
+    assert!(db
+        .update_save_path(save_id, dir.path().join("nope.sav"))
+        .is_err());
+    assert!(db.update_save_path(save_id, &ruby_path).is_err());
+    assert_eq!(db.get_save(save_id).unwrap().save_path, old_path);
+
+    db.update_save_path(save_id, &new_path).unwrap();
+    assert_eq!(db.get_save(save_id).unwrap().save_path, new_path);
+}
+
+#[test]
+fn test_find_stored_copy() {
+    let mut db = create_test_db();
+    let dimensions = db.get_box_dimensions().unwrap();
+    let wurmple = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let treecko = MonsterData::from_pk3(&std::fs::read(TREECKO_PK3).unwrap()).unwrap();
+    assert_eq!(db.find_stored_copy(&wurmple).unwrap(), None);
+
+    let wurmple_id = db
+        .insert_new_mon(&wurmple, BoxLocation::new(1, 1, None, &dimensions).unwrap())
+        .unwrap();
+    db.insert_new_mon(&wurmple, BoxLocation::new(1, 2, None, &dimensions).unwrap())
+        .unwrap();
+    assert_eq!(db.find_stored_copy(&wurmple).unwrap(), Some(wurmple_id));
+    assert_eq!(db.find_stored_copy(&treecko).unwrap(), None);
+}
@@ -0,0 +1,26 @@
+use pkroam_backend::logging::rotate_log_files;
+
+#[test]
+fn test_rotate_log_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let read = |name: &str| std::fs::read_to_string(dir.path().join(name)).ok();
+
+    // The first run has nothing to rotate
+    rotate_log_files(dir.path()).unwrap();
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+
+    for (name, contents) in [
+        ("pkroam.log", "current"),
+        ("pkroam.log.0", "zero"),
+        ("pkroam.log.1", "one"),
+        ("pkroam.log.2", "two"),
+    ] {
+        std::fs::write(dir.path().join(name), contents).unwrap();
+    }
+    rotate_log_files(dir.path()).unwrap();
+    assert_eq!(read("pkroam.log.0").as_deref(), Some("current"));
+    assert_eq!(read("pkroam.log.1").as_deref(), Some("zero"));
+    assert_eq!(read("pkroam.log.2").as_deref(), Some("one"));
+    // The oldest log is dropped rather than kept as a fourth file
+    assert_eq!(read("pkroam.log.3"), None);
+}
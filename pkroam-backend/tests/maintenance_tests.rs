@@ -0,0 +1,54 @@
+use pkroam::save::SaveFile;
+use pkroam_backend::{
+    maintenance::{repair_save, RepairOutcome},
+    types::GameSaveData,
+};
+
+const EMERALD_SAVE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "emerald.sav"
+);
+
+#[test]
+fn test_repair_save() {
+    let dir = tempfile::tempdir().unwrap();
+    let backup_dir = dir.path().join("backups");
+    std::fs::create_dir(&backup_dir).unwrap();
+    let save_path = dir.path().join("emerald.sav");
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    std::fs::write(&save_path, &save_data).unwrap();
+    let save = GameSaveData::from_path(&save_path, None).unwrap();
+    assert_eq!(
+        repair_save(&save, &backup_dir).unwrap(),
+        RepairOutcome::AlreadyValid
+    );
+
+    // Section 0 of the latest block is at 0x8000, corrupt its stored checksum
+    save_data[0x8000 + 0xff6] ^= 0xff;
+    std::fs::write(&save_path, &save_data).unwrap();
+    let RepairOutcome::Repaired { sections, backup } = repair_save(&save, &backup_dir).unwrap()
+    else {
+        panic!("Expected the save to be repaired");
+    };
+    assert_eq!(sections, 1);
+    assert_eq!(std::fs::read(backup).unwrap(), save_data);
+    SaveFile::new(&save_path)
+        .unwrap()
+        .verify_sections()
+        .unwrap();
+}
+
+#[test]
+fn test_repair_save_checks_trainer() {
+    let dir = tempfile::tempdir().unwrap();
+    let save_path = dir.path().join("emerald.sav");
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    save_data[0x8000 + 0xff6] ^= 0xff;
+    std::fs::write(&save_path, &save_data).unwrap();
+
+    let mut save = GameSaveData::from_path(&save_path, None).unwrap();
+    save.trainer_id += 1;
+    assert!(repair_save(&save, dir.path()).is_err());
+    assert_eq!(std::fs::read(&save_path).unwrap(), save_data);
+}
@@ -0,0 +1,202 @@
+use pkroam::{
+    pk3::{MiscFlags, Pokemon},
+    save::SaveFile,
+};
+use pkroam_backend::{
+    database::DbConn,
+    transfer::{withdraw_to_party, withdraw_to_save},
+    types::{BoxDimensions, BoxLocation, MonsterData},
+};
+
+const WURMPLE_PK3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "wurmple.pk3"
+);
+const EMERALD_SAVE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "emerald.sav"
+);
+const RUBY_SAVE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "ruby-with-treecko.sav"
+);
+
+#[test]
+fn test_withdraw_restores_mon_when_save_write_fails() {
+    let mut db = DbConn::new(":memory:").unwrap();
+    let mon = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let mon_id = db
+        .insert_new_mon(
+            &mon,
+            BoxLocation::new(2, 7, None, &BoxDimensions::default()).unwrap(),
+        )
+        .unwrap();
+
+    // Save data which didn't come from a file can't be written in place
+    let mut save_file = SaveFile::from_bytes(std::fs::read(EMERALD_SAVE).unwrap()).unwrap();
+    assert!(withdraw_to_save(&mut db, mon_id, &mut save_file, 2, 1, None).is_err());
+
+    assert_eq!(db.get_mon(mon_id).unwrap().data, mon.data);
+    let entries = db.get_box_entries().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].box_number(), 2);
+    assert_eq!(entries[0].box_position(), 7);
+    assert_eq!(entries[0].monster_id(), Some(mon_id));
+}
+
+#[test]
+fn test_withdraw_writes_mon_to_save() {
+    let mut db = DbConn::new(":memory:").unwrap();
+    let mon = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let mon_id = db
+        .insert_new_mon(
+            &mon,
+            BoxLocation::new(1, 1, None, &BoxDimensions::default()).unwrap(),
+        )
+        .unwrap();
+
+    let save_path = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    std::fs::copy(EMERALD_SAVE, &save_path).unwrap();
+    let mut save_file = SaveFile::new(&save_path).unwrap();
+    save_file.set_backup_dir(None);
+    withdraw_to_save(&mut db, mon_id, &mut save_file, 2, 1, None).unwrap();
+
+    assert!(db.get_mon(mon_id).is_err());
+    assert!(db.get_box_entries().unwrap().is_empty());
+    let save_file = SaveFile::new(&save_path).unwrap();
+    let withdrawn = save_file.get_pokemon_from_box(2, 1).unwrap().unwrap();
+    assert_eq!(withdrawn.to_pk3(), mon.data);
+}
+
+#[test]
+fn test_obedience_bit_survives_roam_storage() {
+    let mut pkmn = Pokemon::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let misc_flags = MiscFlags {
+        ribbons: pkmn.misc_flags.ribbons,
+        obedience: true,
+    };
+    pkmn.set_misc_flags(misc_flags);
+    assert!(pkmn.checksum_is_valid());
+
+    let mut db = DbConn::new(":memory:").unwrap();
+    let mon = MonsterData::from_pk3(&pkmn.to_pk3()).unwrap();
+    let mon_id = db
+        .insert_new_mon(
+            &mon,
+            BoxLocation::new(1, 1, None, &BoxDimensions::default()).unwrap(),
+        )
+        .unwrap();
+
+    let save_path = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    std::fs::copy(EMERALD_SAVE, &save_path).unwrap();
+    let mut save_file = SaveFile::new(&save_path).unwrap();
+    save_file.set_backup_dir(None);
+    withdraw_to_save(&mut db, mon_id, &mut save_file, 2, 1, None).unwrap();
+
+    let save_file = SaveFile::new(&save_path).unwrap();
+    let withdrawn = save_file.get_pokemon_from_box(2, 1).unwrap().unwrap();
+    assert_eq!(withdrawn.misc_flags, misc_flags);
+    assert!(withdrawn.misc_flags.obedience);
+}
+
+#[test]
+fn test_withdraw_rejects_mismatched_data_length() {
+    let mut db = DbConn::new(":memory:").unwrap();
+    let mut mon = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    mon.data.extend_from_slice(&[0; 10]);
+    let mon_id = db
+        .insert_new_mon(
+            &mon,
+            BoxLocation::new(1, 1, None, &BoxDimensions::default()).unwrap(),
+        )
+        .unwrap();
+
+    let mut save_file = SaveFile::from_bytes(std::fs::read(EMERALD_SAVE).unwrap()).unwrap();
+    let err = withdraw_to_save(&mut db, mon_id, &mut save_file, 2, 1, None).unwrap_err();
+    assert!(err.to_string().contains("90 bytes"));
+    assert!(db.get_mon(mon_id).is_ok());
+    assert!(save_file.get_pokemon_from_box(2, 1).unwrap().is_none());
+}
+
+#[test]
+fn test_withdraw_party_format_mon() {
+    let party_mon = SaveFile::new(EMERALD_SAVE)
+        .unwrap()
+        .get_party()
+        .unwrap()
+        .remove(0)
+        .to_pk3();
+    assert_eq!(party_mon.len(), 100);
+    let mut db = DbConn::new(":memory:").unwrap();
+    let mon = MonsterData::from_pk3(&party_mon).unwrap();
+    let mon_id = db
+        .insert_new_mon(
+            &mon,
+            BoxLocation::new(1, 1, None, &BoxDimensions::default()).unwrap(),
+        )
+        .unwrap();
+
+    let save_path = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    std::fs::copy(EMERALD_SAVE, &save_path).unwrap();
+    let mut save_file = SaveFile::new(&save_path).unwrap();
+    save_file.set_backup_dir(None);
+    withdraw_to_save(&mut db, mon_id, &mut save_file, 2, 1, None).unwrap();
+    let withdrawn = SaveFile::new(&save_path)
+        .unwrap()
+        .get_pokemon_from_box(2, 1)
+        .unwrap()
+        .unwrap();
+    assert_eq!(withdrawn.to_pk3(), party_mon[..80]);
+}
+
+#[test]
+fn test_withdraw_to_full_party() {
+    let mut db = DbConn::new(":memory:").unwrap();
+    let mon = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let mon_id = db
+        .insert_new_mon(
+            &mon,
+            BoxLocation::new(1, 1, None, &BoxDimensions::default()).unwrap(),
+        )
+        .unwrap();
+
+    let save_path = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    std::fs::copy(EMERALD_SAVE, &save_path).unwrap();
+    let mut save_file = SaveFile::new(&save_path).unwrap();
+    save_file.set_backup_dir(None);
+    let err = withdraw_to_party(&mut db, mon_id, &mut save_file, None).unwrap_err();
+    assert_eq!(err.to_string(), "The party is full");
+
+    assert!(db.get_mon(mon_id).is_ok());
+    assert_eq!(
+        std::fs::read(&save_path).unwrap(),
+        std::fs::read(EMERALD_SAVE).unwrap()
+    );
+}
+
+#[test]
+fn test_withdraw_to_party() {
+    let mut db = DbConn::new(":memory:").unwrap();
+    let mon = MonsterData::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let mon_id = db
+        .insert_new_mon(
+            &mon,
+            BoxLocation::new(1, 1, None, &BoxDimensions::default()).unwrap(),
+        )
+        .unwrap();
+
+    let save_path = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    std::fs::copy(RUBY_SAVE, &save_path).unwrap();
+    let mut save_file = SaveFile::new(&save_path).unwrap();
+    save_file.set_backup_dir(None);
+    let party_size = save_file.party_size().unwrap();
+    withdraw_to_party(&mut db, mon_id, &mut save_file, None).unwrap();
+
+    assert!(db.get_mon(mon_id).is_err());
+    let party = SaveFile::new(&save_path).unwrap().get_party().unwrap();
+    assert_eq!(party.len(), usize::from(party_size) + 1);
+    assert_eq!(party.last().unwrap().level(), 3);
+}
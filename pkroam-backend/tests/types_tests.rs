@@ -0,0 +1,102 @@
+use pkroam_backend::types::{Game, GameSaveData, MonsterData, SaveListing};
+
+const WURMPLE_PK3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "wurmple.pk3"
+);
+const EMERALD_SAVE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "emerald.sav"
+);
+
+#[test]
+fn test_monster_data_from_pk3() {
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    let mon = MonsterData::from_pk3(&wurmple_pk3).unwrap();
+    assert_eq!(mon.data, wurmple_pk3);
+}
+
+#[test]
+fn test_monster_data_rejects_empty_pk3() {
+    assert!(MonsterData::from_pk3(&[0u8; 80]).is_err());
+    assert!(MonsterData::from_pk3(&[0u8; 100]).is_err());
+}
+
+#[test]
+fn test_monster_data_rehomed_pk3() {
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    let mut mon = MonsterData::from_pk3(&wurmple_pk3).unwrap();
+    let mut save = GameSaveData::from_path(EMERALD_SAVE, Some(2)).unwrap();
+    save.trainer_name = "Brendan".to_string();
+    save.trainer_name_data = None;
+    save.trainer_id = 12345;
+    save.secret_id = 54321;
+
+    let original = pkroam::pk3::Pokemon::from_pk3(&wurmple_pk3).unwrap();
+    let rehomed = pkroam::pk3::Pokemon::from_pk3(&mon.rehomed_pk3(&save).unwrap()).unwrap();
+    assert_eq!(rehomed.original_trainer_name, "Brendan");
+    assert_eq!(rehomed.original_trainer_id.public_id, 12345);
+    assert_eq!(rehomed.original_trainer_id.secret_id, 54321);
+    assert!(rehomed.checksum_is_valid());
+    // The data region holding the obedience flag is carried over unchanged
+    assert_eq!(rehomed.source_data[32..80], original.source_data[32..80]);
+    assert_eq!(
+        mon.original_trainer_id,
+        u32::from(original.original_trainer_id.public_id)
+    );
+
+    // The name as stored in the save is carried over even when it doesn't decode
+    save.trainer_name_data = Some(vec![0xbc, 0xe6, 0x0a, 0xff]);
+    let rehomed = mon.rehomed_pk3(&save).unwrap();
+    assert_eq!(rehomed[20..27], [0xbc, 0xe6, 0x0a, 0xff, 0xff, 0xff, 0xff]);
+    assert_eq!(
+        pkroam::pk3::Pokemon::from_pk3(&rehomed)
+            .unwrap()
+            .original_trainer_name,
+        "Br*"
+    );
+
+    save.trainer_name_data = None;
+    save.trainer_name = "Br\u{1f600}ndan".to_string();
+    assert!(mon.rehomed_pk3(&save).is_err());
+    mon.data = vec![0u8; 80];
+    assert!(mon.rehomed_pk3(&save).is_err());
+}
+
+#[test]
+fn test_monster_data_days_stored() {
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    let mut mon = MonsterData::from_pk3(&wurmple_pk3).unwrap();
+    let now = std::time::UNIX_EPOCH + std::time::Duration::from_secs(10 * 24 * 60 * 60 + 5);
+    assert_eq!(mon.days_stored(now), None);
+
+    mon.deposited_at = Some(24 * 60 * 60);
+    assert_eq!(mon.days_stored(now), Some(9));
+    mon.deposited_at = Some(20 * 24 * 60 * 60);
+    assert_eq!(mon.days_stored(now), Some(0));
+}
+
+#[test]
+fn test_game_save_data_detects_game() {
+    let save = GameSaveData::from_path(EMERALD_SAVE, None).unwrap();
+    assert!(matches!(save.game, Game::Emerald));
+    let save = GameSaveData::from_path(EMERALD_SAVE, Some(0)).unwrap();
+    assert!(matches!(save.game, Game::Ruby));
+}
+
+#[test]
+fn test_save_listing_json() {
+    let mut save = GameSaveData::from_path(EMERALD_SAVE, None).unwrap();
+    save.id = Some(3);
+    let listing = serde_json::to_value(SaveListing::from(&save)).unwrap();
+    assert_eq!(listing["id"], 3);
+    assert_eq!(listing["game"], "Emerald");
+    assert_eq!(listing["trainer_name"], "Shane");
+    assert_eq!(listing["trainer_id"], 4437);
+    assert!(listing["save_path"]
+        .as_str()
+        .unwrap()
+        .ends_with("emerald.sav"));
+}
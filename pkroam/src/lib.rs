@@ -1,75 +1,598 @@
 pub mod pk3;
+pub mod pk4;
 pub mod save;
 
-use pk3::Pokemon;
+use pk3::{Language, Pokemon};
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct TrainerId {
     pub public_id: u16,
     pub secret_id: u16,
 }
 
-fn decode_text(text_data: &[u8]) -> String {
+/// Names in Japanese games are at most 5 characters, the rest of the field is padding.
+pub const JAPANESE_NAME_LENGTH: usize = 5;
+
+/// What to do with bytes that have no character when decoding text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodePolicy {
+    /// Fail on the first byte without a character.
+    Strict,
+    /// Substitute `*` for bytes without a character, which can't be encoded back.
+    Lossy,
+}
+
+/// Decodes Gen 3 Western text up to the first terminator or control code.
+pub fn decode_text(text_data: &[u8]) -> String {
+    decode_text_for_language(text_data, Language::English)
+}
+
+/// Decodes text stored by a game of the given language, Japanese games use their own charmap.
+pub fn decode_text_for_language(text_data: &[u8], language: Language) -> String {
+    // Lossy decoding substitutes rather than failing
+    decode_text_with_policy(text_data, language, DecodePolicy::Lossy).unwrap_or_default()
+}
+
+/// Decodes text as `decode_text_for_language` does, but with the handling of bytes without a
+/// character chosen by `policy`.
+pub fn decode_text_with_policy(
+    text_data: &[u8],
+    language: Language,
+    policy: DecodePolicy,
+) -> std::io::Result<String> {
+    match language {
+        Language::Japanese => decode_with_charmap(
+            &text_data[..text_data.len().min(JAPANESE_NAME_LENGTH)],
+            &JAPANESE_CHARMAP,
+            policy,
+        ),
+        _ => decode_with_charmap(text_data, &WESTERN_CHARMAP, policy),
+    }
+}
+
+fn decode_with_charmap(
+    text_data: &[u8],
+    charmap: &[Option<char>],
+    policy: DecodePolicy,
+) -> std::io::Result<String> {
     let mut out_text = String::new();
     for byte in text_data {
-        let decoded_char = match *byte {
-            0xfa..=0xff => break,
-            0xbb => 'A',
-            0xbc => 'B',
-            0xbd => 'C',
-            0xbe => 'D',
-            0xbf => 'E',
-            0xc0 => 'F',
-            0xc1 => 'G',
-            0xc2 => 'H',
-            0xc3 => 'I',
-            0xc4 => 'J',
-            0xc5 => 'K',
-            0xc6 => 'L',
-            0xc7 => 'M',
-            0xc8 => 'N',
-            0xc9 => 'O',
-            0xca => 'P',
-            0xcb => 'Q',
-            0xcc => 'R',
-            0xcd => 'S',
-            0xce => 'T',
-            0xcf => 'U',
-            0xd0 => 'V',
-            0xd1 => 'W',
-            0xd2 => 'X',
-            0xd3 => 'Y',
-            0xd4 => 'Z',
-            0xd5 => 'a',
-            0xd6 => 'b',
-            0xd7 => 'c',
-            0xd8 => 'd',
-            0xd9 => 'e',
-            0xda => 'f',
-            0xdb => 'g',
-            0xdc => 'h',
-            0xdd => 'i',
-            0xde => 'j',
-            0xdf => 'k',
-            0xe0 => 'l',
-            0xe1 => 'm',
-            0xe2 => 'n',
-            0xe3 => 'o',
-            0xe4 => 'p',
-            0xe5 => 'q',
-            0xe6 => 'r',
-            0xe7 => 's',
-            0xe8 => 't',
-            0xe9 => 'u',
-            0xea => 'v',
-            0xeb => 'w',
-            0xec => 'x',
-            0xed => 'y',
-            0xee => 'z',
-            _ => '*',
+        match (charmap.get(usize::from(*byte)), policy) {
+            (Some(Some(decoded_char)), _) => out_text.push(*decoded_char),
+            (Some(None), DecodePolicy::Lossy) => out_text.push('*'),
+            (Some(None), DecodePolicy::Strict) => {
+                log::error!("Byte 0x{byte:02x} has no character");
+                return Err(std::io::ErrorKind::InvalidData.into());
+            }
+            (None, _) => break,
+        }
+    }
+
+    Ok(out_text)
+}
+
+/// Encodes `text` into the Gen 3 Western character set, truncated to `max_len` and padded with
+/// the 0xff terminator. Fails if any character has no encoding.
+pub fn encode_text(text: &str, max_len: usize) -> std::io::Result<Vec<u8>> {
+    let mut out_data = Vec::with_capacity(max_len);
+    for c in text.chars().take(max_len) {
+        let Some(encoded_byte) = WESTERN_CHARMAP.iter().position(|&mapped| mapped == Some(c))
+        else {
+            log::error!("Character {c:?} cannot be encoded");
+            return Err(std::io::ErrorKind::InvalidInput.into());
         };
-        out_text.push(decoded_char);
+        out_data.push(encoded_byte as u8);
     }
+    out_data.resize(max_len, 0xff);
 
-    out_text
+    Ok(out_data)
 }
+
+/// The Gen 3 Western character set. Bytes 0xfa and up are control codes and terminators.
+const WESTERN_CHARMAP: [Option<char>; 0xfa] = [
+    Some(' '), // 0x00
+    Some('À'), // 0x01
+    Some('Á'), // 0x02
+    Some('Â'), // 0x03
+    Some('Ç'), // 0x04
+    Some('È'), // 0x05
+    Some('É'), // 0x06
+    Some('Ê'), // 0x07
+    Some('Ë'), // 0x08
+    Some('Ì'), // 0x09
+    None,      // 0x0a
+    Some('Î'), // 0x0b
+    Some('Ï'), // 0x0c
+    Some('Ò'), // 0x0d
+    Some('Ó'), // 0x0e
+    Some('Ô'), // 0x0f
+    Some('Œ'), // 0x10
+    Some('Ù'), // 0x11
+    Some('Ú'), // 0x12
+    Some('Û'), // 0x13
+    Some('Ñ'), // 0x14
+    Some('ß'), // 0x15
+    Some('à'), // 0x16
+    Some('á'), // 0x17
+    None,      // 0x18
+    Some('ç'), // 0x19
+    Some('è'), // 0x1a
+    Some('é'), // 0x1b
+    Some('ê'), // 0x1c
+    Some('ë'), // 0x1d
+    Some('ì'), // 0x1e
+    None,      // 0x1f
+    Some('î'), // 0x20
+    Some('ï'), // 0x21
+    Some('ò'), // 0x22
+    Some('ó'), // 0x23
+    Some('ô'), // 0x24
+    Some('œ'), // 0x25
+    Some('ù'), // 0x26
+    Some('ú'), // 0x27
+    Some('û'), // 0x28
+    Some('ñ'), // 0x29
+    Some('º'), // 0x2a
+    Some('ª'), // 0x2b
+    None,      // 0x2c
+    Some('&'), // 0x2d
+    Some('+'), // 0x2e
+    None,      // 0x2f
+    None,      // 0x30
+    None,      // 0x31
+    None,      // 0x32
+    None,      // 0x33
+    None,      // 0x34
+    Some('='), // 0x35
+    Some(';'), // 0x36
+    None,      // 0x37
+    None,      // 0x38
+    None,      // 0x39
+    None,      // 0x3a
+    None,      // 0x3b
+    None,      // 0x3c
+    None,      // 0x3d
+    None,      // 0x3e
+    None,      // 0x3f
+    None,      // 0x40
+    None,      // 0x41
+    None,      // 0x42
+    None,      // 0x43
+    None,      // 0x44
+    None,      // 0x45
+    None,      // 0x46
+    None,      // 0x47
+    None,      // 0x48
+    None,      // 0x49
+    None,      // 0x4a
+    None,      // 0x4b
+    None,      // 0x4c
+    None,      // 0x4d
+    None,      // 0x4e
+    None,      // 0x4f
+    None,      // 0x50
+    Some('¿'), // 0x51
+    Some('¡'), // 0x52
+    None,      // 0x53
+    None,      // 0x54
+    None,      // 0x55
+    None,      // 0x56
+    None,      // 0x57
+    None,      // 0x58
+    None,      // 0x59
+    Some('Í'), // 0x5a
+    Some('%'), // 0x5b
+    Some('('), // 0x5c
+    Some(')'), // 0x5d
+    None,      // 0x5e
+    None,      // 0x5f
+    None,      // 0x60
+    None,      // 0x61
+    None,      // 0x62
+    None,      // 0x63
+    None,      // 0x64
+    None,      // 0x65
+    None,      // 0x66
+    None,      // 0x67
+    Some('â'), // 0x68
+    None,      // 0x69
+    None,      // 0x6a
+    None,      // 0x6b
+    None,      // 0x6c
+    None,      // 0x6d
+    None,      // 0x6e
+    Some('í'), // 0x6f
+    None,      // 0x70
+    None,      // 0x71
+    None,      // 0x72
+    None,      // 0x73
+    None,      // 0x74
+    None,      // 0x75
+    None,      // 0x76
+    None,      // 0x77
+    None,      // 0x78
+    None,      // 0x79
+    None,      // 0x7a
+    None,      // 0x7b
+    None,      // 0x7c
+    None,      // 0x7d
+    None,      // 0x7e
+    None,      // 0x7f
+    None,      // 0x80
+    None,      // 0x81
+    None,      // 0x82
+    None,      // 0x83
+    None,      // 0x84
+    Some('<'), // 0x85
+    Some('>'), // 0x86
+    None,      // 0x87
+    None,      // 0x88
+    None,      // 0x89
+    None,      // 0x8a
+    None,      // 0x8b
+    None,      // 0x8c
+    None,      // 0x8d
+    None,      // 0x8e
+    None,      // 0x8f
+    None,      // 0x90
+    None,      // 0x91
+    None,      // 0x92
+    None,      // 0x93
+    None,      // 0x94
+    None,      // 0x95
+    None,      // 0x96
+    None,      // 0x97
+    None,      // 0x98
+    None,      // 0x99
+    None,      // 0x9a
+    None,      // 0x9b
+    None,      // 0x9c
+    None,      // 0x9d
+    None,      // 0x9e
+    None,      // 0x9f
+    None,      // 0xa0
+    Some('0'), // 0xa1
+    Some('1'), // 0xa2
+    Some('2'), // 0xa3
+    Some('3'), // 0xa4
+    Some('4'), // 0xa5
+    Some('5'), // 0xa6
+    Some('6'), // 0xa7
+    Some('7'), // 0xa8
+    Some('8'), // 0xa9
+    Some('9'), // 0xaa
+    Some('!'), // 0xab
+    Some('?'), // 0xac
+    Some('.'), // 0xad
+    Some('-'), // 0xae
+    Some('·'), // 0xaf
+    Some('…'), // 0xb0
+    Some('“'), // 0xb1
+    Some('”'), // 0xb2
+    Some('‘'), // 0xb3
+    Some('’'), // 0xb4
+    Some('♂'), // 0xb5
+    Some('♀'), // 0xb6
+    None,      // 0xb7
+    Some(','), // 0xb8
+    Some('×'), // 0xb9
+    Some('/'), // 0xba
+    Some('A'), // 0xbb
+    Some('B'), // 0xbc
+    Some('C'), // 0xbd
+    Some('D'), // 0xbe
+    Some('E'), // 0xbf
+    Some('F'), // 0xc0
+    Some('G'), // 0xc1
+    Some('H'), // 0xc2
+    Some('I'), // 0xc3
+    Some('J'), // 0xc4
+    Some('K'), // 0xc5
+    Some('L'), // 0xc6
+    Some('M'), // 0xc7
+    Some('N'), // 0xc8
+    Some('O'), // 0xc9
+    Some('P'), // 0xca
+    Some('Q'), // 0xcb
+    Some('R'), // 0xcc
+    Some('S'), // 0xcd
+    Some('T'), // 0xce
+    Some('U'), // 0xcf
+    Some('V'), // 0xd0
+    Some('W'), // 0xd1
+    Some('X'), // 0xd2
+    Some('Y'), // 0xd3
+    Some('Z'), // 0xd4
+    Some('a'), // 0xd5
+    Some('b'), // 0xd6
+    Some('c'), // 0xd7
+    Some('d'), // 0xd8
+    Some('e'), // 0xd9
+    Some('f'), // 0xda
+    Some('g'), // 0xdb
+    Some('h'), // 0xdc
+    Some('i'), // 0xdd
+    Some('j'), // 0xde
+    Some('k'), // 0xdf
+    Some('l'), // 0xe0
+    Some('m'), // 0xe1
+    Some('n'), // 0xe2
+    Some('o'), // 0xe3
+    Some('p'), // 0xe4
+    Some('q'), // 0xe5
+    Some('r'), // 0xe6
+    Some('s'), // 0xe7
+    Some('t'), // 0xe8
+    Some('u'), // 0xe9
+    Some('v'), // 0xea
+    Some('w'), // 0xeb
+    Some('x'), // 0xec
+    Some('y'), // 0xed
+    Some('z'), // 0xee
+    Some('▶'), // 0xef
+    Some(':'), // 0xf0
+    Some('Ä'), // 0xf1
+    Some('Ö'), // 0xf2
+    Some('Ü'), // 0xf3
+    Some('ä'), // 0xf4
+    Some('ö'), // 0xf5
+    Some('ü'), // 0xf6
+    None,      // 0xf7
+    None,      // 0xf8
+    None,      // 0xf9
+];
+
+/// The Gen 3 Japanese character set, laid out like the Western one but with kana in place of
+/// accented letters and full-width forms for the rest.
+const JAPANESE_CHARMAP: [Option<char>; 0xfa] = [
+    Some('　'), // 0x00
+    Some('あ'), // 0x01
+    Some('い'), // 0x02
+    Some('う'), // 0x03
+    Some('え'), // 0x04
+    Some('お'), // 0x05
+    Some('か'), // 0x06
+    Some('き'), // 0x07
+    Some('く'), // 0x08
+    Some('け'), // 0x09
+    Some('こ'), // 0x0a
+    Some('さ'), // 0x0b
+    Some('し'), // 0x0c
+    Some('す'), // 0x0d
+    Some('せ'), // 0x0e
+    Some('そ'), // 0x0f
+    Some('た'), // 0x10
+    Some('ち'), // 0x11
+    Some('つ'), // 0x12
+    Some('て'), // 0x13
+    Some('と'), // 0x14
+    Some('な'), // 0x15
+    Some('に'), // 0x16
+    Some('ぬ'), // 0x17
+    Some('ね'), // 0x18
+    Some('の'), // 0x19
+    Some('は'), // 0x1a
+    Some('ひ'), // 0x1b
+    Some('ふ'), // 0x1c
+    Some('へ'), // 0x1d
+    Some('ほ'), // 0x1e
+    Some('ま'), // 0x1f
+    Some('み'), // 0x20
+    Some('む'), // 0x21
+    Some('め'), // 0x22
+    Some('も'), // 0x23
+    Some('や'), // 0x24
+    Some('ゆ'), // 0x25
+    Some('よ'), // 0x26
+    Some('ら'), // 0x27
+    Some('り'), // 0x28
+    Some('る'), // 0x29
+    Some('れ'), // 0x2a
+    Some('ろ'), // 0x2b
+    Some('わ'), // 0x2c
+    Some('を'), // 0x2d
+    Some('ん'), // 0x2e
+    Some('ぁ'), // 0x2f
+    Some('ぃ'), // 0x30
+    Some('ぅ'), // 0x31
+    Some('ぇ'), // 0x32
+    Some('ぉ'), // 0x33
+    Some('ゃ'), // 0x34
+    Some('ゅ'), // 0x35
+    Some('ょ'), // 0x36
+    Some('が'), // 0x37
+    Some('ぎ'), // 0x38
+    Some('ぐ'), // 0x39
+    Some('げ'), // 0x3a
+    Some('ご'), // 0x3b
+    Some('ざ'), // 0x3c
+    Some('じ'), // 0x3d
+    Some('ず'), // 0x3e
+    Some('ぜ'), // 0x3f
+    Some('ぞ'), // 0x40
+    Some('だ'), // 0x41
+    Some('ぢ'), // 0x42
+    Some('づ'), // 0x43
+    Some('で'), // 0x44
+    Some('ど'), // 0x45
+    Some('ば'), // 0x46
+    Some('び'), // 0x47
+    Some('ぶ'), // 0x48
+    Some('べ'), // 0x49
+    Some('ぼ'), // 0x4a
+    Some('ぱ'), // 0x4b
+    Some('ぴ'), // 0x4c
+    Some('ぷ'), // 0x4d
+    Some('ぺ'), // 0x4e
+    Some('ぽ'), // 0x4f
+    Some('っ'), // 0x50
+    Some('ア'), // 0x51
+    Some('イ'), // 0x52
+    Some('ウ'), // 0x53
+    Some('エ'), // 0x54
+    Some('オ'), // 0x55
+    Some('カ'), // 0x56
+    Some('キ'), // 0x57
+    Some('ク'), // 0x58
+    Some('ケ'), // 0x59
+    Some('コ'), // 0x5a
+    Some('サ'), // 0x5b
+    Some('シ'), // 0x5c
+    Some('ス'), // 0x5d
+    Some('セ'), // 0x5e
+    Some('ソ'), // 0x5f
+    Some('タ'), // 0x60
+    Some('チ'), // 0x61
+    Some('ツ'), // 0x62
+    Some('テ'), // 0x63
+    Some('ト'), // 0x64
+    Some('ナ'), // 0x65
+    Some('ニ'), // 0x66
+    Some('ヌ'), // 0x67
+    Some('ネ'), // 0x68
+    Some('ノ'), // 0x69
+    Some('ハ'), // 0x6a
+    Some('ヒ'), // 0x6b
+    Some('フ'), // 0x6c
+    Some('ヘ'), // 0x6d
+    Some('ホ'), // 0x6e
+    Some('マ'), // 0x6f
+    Some('ミ'), // 0x70
+    Some('ム'), // 0x71
+    Some('メ'), // 0x72
+    Some('モ'), // 0x73
+    Some('ヤ'), // 0x74
+    Some('ユ'), // 0x75
+    Some('ヨ'), // 0x76
+    Some('ラ'), // 0x77
+    Some('リ'), // 0x78
+    Some('ル'), // 0x79
+    Some('レ'), // 0x7a
+    Some('ロ'), // 0x7b
+    Some('ワ'), // 0x7c
+    Some('ヲ'), // 0x7d
+    Some('ン'), // 0x7e
+    Some('ァ'), // 0x7f
+    Some('ィ'), // 0x80
+    Some('ゥ'), // 0x81
+    Some('ェ'), // 0x82
+    Some('ォ'), // 0x83
+    Some('ャ'), // 0x84
+    Some('ュ'), // 0x85
+    Some('ョ'), // 0x86
+    Some('ガ'), // 0x87
+    Some('ギ'), // 0x88
+    Some('グ'), // 0x89
+    Some('ゲ'), // 0x8a
+    Some('ゴ'), // 0x8b
+    Some('ザ'), // 0x8c
+    Some('ジ'), // 0x8d
+    Some('ズ'), // 0x8e
+    Some('ゼ'), // 0x8f
+    Some('ゾ'), // 0x90
+    Some('ダ'), // 0x91
+    Some('ヂ'), // 0x92
+    Some('ヅ'), // 0x93
+    Some('デ'), // 0x94
+    Some('ド'), // 0x95
+    Some('バ'), // 0x96
+    Some('ビ'), // 0x97
+    Some('ブ'), // 0x98
+    Some('ベ'), // 0x99
+    Some('ボ'), // 0x9a
+    Some('パ'), // 0x9b
+    Some('ピ'), // 0x9c
+    Some('プ'), // 0x9d
+    Some('ペ'), // 0x9e
+    Some('ポ'), // 0x9f
+    Some('ッ'), // 0xa0
+    Some('０'), // 0xa1
+    Some('１'), // 0xa2
+    Some('２'), // 0xa3
+    Some('３'), // 0xa4
+    Some('４'), // 0xa5
+    Some('５'), // 0xa6
+    Some('６'), // 0xa7
+    Some('７'), // 0xa8
+    Some('８'), // 0xa9
+    Some('９'), // 0xaa
+    Some('！'), // 0xab
+    Some('？'), // 0xac
+    Some('。'), // 0xad
+    Some('ー'), // 0xae
+    Some('・'), // 0xaf
+    Some('‥'),  // 0xb0
+    Some('『'), // 0xb1
+    Some('』'), // 0xb2
+    Some('「'), // 0xb3
+    Some('」'), // 0xb4
+    Some('♂'),  // 0xb5
+    Some('♀'),  // 0xb6
+    Some('円'), // 0xb7
+    Some('．'), // 0xb8
+    Some('×'),  // 0xb9
+    Some('／'), // 0xba
+    Some('Ａ'), // 0xbb
+    Some('Ｂ'), // 0xbc
+    Some('Ｃ'), // 0xbd
+    Some('Ｄ'), // 0xbe
+    Some('Ｅ'), // 0xbf
+    Some('Ｆ'), // 0xc0
+    Some('Ｇ'), // 0xc1
+    Some('Ｈ'), // 0xc2
+    Some('Ｉ'), // 0xc3
+    Some('Ｊ'), // 0xc4
+    Some('Ｋ'), // 0xc5
+    Some('Ｌ'), // 0xc6
+    Some('Ｍ'), // 0xc7
+    Some('Ｎ'), // 0xc8
+    Some('Ｏ'), // 0xc9
+    Some('Ｐ'), // 0xca
+    Some('Ｑ'), // 0xcb
+    Some('Ｒ'), // 0xcc
+    Some('Ｓ'), // 0xcd
+    Some('Ｔ'), // 0xce
+    Some('Ｕ'), // 0xcf
+    Some('Ｖ'), // 0xd0
+    Some('Ｗ'), // 0xd1
+    Some('Ｘ'), // 0xd2
+    Some('Ｙ'), // 0xd3
+    Some('Ｚ'), // 0xd4
+    Some('ａ'), // 0xd5
+    Some('ｂ'), // 0xd6
+    Some('ｃ'), // 0xd7
+    Some('ｄ'), // 0xd8
+    Some('ｅ'), // 0xd9
+    Some('ｆ'), // 0xda
+    Some('ｇ'), // 0xdb
+    Some('ｈ'), // 0xdc
+    Some('ｉ'), // 0xdd
+    Some('ｊ'), // 0xde
+    Some('ｋ'), // 0xdf
+    Some('ｌ'), // 0xe0
+    Some('ｍ'), // 0xe1
+    Some('ｎ'), // 0xe2
+    Some('ｏ'), // 0xe3
+    Some('ｐ'), // 0xe4
+    Some('ｑ'), // 0xe5
+    Some('ｒ'), // 0xe6
+    Some('ｓ'), // 0xe7
+    Some('ｔ'), // 0xe8
+    Some('ｕ'), // 0xe9
+    Some('ｖ'), // 0xea
+    Some('ｗ'), // 0xeb
+    Some('ｘ'), // 0xec
+    Some('ｙ'), // 0xed
+    Some('ｚ'), // 0xee
+    Some('▶'),  // 0xef
+    Some('：'), // 0xf0
+    Some('Ä'),  // 0xf1
+    Some('Ö'),  // 0xf2
+    Some('Ü'),  // 0xf3
+    Some('ä'),  // 0xf4
+    Some('ö'),  // 0xf5
+    Some('ü'),  // 0xf6
+    None,       // 0xf7
+    None,       // 0xf8
+    None,       // 0xf9
+];
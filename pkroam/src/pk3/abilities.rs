@@ -0,0 +1,485 @@
+use super::species::NationalDexNumber;
+
+/// The English name of a Gen 3 ability, or `None` for no ability and unused ids.
+pub fn ability_name(ability_id: u8) -> Option<&'static str> {
+    ABILITY_NAMES
+        .get(usize::from(ability_id))
+        .copied()
+        .flatten()
+}
+
+/// The abilities a species can have, picked between by the ability bit. Species with a
+/// single ability have 0 as their second.
+pub fn species_abilities(national_dex_number: NationalDexNumber) -> [u8; 2] {
+    SPECIES_ABILITIES[national_dex_number.index()]
+}
+
+const ABILITY_NAMES: [Option<&str>; 78] = [
+    None,                  // 000
+    Some("Stench"),        // 001
+    Some("Drizzle"),       // 002
+    Some("Speed Boost"),   // 003
+    Some("Battle Armor"),  // 004
+    Some("Sturdy"),        // 005
+    Some("Damp"),          // 006
+    Some("Limber"),        // 007
+    Some("Sand Veil"),     // 008
+    Some("Static"),        // 009
+    Some("Volt Absorb"),   // 010
+    Some("Water Absorb"),  // 011
+    Some("Oblivious"),     // 012
+    Some("Cloud Nine"),    // 013
+    Some("Compound Eyes"), // 014
+    Some("Insomnia"),      // 015
+    Some("Color Change"),  // 016
+    Some("Immunity"),      // 017
+    Some("Flash Fire"),    // 018
+    Some("Shield Dust"),   // 019
+    Some("Own Tempo"),     // 020
+    Some("Suction Cups"),  // 021
+    Some("Intimidate"),    // 022
+    Some("Shadow Tag"),    // 023
+    Some("Rough Skin"),    // 024
+    Some("Wonder Guard"),  // 025
+    Some("Levitate"),      // 026
+    Some("Effect Spore"),  // 027
+    Some("Synchronize"),   // 028
+    Some("Clear Body"),    // 029
+    Some("Natural Cure"),  // 030
+    Some("Lightning Rod"), // 031
+    Some("Serene Grace"),  // 032
+    Some("Swift Swim"),    // 033
+    Some("Chlorophyll"),   // 034
+    Some("Illuminate"),    // 035
+    Some("Trace"),         // 036
+    Some("Huge Power"),    // 037
+    Some("Poison Point"),  // 038
+    Some("Inner Focus"),   // 039
+    Some("Magma Armor"),   // 040
+    Some("Water Veil"),    // 041
+    Some("Magnet Pull"),   // 042
+    Some("Soundproof"),    // 043
+    Some("Rain Dish"),     // 044
+    Some("Sand Stream"),   // 045
+    Some("Pressure"),      // 046
+    Some("Thick Fat"),     // 047
+    Some("Early Bird"),    // 048
+    Some("Flame Body"),    // 049
+    Some("Run Away"),      // 050
+    Some("Keen Eye"),      // 051
+    Some("Hyper Cutter"),  // 052
+    Some("Pickup"),        // 053
+    Some("Truant"),        // 054
+    Some("Hustle"),        // 055
+    Some("Cute Charm"),    // 056
+    Some("Plus"),          // 057
+    Some("Minus"),         // 058
+    Some("Forecast"),      // 059
+    Some("Sticky Hold"),   // 060
+    Some("Shed Skin"),     // 061
+    Some("Guts"),          // 062
+    Some("Marvel Scale"),  // 063
+    Some("Liquid Ooze"),   // 064
+    Some("Overgrow"),      // 065
+    Some("Blaze"),         // 066
+    Some("Torrent"),       // 067
+    Some("Swarm"),         // 068
+    Some("Rock Head"),     // 069
+    Some("Drought"),       // 070
+    Some("Arena Trap"),    // 071
+    Some("Vital Spirit"),  // 072
+    Some("White Smoke"),   // 073
+    Some("Pure Power"),    // 074
+    Some("Shell Armor"),   // 075
+    Some("Cacophony"),     // 076
+    Some("Air Lock"),      // 077
+];
+
+const SPECIES_ABILITIES: [[u8; 2]; 386] = [
+    [65, 0],  // 001 Bulbasaur
+    [65, 0],  // 002 Ivysaur
+    [65, 0],  // 003 Venusaur
+    [66, 0],  // 004 Charmander
+    [66, 0],  // 005 Charmeleon
+    [66, 0],  // 006 Charizard
+    [67, 0],  // 007 Squirtle
+    [67, 0],  // 008 Wartortle
+    [67, 0],  // 009 Blastoise
+    [19, 0],  // 010 Caterpie
+    [61, 0],  // 011 Metapod
+    [14, 0],  // 012 Butterfree
+    [19, 0],  // 013 Weedle
+    [61, 0],  // 014 Kakuna
+    [68, 0],  // 015 Beedrill
+    [51, 0],  // 016 Pidgey
+    [51, 0],  // 017 Pidgeotto
+    [51, 0],  // 018 Pidgeot
+    [50, 62], // 019 Rattata
+    [50, 62], // 020 Raticate
+    [51, 0],  // 021 Spearow
+    [51, 0],  // 022 Fearow
+    [22, 61], // 023 Ekans
+    [22, 61], // 024 Arbok
+    [9, 0],   // 025 Pikachu
+    [9, 0],   // 026 Raichu
+    [8, 0],   // 027 Sandshrew
+    [8, 0],   // 028 Sandslash
+    [38, 0],  // 029 Nidoran♀
+    [38, 0],  // 030 Nidorina
+    [38, 0],  // 031 Nidoqueen
+    [38, 0],  // 032 Nidoran♂
+    [38, 0],  // 033 Nidorino
+    [38, 0],  // 034 Nidoking
+    [56, 0],  // 035 Clefairy
+    [56, 0],  // 036 Clefable
+    [18, 0],  // 037 Vulpix
+    [18, 0],  // 038 Ninetales
+    [56, 0],  // 039 Jigglypuff
+    [56, 0],  // 040 Wigglytuff
+    [39, 0],  // 041 Zubat
+    [39, 0],  // 042 Golbat
+    [34, 0],  // 043 Oddish
+    [34, 0],  // 044 Gloom
+    [34, 0],  // 045 Vileplume
+    [27, 0],  // 046 Paras
+    [27, 0],  // 047 Parasect
+    [14, 0],  // 048 Venonat
+    [19, 0],  // 049 Venomoth
+    [8, 71],  // 050 Diglett
+    [8, 71],  // 051 Dugtrio
+    [53, 0],  // 052 Meowth
+    [7, 0],   // 053 Persian
+    [6, 13],  // 054 Psyduck
+    [6, 13],  // 055 Golduck
+    [72, 0],  // 056 Mankey
+    [72, 0],  // 057 Primeape
+    [22, 18], // 058 Growlithe
+    [22, 18], // 059 Arcanine
+    [11, 6],  // 060 Poliwag
+    [11, 6],  // 061 Poliwhirl
+    [11, 6],  // 062 Poliwrath
+    [28, 39], // 063 Abra
+    [28, 39], // 064 Kadabra
+    [28, 39], // 065 Alakazam
+    [62, 0],  // 066 Machop
+    [62, 0],  // 067 Machoke
+    [62, 0],  // 068 Machamp
+    [34, 0],  // 069 Bellsprout
+    [34, 0],  // 070 Weepinbell
+    [34, 0],  // 071 Victreebel
+    [29, 64], // 072 Tentacool
+    [29, 64], // 073 Tentacruel
+    [69, 5],  // 074 Geodude
+    [69, 5],  // 075 Graveler
+    [69, 5],  // 076 Golem
+    [50, 18], // 077 Ponyta
+    [50, 18], // 078 Rapidash
+    [12, 20], // 079 Slowpoke
+    [12, 20], // 080 Slowbro
+    [42, 5],  // 081 Magnemite
+    [42, 5],  // 082 Magneton
+    [51, 39], // 083 Farfetch'd
+    [50, 48], // 084 Doduo
+    [50, 48], // 085 Dodrio
+    [47, 0],  // 086 Seel
+    [47, 0],  // 087 Dewgong
+    [1, 60],  // 088 Grimer
+    [1, 60],  // 089 Muk
+    [75, 0],  // 090 Shellder
+    [75, 0],  // 091 Cloyster
+    [26, 0],  // 092 Gastly
+    [26, 0],  // 093 Haunter
+    [26, 0],  // 094 Gengar
+    [69, 5],  // 095 Onix
+    [15, 0],  // 096 Drowzee
+    [15, 0],  // 097 Hypno
+    [52, 75], // 098 Krabby
+    [52, 75], // 099 Kingler
+    [43, 9],  // 100 Voltorb
+    [43, 9],  // 101 Electrode
+    [34, 0],  // 102 Exeggcute
+    [34, 0],  // 103 Exeggutor
+    [69, 31], // 104 Cubone
+    [69, 31], // 105 Marowak
+    [7, 0],   // 106 Hitmonlee
+    [51, 0],  // 107 Hitmonchan
+    [20, 12], // 108 Lickitung
+    [26, 0],  // 109 Koffing
+    [26, 0],  // 110 Weezing
+    [31, 69], // 111 Rhyhorn
+    [31, 69], // 112 Rhydon
+    [30, 32], // 113 Chansey
+    [34, 0],  // 114 Tangela
+    [48, 0],  // 115 Kangaskhan
+    [33, 0],  // 116 Horsea
+    [38, 0],  // 117 Seadra
+    [33, 41], // 118 Goldeen
+    [33, 41], // 119 Seaking
+    [35, 30], // 120 Staryu
+    [35, 30], // 121 Starmie
+    [43, 0],  // 122 Mr. Mime
+    [68, 0],  // 123 Scyther
+    [12, 0],  // 124 Jynx
+    [9, 0],   // 125 Electabuzz
+    [49, 0],  // 126 Magmar
+    [52, 0],  // 127 Pinsir
+    [22, 0],  // 128 Tauros
+    [33, 0],  // 129 Magikarp
+    [22, 0],  // 130 Gyarados
+    [11, 75], // 131 Lapras
+    [7, 0],   // 132 Ditto
+    [50, 0],  // 133 Eevee
+    [11, 0],  // 134 Vaporeon
+    [10, 0],  // 135 Jolteon
+    [18, 0],  // 136 Flareon
+    [36, 0],  // 137 Porygon
+    [33, 75], // 138 Omanyte
+    [33, 75], // 139 Omastar
+    [33, 4],  // 140 Kabuto
+    [33, 4],  // 141 Kabutops
+    [69, 46], // 142 Aerodactyl
+    [17, 47], // 143 Snorlax
+    [46, 0],  // 144 Articuno
+    [46, 0],  // 145 Zapdos
+    [46, 0],  // 146 Moltres
+    [61, 0],  // 147 Dratini
+    [61, 0],  // 148 Dragonair
+    [39, 0],  // 149 Dragonite
+    [46, 0],  // 150 Mewtwo
+    [28, 0],  // 151 Mew
+    [65, 0],  // 152 Chikorita
+    [65, 0],  // 153 Bayleef
+    [65, 0],  // 154 Meganium
+    [66, 0],  // 155 Cyndaquil
+    [66, 0],  // 156 Quilava
+    [66, 0],  // 157 Typhlosion
+    [67, 0],  // 158 Totodile
+    [67, 0],  // 159 Croconaw
+    [67, 0],  // 160 Feraligatr
+    [50, 51], // 161 Sentret
+    [50, 51], // 162 Furret
+    [15, 51], // 163 Hoothoot
+    [15, 51], // 164 Noctowl
+    [68, 48], // 165 Ledyba
+    [68, 48], // 166 Ledian
+    [68, 15], // 167 Spinarak
+    [68, 15], // 168 Ariados
+    [39, 0],  // 169 Crobat
+    [10, 35], // 170 Chinchou
+    [10, 35], // 171 Lanturn
+    [9, 0],   // 172 Pichu
+    [56, 0],  // 173 Cleffa
+    [56, 0],  // 174 Igglybuff
+    [55, 32], // 175 Togepi
+    [55, 32], // 176 Togetic
+    [28, 48], // 177 Natu
+    [28, 48], // 178 Xatu
+    [9, 0],   // 179 Mareep
+    [9, 0],   // 180 Flaaffy
+    [9, 0],   // 181 Ampharos
+    [34, 0],  // 182 Bellossom
+    [47, 37], // 183 Marill
+    [47, 37], // 184 Azumarill
+    [5, 69],  // 185 Sudowoodo
+    [11, 6],  // 186 Politoed
+    [34, 0],  // 187 Hoppip
+    [34, 0],  // 188 Skiploom
+    [34, 0],  // 189 Jumpluff
+    [50, 53], // 190 Aipom
+    [34, 0],  // 191 Sunkern
+    [34, 0],  // 192 Sunflora
+    [3, 14],  // 193 Yanma
+    [6, 11],  // 194 Wooper
+    [6, 11],  // 195 Quagsire
+    [28, 0],  // 196 Espeon
+    [28, 0],  // 197 Umbreon
+    [15, 0],  // 198 Murkrow
+    [12, 20], // 199 Slowking
+    [26, 0],  // 200 Misdreavus
+    [26, 0],  // 201 Unown
+    [23, 0],  // 202 Wobbuffet
+    [39, 48], // 203 Girafarig
+    [5, 0],   // 204 Pineco
+    [5, 0],   // 205 Forretress
+    [32, 50], // 206 Dunsparce
+    [52, 8],  // 207 Gligar
+    [69, 5],  // 208 Steelix
+    [22, 50], // 209 Snubbull
+    [22, 0],  // 210 Granbull
+    [38, 33], // 211 Qwilfish
+    [68, 0],  // 212 Scizor
+    [5, 0],   // 213 Shuckle
+    [68, 62], // 214 Heracross
+    [39, 51], // 215 Sneasel
+    [53, 0],  // 216 Teddiursa
+    [62, 0],  // 217 Ursaring
+    [40, 49], // 218 Slugma
+    [40, 49], // 219 Magcargo
+    [12, 0],  // 220 Swinub
+    [12, 0],  // 221 Piloswine
+    [55, 30], // 222 Corsola
+    [55, 0],  // 223 Remoraid
+    [21, 0],  // 224 Octillery
+    [72, 55], // 225 Delibird
+    [33, 11], // 226 Mantine
+    [51, 5],  // 227 Skarmory
+    [48, 18], // 228 Houndour
+    [48, 18], // 229 Houndoom
+    [33, 0],  // 230 Kingdra
+    [53, 0],  // 231 Phanpy
+    [5, 0],   // 232 Donphan
+    [36, 0],  // 233 Porygon2
+    [22, 0],  // 234 Stantler
+    [20, 0],  // 235 Smeargle
+    [62, 0],  // 236 Tyrogue
+    [22, 0],  // 237 Hitmontop
+    [12, 0],  // 238 Smoochum
+    [9, 0],   // 239 Elekid
+    [49, 0],  // 240 Magby
+    [47, 0],  // 241 Miltank
+    [30, 32], // 242 Blissey
+    [46, 0],  // 243 Raikou
+    [46, 0],  // 244 Entei
+    [46, 0],  // 245 Suicune
+    [62, 0],  // 246 Larvitar
+    [61, 0],  // 247 Pupitar
+    [45, 0],  // 248 Tyranitar
+    [46, 0],  // 249 Lugia
+    [46, 0],  // 250 Ho-Oh
+    [30, 0],  // 251 Celebi
+    [65, 0],  // 252 Treecko
+    [65, 0],  // 253 Grovyle
+    [65, 0],  // 254 Sceptile
+    [66, 0],  // 255 Torchic
+    [66, 0],  // 256 Combusken
+    [66, 0],  // 257 Blaziken
+    [67, 0],  // 258 Mudkip
+    [67, 0],  // 259 Marshtomp
+    [67, 0],  // 260 Swampert
+    [50, 0],  // 261 Poochyena
+    [22, 0],  // 262 Mightyena
+    [53, 0],  // 263 Zigzagoon
+    [53, 0],  // 264 Linoone
+    [19, 0],  // 265 Wurmple
+    [61, 0],  // 266 Silcoon
+    [68, 0],  // 267 Beautifly
+    [61, 0],  // 268 Cascoon
+    [19, 0],  // 269 Dustox
+    [33, 44], // 270 Lotad
+    [33, 44], // 271 Lombre
+    [33, 44], // 272 Ludicolo
+    [34, 48], // 273 Seedot
+    [34, 48], // 274 Nuzleaf
+    [34, 48], // 275 Shiftry
+    [62, 0],  // 276 Taillow
+    [62, 0],  // 277 Swellow
+    [51, 0],  // 278 Wingull
+    [51, 0],  // 279 Pelipper
+    [28, 36], // 280 Ralts
+    [28, 36], // 281 Kirlia
+    [28, 36], // 282 Gardevoir
+    [33, 0],  // 283 Surskit
+    [22, 0],  // 284 Masquerain
+    [27, 0],  // 285 Shroomish
+    [27, 0],  // 286 Breloom
+    [54, 0],  // 287 Slakoth
+    [72, 0],  // 288 Vigoroth
+    [54, 0],  // 289 Slaking
+    [14, 0],  // 290 Nincada
+    [3, 0],   // 291 Ninjask
+    [25, 0],  // 292 Shedinja
+    [43, 0],  // 293 Whismur
+    [43, 0],  // 294 Loudred
+    [43, 0],  // 295 Exploud
+    [47, 62], // 296 Makuhita
+    [47, 62], // 297 Hariyama
+    [47, 37], // 298 Azurill
+    [5, 42],  // 299 Nosepass
+    [56, 0],  // 300 Skitty
+    [56, 0],  // 301 Delcatty
+    [51, 0],  // 302 Sableye
+    [52, 22], // 303 Mawile
+    [5, 69],  // 304 Aron
+    [5, 69],  // 305 Lairon
+    [5, 69],  // 306 Aggron
+    [74, 0],  // 307 Meditite
+    [74, 0],  // 308 Medicham
+    [9, 31],  // 309 Electrike
+    [9, 31],  // 310 Manectric
+    [57, 0],  // 311 Plusle
+    [58, 0],  // 312 Minun
+    [35, 68], // 313 Volbeat
+    [12, 0],  // 314 Illumise
+    [30, 38], // 315 Roselia
+    [64, 60], // 316 Gulpin
+    [64, 60], // 317 Swalot
+    [24, 0],  // 318 Carvanha
+    [24, 0],  // 319 Sharpedo
+    [41, 12], // 320 Wailmer
+    [41, 12], // 321 Wailord
+    [12, 0],  // 322 Numel
+    [40, 0],  // 323 Camerupt
+    [73, 0],  // 324 Torkoal
+    [47, 20], // 325 Spoink
+    [47, 20], // 326 Grumpig
+    [20, 0],  // 327 Spinda
+    [52, 71], // 328 Trapinch
+    [26, 0],  // 329 Vibrava
+    [26, 0],  // 330 Flygon
+    [8, 0],   // 331 Cacnea
+    [8, 0],   // 332 Cacturne
+    [30, 0],  // 333 Swablu
+    [30, 0],  // 334 Altaria
+    [17, 0],  // 335 Zangoose
+    [61, 0],  // 336 Seviper
+    [26, 0],  // 337 Lunatone
+    [26, 0],  // 338 Solrock
+    [12, 0],  // 339 Barboach
+    [12, 0],  // 340 Whiscash
+    [52, 75], // 341 Corphish
+    [52, 75], // 342 Crawdaunt
+    [26, 0],  // 343 Baltoy
+    [26, 0],  // 344 Claydol
+    [21, 0],  // 345 Lileep
+    [21, 0],  // 346 Cradily
+    [4, 0],   // 347 Anorith
+    [4, 0],   // 348 Armaldo
+    [33, 0],  // 349 Feebas
+    [63, 0],  // 350 Milotic
+    [59, 0],  // 351 Castform
+    [16, 0],  // 352 Kecleon
+    [15, 0],  // 353 Shuppet
+    [15, 0],  // 354 Banette
+    [26, 0],  // 355 Duskull
+    [46, 0],  // 356 Dusclops
+    [34, 0],  // 357 Tropius
+    [26, 0],  // 358 Chimecho
+    [46, 0],  // 359 Absol
+    [23, 0],  // 360 Wynaut
+    [39, 0],  // 361 Snorunt
+    [39, 0],  // 362 Glalie
+    [47, 0],  // 363 Spheal
+    [47, 0],  // 364 Sealeo
+    [47, 0],  // 365 Walrein
+    [75, 0],  // 366 Clamperl
+    [33, 0],  // 367 Huntail
+    [33, 0],  // 368 Gorebyss
+    [33, 69], // 369 Relicanth
+    [33, 0],  // 370 Luvdisc
+    [69, 0],  // 371 Bagon
+    [69, 0],  // 372 Shelgon
+    [22, 0],  // 373 Salamence
+    [29, 0],  // 374 Beldum
+    [29, 0],  // 375 Metang
+    [29, 0],  // 376 Metagross
+    [29, 0],  // 377 Regirock
+    [29, 0],  // 378 Regice
+    [29, 0],  // 379 Registeel
+    [26, 0],  // 380 Latias
+    [26, 0],  // 381 Latios
+    [2, 0],   // 382 Kyogre
+    [70, 0],  // 383 Groudon
+    [77, 0],  // 384 Rayquaza
+    [32, 0],  // 385 Jirachi
+    [46, 0],  // 386 Deoxys
+];
@@ -0,0 +1,435 @@
+use super::species::NationalDexNumber;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Gender {
+    Male,
+    Female,
+    Genderless,
+}
+
+/// How a species' gender is chosen. Mixed species are female when the low byte of the
+/// personality value is below the threshold.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GenderRatio {
+    Genderless,
+    MaleOnly,
+    FemaleOnly,
+    Mixed(u8),
+}
+
+impl GenderRatio {
+    pub fn for_national_dex_number(national_dex_number: NationalDexNumber) -> Self {
+        match GENDER_THRESHOLDS[national_dex_number.index()] {
+            0 => GenderRatio::MaleOnly,
+            254 => GenderRatio::FemaleOnly,
+            255 => GenderRatio::Genderless,
+            threshold => GenderRatio::Mixed(threshold),
+        }
+    }
+
+    pub fn gender_for_personality_value(&self, personality_value: u32) -> Gender {
+        match self {
+            GenderRatio::Genderless => Gender::Genderless,
+            GenderRatio::MaleOnly => Gender::Male,
+            GenderRatio::FemaleOnly => Gender::Female,
+            GenderRatio::Mixed(threshold) => {
+                if ((personality_value & 0xff) as u8) < *threshold {
+                    Gender::Female
+                } else {
+                    Gender::Male
+                }
+            }
+        }
+    }
+}
+
+/// The gender byte from each species' base stats in the games: 0 is male only, 254 is female
+/// only, 255 is genderless and anything else is the female threshold.
+const GENDER_THRESHOLDS: [u8; 386] = [
+    31,  // 001 Bulbasaur
+    31,  // 002 Ivysaur
+    31,  // 003 Venusaur
+    31,  // 004 Charmander
+    31,  // 005 Charmeleon
+    31,  // 006 Charizard
+    31,  // 007 Squirtle
+    31,  // 008 Wartortle
+    31,  // 009 Blastoise
+    127, // 010 Caterpie
+    127, // 011 Metapod
+    127, // 012 Butterfree
+    127, // 013 Weedle
+    127, // 014 Kakuna
+    127, // 015 Beedrill
+    127, // 016 Pidgey
+    127, // 017 Pidgeotto
+    127, // 018 Pidgeot
+    127, // 019 Rattata
+    127, // 020 Raticate
+    127, // 021 Spearow
+    127, // 022 Fearow
+    127, // 023 Ekans
+    127, // 024 Arbok
+    127, // 025 Pikachu
+    127, // 026 Raichu
+    127, // 027 Sandshrew
+    127, // 028 Sandslash
+    254, // 029 Nidoran♀
+    254, // 030 Nidorina
+    254, // 031 Nidoqueen
+    0,   // 032 Nidoran♂
+    0,   // 033 Nidorino
+    0,   // 034 Nidoking
+    191, // 035 Clefairy
+    191, // 036 Clefable
+    191, // 037 Vulpix
+    191, // 038 Ninetales
+    191, // 039 Jigglypuff
+    191, // 040 Wigglytuff
+    127, // 041 Zubat
+    127, // 042 Golbat
+    127, // 043 Oddish
+    127, // 044 Gloom
+    127, // 045 Vileplume
+    127, // 046 Paras
+    127, // 047 Parasect
+    127, // 048 Venonat
+    127, // 049 Venomoth
+    127, // 050 Diglett
+    127, // 051 Dugtrio
+    127, // 052 Meowth
+    127, // 053 Persian
+    127, // 054 Psyduck
+    127, // 055 Golduck
+    127, // 056 Mankey
+    127, // 057 Primeape
+    63,  // 058 Growlithe
+    63,  // 059 Arcanine
+    127, // 060 Poliwag
+    127, // 061 Poliwhirl
+    127, // 062 Poliwrath
+    63,  // 063 Abra
+    63,  // 064 Kadabra
+    63,  // 065 Alakazam
+    63,  // 066 Machop
+    63,  // 067 Machoke
+    63,  // 068 Machamp
+    127, // 069 Bellsprout
+    127, // 070 Weepinbell
+    127, // 071 Victreebel
+    127, // 072 Tentacool
+    127, // 073 Tentacruel
+    127, // 074 Geodude
+    127, // 075 Graveler
+    127, // 076 Golem
+    127, // 077 Ponyta
+    127, // 078 Rapidash
+    127, // 079 Slowpoke
+    127, // 080 Slowbro
+    255, // 081 Magnemite
+    255, // 082 Magneton
+    127, // 083 Farfetch'd
+    127, // 084 Doduo
+    127, // 085 Dodrio
+    127, // 086 Seel
+    127, // 087 Dewgong
+    127, // 088 Grimer
+    127, // 089 Muk
+    127, // 090 Shellder
+    127, // 091 Cloyster
+    127, // 092 Gastly
+    127, // 093 Haunter
+    127, // 094 Gengar
+    127, // 095 Onix
+    127, // 096 Drowzee
+    127, // 097 Hypno
+    127, // 098 Krabby
+    127, // 099 Kingler
+    255, // 100 Voltorb
+    255, // 101 Electrode
+    127, // 102 Exeggcute
+    127, // 103 Exeggutor
+    127, // 104 Cubone
+    127, // 105 Marowak
+    0,   // 106 Hitmonlee
+    0,   // 107 Hitmonchan
+    127, // 108 Lickitung
+    127, // 109 Koffing
+    127, // 110 Weezing
+    127, // 111 Rhyhorn
+    127, // 112 Rhydon
+    254, // 113 Chansey
+    127, // 114 Tangela
+    254, // 115 Kangaskhan
+    127, // 116 Horsea
+    127, // 117 Seadra
+    127, // 118 Goldeen
+    127, // 119 Seaking
+    255, // 120 Staryu
+    255, // 121 Starmie
+    127, // 122 Mr. Mime
+    127, // 123 Scyther
+    254, // 124 Jynx
+    63,  // 125 Electabuzz
+    63,  // 126 Magmar
+    127, // 127 Pinsir
+    0,   // 128 Tauros
+    127, // 129 Magikarp
+    127, // 130 Gyarados
+    127, // 131 Lapras
+    255, // 132 Ditto
+    31,  // 133 Eevee
+    31,  // 134 Vaporeon
+    31,  // 135 Jolteon
+    31,  // 136 Flareon
+    255, // 137 Porygon
+    31,  // 138 Omanyte
+    31,  // 139 Omastar
+    31,  // 140 Kabuto
+    31,  // 141 Kabutops
+    31,  // 142 Aerodactyl
+    31,  // 143 Snorlax
+    255, // 144 Articuno
+    255, // 145 Zapdos
+    255, // 146 Moltres
+    127, // 147 Dratini
+    127, // 148 Dragonair
+    127, // 149 Dragonite
+    255, // 150 Mewtwo
+    255, // 151 Mew
+    31,  // 152 Chikorita
+    31,  // 153 Bayleef
+    31,  // 154 Meganium
+    31,  // 155 Cyndaquil
+    31,  // 156 Quilava
+    31,  // 157 Typhlosion
+    31,  // 158 Totodile
+    31,  // 159 Croconaw
+    31,  // 160 Feraligatr
+    127, // 161 Sentret
+    127, // 162 Furret
+    127, // 163 Hoothoot
+    127, // 164 Noctowl
+    127, // 165 Ledyba
+    127, // 166 Ledian
+    127, // 167 Spinarak
+    127, // 168 Ariados
+    127, // 169 Crobat
+    127, // 170 Chinchou
+    127, // 171 Lanturn
+    127, // 172 Pichu
+    191, // 173 Cleffa
+    191, // 174 Igglybuff
+    31,  // 175 Togepi
+    31,  // 176 Togetic
+    127, // 177 Natu
+    127, // 178 Xatu
+    127, // 179 Mareep
+    127, // 180 Flaaffy
+    127, // 181 Ampharos
+    127, // 182 Bellossom
+    127, // 183 Marill
+    127, // 184 Azumarill
+    127, // 185 Sudowoodo
+    127, // 186 Politoed
+    127, // 187 Hoppip
+    127, // 188 Skiploom
+    127, // 189 Jumpluff
+    127, // 190 Aipom
+    127, // 191 Sunkern
+    127, // 192 Sunflora
+    127, // 193 Yanma
+    127, // 194 Wooper
+    127, // 195 Quagsire
+    31,  // 196 Espeon
+    31,  // 197 Umbreon
+    127, // 198 Murkrow
+    127, // 199 Slowking
+    127, // 200 Misdreavus
+    255, // 201 Unown
+    127, // 202 Wobbuffet
+    127, // 203 Girafarig
+    127, // 204 Pineco
+    127, // 205 Forretress
+    127, // 206 Dunsparce
+    127, // 207 Gligar
+    127, // 208 Steelix
+    191, // 209 Snubbull
+    191, // 210 Granbull
+    127, // 211 Qwilfish
+    127, // 212 Scizor
+    127, // 213 Shuckle
+    127, // 214 Heracross
+    127, // 215 Sneasel
+    127, // 216 Teddiursa
+    127, // 217 Ursaring
+    127, // 218 Slugma
+    127, // 219 Magcargo
+    127, // 220 Swinub
+    127, // 221 Piloswine
+    191, // 222 Corsola
+    127, // 223 Remoraid
+    127, // 224 Octillery
+    127, // 225 Delibird
+    127, // 226 Mantine
+    127, // 227 Skarmory
+    127, // 228 Houndour
+    127, // 229 Houndoom
+    127, // 230 Kingdra
+    127, // 231 Phanpy
+    127, // 232 Donphan
+    255, // 233 Porygon2
+    127, // 234 Stantler
+    127, // 235 Smeargle
+    0,   // 236 Tyrogue
+    0,   // 237 Hitmontop
+    254, // 238 Smoochum
+    63,  // 239 Elekid
+    63,  // 240 Magby
+    254, // 241 Miltank
+    254, // 242 Blissey
+    255, // 243 Raikou
+    255, // 244 Entei
+    255, // 245 Suicune
+    127, // 246 Larvitar
+    127, // 247 Pupitar
+    127, // 248 Tyranitar
+    255, // 249 Lugia
+    255, // 250 Ho-Oh
+    255, // 251 Celebi
+    31,  // 252 Treecko
+    31,  // 253 Grovyle
+    31,  // 254 Sceptile
+    31,  // 255 Torchic
+    31,  // 256 Combusken
+    31,  // 257 Blaziken
+    31,  // 258 Mudkip
+    31,  // 259 Marshtomp
+    31,  // 260 Swampert
+    127, // 261 Poochyena
+    127, // 262 Mightyena
+    127, // 263 Zigzagoon
+    127, // 264 Linoone
+    127, // 265 Wurmple
+    127, // 266 Silcoon
+    127, // 267 Beautifly
+    127, // 268 Cascoon
+    127, // 269 Dustox
+    127, // 270 Lotad
+    127, // 271 Lombre
+    127, // 272 Ludicolo
+    127, // 273 Seedot
+    127, // 274 Nuzleaf
+    127, // 275 Shiftry
+    127, // 276 Taillow
+    127, // 277 Swellow
+    127, // 278 Wingull
+    127, // 279 Pelipper
+    127, // 280 Ralts
+    127, // 281 Kirlia
+    127, // 282 Gardevoir
+    127, // 283 Surskit
+    127, // 284 Masquerain
+    127, // 285 Shroomish
+    127, // 286 Breloom
+    127, // 287 Slakoth
+    127, // 288 Vigoroth
+    127, // 289 Slaking
+    127, // 290 Nincada
+    127, // 291 Ninjask
+    255, // 292 Shedinja
+    127, // 293 Whismur
+    127, // 294 Loudred
+    127, // 295 Exploud
+    63,  // 296 Makuhita
+    63,  // 297 Hariyama
+    191, // 298 Azurill
+    127, // 299 Nosepass
+    191, // 300 Skitty
+    191, // 301 Delcatty
+    127, // 302 Sableye
+    127, // 303 Mawile
+    127, // 304 Aron
+    127, // 305 Lairon
+    127, // 306 Aggron
+    127, // 307 Meditite
+    127, // 308 Medicham
+    127, // 309 Electrike
+    127, // 310 Manectric
+    127, // 311 Plusle
+    127, // 312 Minun
+    0,   // 313 Volbeat
+    254, // 314 Illumise
+    127, // 315 Roselia
+    127, // 316 Gulpin
+    127, // 317 Swalot
+    127, // 318 Carvanha
+    127, // 319 Sharpedo
+    127, // 320 Wailmer
+    127, // 321 Wailord
+    127, // 322 Numel
+    127, // 323 Camerupt
+    127, // 324 Torkoal
+    127, // 325 Spoink
+    127, // 326 Grumpig
+    127, // 327 Spinda
+    127, // 328 Trapinch
+    127, // 329 Vibrava
+    127, // 330 Flygon
+    127, // 331 Cacnea
+    127, // 332 Cacturne
+    127, // 333 Swablu
+    127, // 334 Altaria
+    127, // 335 Zangoose
+    127, // 336 Seviper
+    255, // 337 Lunatone
+    255, // 338 Solrock
+    127, // 339 Barboach
+    127, // 340 Whiscash
+    127, // 341 Corphish
+    127, // 342 Crawdaunt
+    255, // 343 Baltoy
+    255, // 344 Claydol
+    31,  // 345 Lileep
+    31,  // 346 Cradily
+    31,  // 347 Anorith
+    31,  // 348 Armaldo
+    127, // 349 Feebas
+    127, // 350 Milotic
+    127, // 351 Castform
+    127, // 352 Kecleon
+    127, // 353 Shuppet
+    127, // 354 Banette
+    127, // 355 Duskull
+    127, // 356 Dusclops
+    127, // 357 Tropius
+    127, // 358 Chimecho
+    127, // 359 Absol
+    127, // 360 Wynaut
+    127, // 361 Snorunt
+    127, // 362 Glalie
+    127, // 363 Spheal
+    127, // 364 Sealeo
+    127, // 365 Walrein
+    127, // 366 Clamperl
+    127, // 367 Huntail
+    127, // 368 Gorebyss
+    31,  // 369 Relicanth
+    191, // 370 Luvdisc
+    127, // 371 Bagon
+    127, // 372 Shelgon
+    127, // 373 Salamence
+    255, // 374 Beldum
+    255, // 375 Metang
+    255, // 376 Metagross
+    255, // 377 Regirock
+    255, // 378 Regice
+    255, // 379 Registeel
+    254, // 380 Latias
+    0,   // 381 Latios
+    255, // 382 Kyogre
+    255, // 383 Groudon
+    255, // 384 Rayquaza
+    255, // 385 Jirachi
+    255, // 386 Deoxys
+];
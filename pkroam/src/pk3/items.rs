@@ -0,0 +1,384 @@
+/// The English name of a Gen 3 item, or `None` for no item and unused ids.
+pub fn item_name(item_id: u16) -> Option<&'static str> {
+    ITEM_NAMES.get(usize::from(item_id)).copied().flatten()
+}
+
+const ITEM_NAMES: [Option<&str>; 377] = [
+    None,                   // 000
+    Some("Master Ball"),    // 001
+    Some("Ultra Ball"),     // 002
+    Some("Great Ball"),     // 003
+    Some("Poké Ball"),      // 004
+    Some("Safari Ball"),    // 005
+    Some("Net Ball"),       // 006
+    Some("Dive Ball"),      // 007
+    Some("Nest Ball"),      // 008
+    Some("Repeat Ball"),    // 009
+    Some("Timer Ball"),     // 010
+    Some("Luxury Ball"),    // 011
+    Some("Premier Ball"),   // 012
+    Some("Potion"),         // 013
+    Some("Antidote"),       // 014
+    Some("Burn Heal"),      // 015
+    Some("Ice Heal"),       // 016
+    Some("Awakening"),      // 017
+    Some("Paralyze Heal"),  // 018
+    Some("Full Restore"),   // 019
+    Some("Max Potion"),     // 020
+    Some("Hyper Potion"),   // 021
+    Some("Super Potion"),   // 022
+    Some("Full Heal"),      // 023
+    Some("Revive"),         // 024
+    Some("Max Revive"),     // 025
+    Some("Fresh Water"),    // 026
+    Some("Soda Pop"),       // 027
+    Some("Lemonade"),       // 028
+    Some("Moomoo Milk"),    // 029
+    Some("Energy Powder"),  // 030
+    Some("Energy Root"),    // 031
+    Some("Heal Powder"),    // 032
+    Some("Revival Herb"),   // 033
+    Some("Ether"),          // 034
+    Some("Max Ether"),      // 035
+    Some("Elixir"),         // 036
+    Some("Max Elixir"),     // 037
+    Some("Lava Cookie"),    // 038
+    Some("Blue Flute"),     // 039
+    Some("Yellow Flute"),   // 040
+    Some("Red Flute"),      // 041
+    Some("Black Flute"),    // 042
+    Some("White Flute"),    // 043
+    Some("Berry Juice"),    // 044
+    Some("Sacred Ash"),     // 045
+    Some("Shoal Salt"),     // 046
+    Some("Shoal Shell"),    // 047
+    Some("Red Shard"),      // 048
+    Some("Blue Shard"),     // 049
+    Some("Yellow Shard"),   // 050
+    Some("Green Shard"),    // 051
+    None,                   // 052
+    None,                   // 053
+    None,                   // 054
+    None,                   // 055
+    None,                   // 056
+    None,                   // 057
+    None,                   // 058
+    None,                   // 059
+    None,                   // 060
+    None,                   // 061
+    None,                   // 062
+    Some("HP Up"),          // 063
+    Some("Protein"),        // 064
+    Some("Iron"),           // 065
+    Some("Carbos"),         // 066
+    Some("Calcium"),        // 067
+    Some("Rare Candy"),     // 068
+    Some("PP Up"),          // 069
+    Some("Zinc"),           // 070
+    Some("PP Max"),         // 071
+    None,                   // 072
+    Some("Guard Spec."),    // 073
+    Some("Dire Hit"),       // 074
+    Some("X Attack"),       // 075
+    Some("X Defense"),      // 076
+    Some("X Speed"),        // 077
+    Some("X Accuracy"),     // 078
+    Some("X Special"),      // 079
+    Some("Poké Doll"),      // 080
+    Some("Fluffy Tail"),    // 081
+    None,                   // 082
+    Some("Super Repel"),    // 083
+    Some("Max Repel"),      // 084
+    Some("Escape Rope"),    // 085
+    Some("Repel"),          // 086
+    None,                   // 087
+    None,                   // 088
+    None,                   // 089
+    None,                   // 090
+    None,                   // 091
+    None,                   // 092
+    Some("Sun Stone"),      // 093
+    Some("Moon Stone"),     // 094
+    Some("Fire Stone"),     // 095
+    Some("Thunder Stone"),  // 096
+    Some("Water Stone"),    // 097
+    Some("Leaf Stone"),     // 098
+    None,                   // 099
+    None,                   // 100
+    None,                   // 101
+    None,                   // 102
+    Some("Tiny Mushroom"),  // 103
+    Some("Big Mushroom"),   // 104
+    None,                   // 105
+    Some("Pearl"),          // 106
+    Some("Big Pearl"),      // 107
+    Some("Stardust"),       // 108
+    Some("Star Piece"),     // 109
+    Some("Nugget"),         // 110
+    Some("Heart Scale"),    // 111
+    None,                   // 112
+    None,                   // 113
+    None,                   // 114
+    None,                   // 115
+    None,                   // 116
+    None,                   // 117
+    None,                   // 118
+    None,                   // 119
+    None,                   // 120
+    Some("Orange Mail"),    // 121
+    Some("Harbor Mail"),    // 122
+    Some("Glitter Mail"),   // 123
+    Some("Mech Mail"),      // 124
+    Some("Wood Mail"),      // 125
+    Some("Wave Mail"),      // 126
+    Some("Bead Mail"),      // 127
+    Some("Shadow Mail"),    // 128
+    Some("Tropic Mail"),    // 129
+    Some("Dream Mail"),     // 130
+    Some("Fab Mail"),       // 131
+    Some("Retro Mail"),     // 132
+    Some("Cheri Berry"),    // 133
+    Some("Chesto Berry"),   // 134
+    Some("Pecha Berry"),    // 135
+    Some("Rawst Berry"),    // 136
+    Some("Aspear Berry"),   // 137
+    Some("Leppa Berry"),    // 138
+    Some("Oran Berry"),     // 139
+    Some("Persim Berry"),   // 140
+    Some("Lum Berry"),      // 141
+    Some("Sitrus Berry"),   // 142
+    Some("Figy Berry"),     // 143
+    Some("Wiki Berry"),     // 144
+    Some("Mago Berry"),     // 145
+    Some("Aguav Berry"),    // 146
+    Some("Iapapa Berry"),   // 147
+    Some("Razz Berry"),     // 148
+    Some("Bluk Berry"),     // 149
+    Some("Nanab Berry"),    // 150
+    Some("Wepear Berry"),   // 151
+    Some("Pinap Berry"),    // 152
+    Some("Pomeg Berry"),    // 153
+    Some("Kelpsy Berry"),   // 154
+    Some("Qualot Berry"),   // 155
+    Some("Hondew Berry"),   // 156
+    Some("Grepa Berry"),    // 157
+    Some("Tamato Berry"),   // 158
+    Some("Cornn Berry"),    // 159
+    Some("Magost Berry"),   // 160
+    Some("Rabuta Berry"),   // 161
+    Some("Nomel Berry"),    // 162
+    Some("Spelon Berry"),   // 163
+    Some("Pamtre Berry"),   // 164
+    Some("Watmel Berry"),   // 165
+    Some("Durin Berry"),    // 166
+    Some("Belue Berry"),    // 167
+    Some("Liechi Berry"),   // 168
+    Some("Ganlon Berry"),   // 169
+    Some("Salac Berry"),    // 170
+    Some("Petaya Berry"),   // 171
+    Some("Apicot Berry"),   // 172
+    Some("Lansat Berry"),   // 173
+    Some("Starf Berry"),    // 174
+    Some("Enigma Berry"),   // 175
+    None,                   // 176
+    None,                   // 177
+    None,                   // 178
+    Some("Bright Powder"),  // 179
+    Some("White Herb"),     // 180
+    Some("Macho Brace"),    // 181
+    Some("Exp. Share"),     // 182
+    Some("Quick Claw"),     // 183
+    Some("Soothe Bell"),    // 184
+    Some("Mental Herb"),    // 185
+    Some("Choice Band"),    // 186
+    Some("King's Rock"),    // 187
+    Some("Silver Powder"),  // 188
+    Some("Amulet Coin"),    // 189
+    Some("Cleanse Tag"),    // 190
+    Some("Soul Dew"),       // 191
+    Some("Deep Sea Tooth"), // 192
+    Some("Deep Sea Scale"), // 193
+    Some("Smoke Ball"),     // 194
+    Some("Everstone"),      // 195
+    Some("Focus Band"),     // 196
+    Some("Lucky Egg"),      // 197
+    Some("Scope Lens"),     // 198
+    Some("Metal Coat"),     // 199
+    Some("Leftovers"),      // 200
+    Some("Dragon Scale"),   // 201
+    Some("Light Ball"),     // 202
+    Some("Soft Sand"),      // 203
+    Some("Hard Stone"),     // 204
+    Some("Miracle Seed"),   // 205
+    Some("Black Glasses"),  // 206
+    Some("Black Belt"),     // 207
+    Some("Magnet"),         // 208
+    Some("Mystic Water"),   // 209
+    Some("Sharp Beak"),     // 210
+    Some("Poison Barb"),    // 211
+    Some("Never-Melt Ice"), // 212
+    Some("Spell Tag"),      // 213
+    Some("Twisted Spoon"),  // 214
+    Some("Charcoal"),       // 215
+    Some("Dragon Fang"),    // 216
+    Some("Silk Scarf"),     // 217
+    Some("Up-Grade"),       // 218
+    Some("Shell Bell"),     // 219
+    Some("Sea Incense"),    // 220
+    Some("Lax Incense"),    // 221
+    Some("Lucky Punch"),    // 222
+    Some("Metal Powder"),   // 223
+    Some("Thick Club"),     // 224
+    Some("Stick"),          // 225
+    None,                   // 226
+    None,                   // 227
+    None,                   // 228
+    None,                   // 229
+    None,                   // 230
+    None,                   // 231
+    None,                   // 232
+    None,                   // 233
+    None,                   // 234
+    None,                   // 235
+    None,                   // 236
+    None,                   // 237
+    None,                   // 238
+    None,                   // 239
+    None,                   // 240
+    None,                   // 241
+    None,                   // 242
+    None,                   // 243
+    None,                   // 244
+    None,                   // 245
+    None,                   // 246
+    None,                   // 247
+    None,                   // 248
+    None,                   // 249
+    None,                   // 250
+    None,                   // 251
+    None,                   // 252
+    None,                   // 253
+    Some("Red Scarf"),      // 254
+    Some("Blue Scarf"),     // 255
+    Some("Pink Scarf"),     // 256
+    Some("Green Scarf"),    // 257
+    Some("Yellow Scarf"),   // 258
+    Some("Mach Bike"),      // 259
+    Some("Coin Case"),      // 260
+    Some("Itemfinder"),     // 261
+    Some("Old Rod"),        // 262
+    Some("Good Rod"),       // 263
+    Some("Super Rod"),      // 264
+    Some("S.S. Ticket"),    // 265
+    Some("Contest Pass"),   // 266
+    None,                   // 267
+    Some("Wailmer Pail"),   // 268
+    Some("Devon Goods"),    // 269
+    Some("Soot Sack"),      // 270
+    Some("Basement Key"),   // 271
+    Some("Acro Bike"),      // 272
+    Some("Pokéblock Case"), // 273
+    Some("Letter"),         // 274
+    Some("Eon Ticket"),     // 275
+    Some("Red Orb"),        // 276
+    Some("Blue Orb"),       // 277
+    Some("Scanner"),        // 278
+    Some("Go-Goggles"),     // 279
+    Some("Meteorite"),      // 280
+    Some("Rm. 1 Key"),      // 281
+    Some("Rm. 2 Key"),      // 282
+    Some("Rm. 4 Key"),      // 283
+    Some("Rm. 6 Key"),      // 284
+    Some("Storage Key"),    // 285
+    Some("Root Fossil"),    // 286
+    Some("Claw Fossil"),    // 287
+    Some("Devon Scope"),    // 288
+    Some("TM01"),           // 289
+    Some("TM02"),           // 290
+    Some("TM03"),           // 291
+    Some("TM04"),           // 292
+    Some("TM05"),           // 293
+    Some("TM06"),           // 294
+    Some("TM07"),           // 295
+    Some("TM08"),           // 296
+    Some("TM09"),           // 297
+    Some("TM10"),           // 298
+    Some("TM11"),           // 299
+    Some("TM12"),           // 300
+    Some("TM13"),           // 301
+    Some("TM14"),           // 302
+    Some("TM15"),           // 303
+    Some("TM16"),           // 304
+    Some("TM17"),           // 305
+    Some("TM18"),           // 306
+    Some("TM19"),           // 307
+    Some("TM20"),           // 308
+    Some("TM21"),           // 309
+    Some("TM22"),           // 310
+    Some("TM23"),           // 311
+    Some("TM24"),           // 312
+    Some("TM25"),           // 313
+    Some("TM26"),           // 314
+    Some("TM27"),           // 315
+    Some("TM28"),           // 316
+    Some("TM29"),           // 317
+    Some("TM30"),           // 318
+    Some("TM31"),           // 319
+    Some("TM32"),           // 320
+    Some("TM33"),           // 321
+    Some("TM34"),           // 322
+    Some("TM35"),           // 323
+    Some("TM36"),           // 324
+    Some("TM37"),           // 325
+    Some("TM38"),           // 326
+    Some("TM39"),           // 327
+    Some("TM40"),           // 328
+    Some("TM41"),           // 329
+    Some("TM42"),           // 330
+    Some("TM43"),           // 331
+    Some("TM44"),           // 332
+    Some("TM45"),           // 333
+    Some("TM46"),           // 334
+    Some("TM47"),           // 335
+    Some("TM48"),           // 336
+    Some("TM49"),           // 337
+    Some("TM50"),           // 338
+    Some("HM01"),           // 339
+    Some("HM02"),           // 340
+    Some("HM03"),           // 341
+    Some("HM04"),           // 342
+    Some("HM05"),           // 343
+    Some("HM06"),           // 344
+    Some("HM07"),           // 345
+    Some("HM08"),           // 346
+    None,                   // 347
+    None,                   // 348
+    Some("Oak's Parcel"),   // 349
+    Some("Poké Flute"),     // 350
+    Some("Secret Key"),     // 351
+    Some("Bike Voucher"),   // 352
+    Some("Gold Teeth"),     // 353
+    Some("Old Amber"),      // 354
+    Some("Card Key"),       // 355
+    Some("Lift Key"),       // 356
+    Some("Helix Fossil"),   // 357
+    Some("Dome Fossil"),    // 358
+    Some("Silph Scope"),    // 359
+    Some("Bicycle"),        // 360
+    Some("Town Map"),       // 361
+    Some("VS Seeker"),      // 362
+    Some("Fame Checker"),   // 363
+    Some("TM Case"),        // 364
+    Some("Berry Pouch"),    // 365
+    Some("Teachy TV"),      // 366
+    Some("Tri-Pass"),       // 367
+    Some("Rainbow Pass"),   // 368
+    Some("Tea"),            // 369
+    Some("MysticTicket"),   // 370
+    Some("AuroraTicket"),   // 371
+    Some("Powder Jar"),     // 372
+    Some("Ruby"),           // 373
+    Some("Sapphire"),       // 374
+    Some("Magma Emblem"),   // 375
+    Some("Old Sea Map"),    // 376
+];
@@ -1,19 +1,47 @@
 use byteorder::{ByteOrder, LittleEndian, ReadBytesExt, WriteBytesExt};
-use std::{
-    convert::Infallible,
-    io::{Cursor, Read, Seek, SeekFrom, Write},
-};
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 
+pub mod abilities;
+pub mod gender;
+pub mod growth;
+pub mod items;
+pub mod moves;
+pub mod nature;
+pub mod showdown;
 pub mod species;
+pub mod stats;
+use gender::Gender;
+use growth::MAX_LEVEL;
+use nature::Nature;
 use species::Species;
+use stats::{BaseStats, Stats};
 
-use super::{decode_text, TrainerId};
+use super::{decode_text, decode_text_for_language, encode_text, TrainerId};
 
 pub const PK3_SIZE_PARTY: usize = 100;
 pub const PK3_SIZE_BOX: usize = 80;
 const SUBSTRUCTURE_OFFSET: u64 = 32;
+const PARTY_LEVEL_OFFSET: usize = 84;
+const PARTY_MAIL_OFFSET: usize = 85;
+const NO_MAIL: u8 = 0xff;
+/// Current HP, then max HP and the other stats in the order of the IVs.
+const PARTY_STATS_OFFSET: usize = 86;
+const OT_ID_OFFSET: usize = 4;
+const NICKNAME_OFFSET: usize = 8;
+const NICKNAME_LENGTH: usize = 10;
+const OT_NAME_OFFSET: usize = 20;
+const OT_NAME_LENGTH: usize = 7;
+/// The index of each stat in the IVs and EVs with its Showdown label, in Showdown's order.
+const SHOWDOWN_STAT_ORDER: [(usize, &str); 6] = [
+    (0, "HP"),
+    (1, "Atk"),
+    (2, "Def"),
+    (4, "SpA"),
+    (5, "SpD"),
+    (3, "Spe"),
+];
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Language {
     Japanese,
     English,
@@ -32,16 +60,185 @@ pub struct Pokemon {
     pub origin_language: Language,
     pub original_trainer_name: String,
     pub species: Species,
+    pub held_item: u16,
     pub experience: u32,
+    pub pp_bonuses: u8,
+    pub friendship: u8,
     pub moves: [u16; 4],
+    pub pp: [u8; 4],
     pub evs: [u8; 6],
     pub ivs: [u8; 6],
     pub is_egg: bool,
+    pub is_bad_egg: bool,
     pub ability: u8,
+    pub met_info: MetInfo,
+    pub misc_flags: MiscFlags,
+}
+
+/// The ribbons and obedience word at the end of the miscellaneous substructure. The obedience
+/// bit marks event Pokemon (e.g. Mew and Deoxys) which obey regardless of badges.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MiscFlags {
+    pub ribbons: u32,
+    pub obedience: bool,
+}
+
+impl From<u32> for MiscFlags {
+    fn from(value: u32) -> Self {
+        MiscFlags {
+            ribbons: value & 0x7fffffff,
+            obedience: (value >> 31) != 0,
+        }
+    }
+}
+
+impl From<MiscFlags> for u32 {
+    fn from(value: MiscFlags) -> Self {
+        (value.ribbons & 0x7fffffff) | (u32::from(value.obedience) << 31)
+    }
+}
+
+/// Where and how a Pokemon was obtained, unpacked from the miscellaneous substructure.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MetInfo {
+    pub location: u8,
+    /// Zero for Pokemon hatched from an egg.
+    pub level: u8,
+    pub origin_game: OriginGame,
+    pub ball: Ball,
+    pub original_trainer_gender: Gender,
+}
+
+impl MetInfo {
+    fn new(location: u8, origin_info: u16) -> Self {
+        MetInfo {
+            location,
+            level: (origin_info & 0x7f) as u8,
+            origin_game: OriginGame::from(((origin_info >> 7) & 0xf) as u8),
+            ball: Ball::from(((origin_info >> 11) & 0xf) as u8),
+            original_trainer_gender: if (origin_info >> 15) != 0 {
+                Gender::Female
+            } else {
+                Gender::Male
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OriginGame {
+    Sapphire,
+    Ruby,
+    Emerald,
+    FireRed,
+    LeafGreen,
+    ColosseumXd,
+    Unknown(u8),
+}
+
+impl From<u8> for OriginGame {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => OriginGame::Sapphire,
+            2 => OriginGame::Ruby,
+            3 => OriginGame::Emerald,
+            4 => OriginGame::FireRed,
+            5 => OriginGame::LeafGreen,
+            15 => OriginGame::ColosseumXd,
+            _ => OriginGame::Unknown(value),
+        }
+    }
+}
+
+impl From<OriginGame> for u8 {
+    fn from(value: OriginGame) -> Self {
+        match value {
+            OriginGame::Sapphire => 1,
+            OriginGame::Ruby => 2,
+            OriginGame::Emerald => 3,
+            OriginGame::FireRed => 4,
+            OriginGame::LeafGreen => 5,
+            OriginGame::ColosseumXd => 15,
+            OriginGame::Unknown(value) => value,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Ball {
+    Master,
+    Ultra,
+    Great,
+    Poke,
+    Safari,
+    Net,
+    Dive,
+    Nest,
+    Repeat,
+    Timer,
+    Luxury,
+    Premier,
+    Unknown(u8),
+}
+
+impl From<u8> for Ball {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Ball::Master,
+            2 => Ball::Ultra,
+            3 => Ball::Great,
+            4 => Ball::Poke,
+            5 => Ball::Safari,
+            6 => Ball::Net,
+            7 => Ball::Dive,
+            8 => Ball::Nest,
+            9 => Ball::Repeat,
+            10 => Ball::Timer,
+            11 => Ball::Luxury,
+            12 => Ball::Premier,
+            _ => Ball::Unknown(value),
+        }
+    }
+}
+
+impl From<Ball> for u8 {
+    fn from(value: Ball) -> Self {
+        match value {
+            Ball::Master => 1,
+            Ball::Ultra => 2,
+            Ball::Great => 3,
+            Ball::Poke => 4,
+            Ball::Safari => 5,
+            Ball::Net => 6,
+            Ball::Dive => 7,
+            Ball::Nest => 8,
+            Ball::Repeat => 9,
+            Ball::Timer => 10,
+            Ball::Luxury => 11,
+            Ball::Premier => 12,
+            Ball::Unknown(value) => value,
+        }
+    }
 }
 
 impl Pokemon {
+    /// Parses PK3 data which has already had its data region decrypted, as exported by some
+    /// tools.
+    pub fn from_decrypted_pk3(pk3: &[u8]) -> std::io::Result<Self> {
+        if pk3.len() != PK3_SIZE_BOX && pk3.len() != PK3_SIZE_PARTY {
+            log::error!("Invalid PK3 length {}", pk3.len());
+            return Err(std::io::ErrorKind::InvalidInput.into());
+        }
+        let mut encrypted = pk3.to_owned();
+        encrypt_decrypt_pk3(&mut encrypted);
+        Self::from_pk3(&encrypted)
+    }
+
     pub fn from_pk3(pk3: &[u8]) -> std::io::Result<Self> {
+        if pk3.len() != PK3_SIZE_BOX && pk3.len() != PK3_SIZE_PARTY {
+            log::error!("Invalid PK3 length {}", pk3.len());
+            return Err(std::io::ErrorKind::InvalidInput.into());
+        }
         let mut source_data = pk3.to_owned();
         encrypt_decrypt_pk3(&mut source_data[..]);
 
@@ -55,15 +252,15 @@ impl Pokemon {
         let mut nickname = [0u8; 10];
         log::trace!("Reading nickname");
         cursor.read_exact(&mut nickname)?;
-        let nickname = decode_text(&nickname);
         log::trace!("Reading language");
         let language = Language::try_from(cursor.read_u8()?)?;
+        let nickname = decode_text_for_language(&nickname, language);
         log::trace!("Reading egg data");
-        let _egg_data = EggData::try_from(cursor.read_u8()?).unwrap();
+        let egg_data = EggData::from(cursor.read_u8()?);
         let mut original_trainer_name = [0u8; 7];
         log::trace!("Reading OT name");
         cursor.read_exact(&mut original_trainer_name)?;
-        let original_trainer_name = decode_text(&original_trainer_name);
+        let original_trainer_name = decode_text_for_language(&original_trainer_name, language);
         log::trace!("Reading markings");
         let _markings = cursor.read_u8()?;
         log::trace!("Reading checksum");
@@ -75,10 +272,10 @@ impl Pokemon {
             get_offset_for_substructure(personality_value, Component::Growth) + SUBSTRUCTURE_OFFSET;
         cursor.seek(SeekFrom::Start(offset))?;
         let species = cursor.read_u16::<LittleEndian>()?;
-        let _held_item_id = cursor.read_u16::<LittleEndian>()?;
+        let held_item = cursor.read_u16::<LittleEndian>()?;
         let experience = cursor.read_u32::<LittleEndian>()?;
-        let _pp_bonuses = cursor.read_u8()?;
-        let _friendship = cursor.read_u8()?;
+        let pp_bonuses = cursor.read_u8()?;
+        let friendship = cursor.read_u8()?;
         let _ = cursor.read_u16::<LittleEndian>()?;
 
         let offset = get_offset_for_substructure(personality_value, Component::Attacks)
@@ -88,9 +285,8 @@ impl Pokemon {
         (0..4).for_each(|idx| {
             moves[idx] = cursor.read_u16::<LittleEndian>().unwrap();
         });
-        let _pp = (0..4)
-            .map(|_| cursor.read_u8().unwrap())
-            .collect::<Vec<_>>();
+        let mut pp = [0u8; 4];
+        cursor.read_exact(&mut pp)?;
 
         let offset = get_offset_for_substructure(personality_value, Component::EvsConditions)
             + SUBSTRUCTURE_OFFSET;
@@ -105,14 +301,14 @@ impl Pokemon {
             + SUBSTRUCTURE_OFFSET;
         cursor.seek(SeekFrom::Start(offset))?;
         let _pokerus_status = cursor.read_u8()?;
-        let _met_location = cursor.read_u8()?;
-        let _origin_info = cursor.read_u16::<LittleEndian>()?;
+        let met_location = cursor.read_u8()?;
+        let met_info = MetInfo::new(met_location, cursor.read_u16::<LittleEndian>()?);
         let ivs_egg_ability_blob = cursor.read_u32::<LittleEndian>()?;
         let mut ivs = [0u8; 6];
         (0..6).for_each(|idx| ivs[idx] = ((ivs_egg_ability_blob >> (5 * idx)) & 0b11111) as u8);
         let is_egg = ((ivs_egg_ability_blob >> 30) & 0b1) != 0;
         let ability = ((ivs_egg_ability_blob >> 31) & 0b1) as u8;
-        let _ribbons_obedience_data = cursor.read_u32::<LittleEndian>()?;
+        let misc_flags = MiscFlags::from(cursor.read_u32::<LittleEndian>()?);
 
         let pkmn = Pokemon {
             source_data,
@@ -125,35 +321,402 @@ impl Pokemon {
             origin_language: language,
             original_trainer_name,
             species: Species::try_from(species)?,
+            held_item,
             experience,
+            pp_bonuses,
+            friendship,
             moves,
+            pp,
             evs,
             ivs,
             is_egg,
+            is_bad_egg: egg_data.is_bad_egg,
             ability,
+            met_info,
+            misc_flags,
         };
         Ok(pkmn)
     }
 
+    pub fn base_stats(&self) -> BaseStats {
+        self.species.base_stats()
+    }
+
+    pub fn nature(&self) -> Nature {
+        Nature::from_personality_value(self.personality_value)
+    }
+
+    pub fn iv_total(&self) -> u16 {
+        self.ivs.iter().copied().map(u16::from).sum()
+    }
+
+    pub fn ev_total(&self) -> u16 {
+        self.evs.iter().copied().map(u16::from).sum()
+    }
+
+    /// The number of stats with the maximum IV of 31.
+    pub fn perfect_iv_count(&self) -> usize {
+        self.ivs.iter().filter(|iv| **iv == 31).count()
+    }
+
+    /// Formats the Pokemon as a Showdown team set.
+    pub fn to_showdown(&self) -> String {
+        let species = self.species.name();
+        let mut header = match self.is_nicknamed() {
+            Some(true) => format!("{} ({species})", self.nickname),
+            _ => species.to_string(),
+        };
+        match self.gender() {
+            Gender::Male => header.push_str(" (M)"),
+            Gender::Female => header.push_str(" (F)"),
+            Gender::Genderless => {}
+        }
+        if let Some(item) = self.held_item_name() {
+            header.push_str(&format!(" @ {item}"));
+        }
+
+        let mut lines = vec![header];
+        if let Some(ability) = self.ability_name() {
+            lines.push(format!("Ability: {ability}"));
+        }
+        let level = self.level();
+        if level != MAX_LEVEL {
+            lines.push(format!("Level: {level}"));
+        }
+        if self.is_shiny() {
+            lines.push(String::from("Shiny: Yes"));
+        }
+        let stat_list = |values: &[u8; 6], skipped: u8| {
+            SHOWDOWN_STAT_ORDER
+                .iter()
+                .filter(|(idx, _)| values[*idx] != skipped)
+                .map(|(idx, label)| format!("{} {label}", values[*idx]))
+                .collect::<Vec<_>>()
+                .join(" / ")
+        };
+        let evs = stat_list(&self.evs, 0);
+        if !evs.is_empty() {
+            lines.push(format!("EVs: {evs}"));
+        }
+        lines.push(format!("{} Nature", self.nature()));
+        let ivs = stat_list(&self.ivs, 31);
+        if !ivs.is_empty() {
+            lines.push(format!("IVs: {ivs}"));
+        }
+        lines.extend(
+            self.moves
+                .iter()
+                .filter_map(|move_id| moves::move_name(*move_id))
+                .map(|name| format!("- {name}")),
+        );
+        lines.join("\n")
+    }
+
+    /// The later generations' characteristic, from the highest IV. Ties go to the first stat
+    /// from PV % 6 onwards, in IV order.
+    pub fn characteristic(&self) -> &'static str {
+        let start = (self.personality_value % 6) as usize;
+        let highest_iv = self.ivs.iter().copied().max().unwrap_or_default();
+        let stat_index = (0..6)
+            .map(|offset| (start + offset) % 6)
+            .find(|&idx| self.ivs[idx] == highest_iv)
+            .unwrap_or(start);
+        CHARACTERISTICS[stat_index][usize::from(highest_iv % 5)]
+    }
+
+    /// The Gen 3 stat formula applied at the current level. IVs and EVs are stored in the order
+    /// HP, Attack, Defense, Speed, Sp. Attack, Sp. Defense. Shedinja always has 1 HP.
+    pub fn computed_stats(&self) -> Stats {
+        let base_stats = self.base_stats();
+        let base_stats = [
+            base_stats.hp,
+            base_stats.attack,
+            base_stats.defense,
+            base_stats.speed,
+            base_stats.special_attack,
+            base_stats.special_defense,
+        ];
+        let level = u16::from(self.level());
+        let nature = self.nature();
+        let mut stats = [0u16; 6];
+        for (idx, stat) in stats.iter_mut().enumerate() {
+            let scaled = (2 * u16::from(base_stats[idx])
+                + u16::from(self.ivs[idx])
+                + u16::from(self.evs[idx]) / 4)
+                * level
+                / 100;
+            *stat = if idx == 0 {
+                scaled + level + 10
+            } else {
+                nature.modify_stat(idx - 1, scaled + 5)
+            };
+        }
+        if self.species == Species::Shedinja {
+            stats[0] = 1;
+        }
+
+        Stats {
+            hp: stats[0],
+            attack: stats[1],
+            defense: stats[2],
+            speed: stats[3],
+            special_attack: stats[4],
+            special_defense: stats[5],
+        }
+    }
+
+    /// The number of PP Ups applied to each move, unpacked from two bits per move.
+    pub fn pp_ups(&self) -> [u8; 4] {
+        let mut pp_ups = [0u8; 4];
+        (0..4).for_each(|idx| pp_ups[idx] = (self.pp_bonuses >> (2 * idx)) & 0b11);
+        pp_ups
+    }
+
+    pub fn held_item_name(&self) -> Option<&'static str> {
+        items::item_name(self.held_item)
+    }
+
+    /// The Gen 3 id of the species ability picked by the ability bit. Species with a single
+    /// ability have it whichever way the bit is set.
+    pub fn ability_id(&self) -> Option<u8> {
+        let [first, second] =
+            abilities::species_abilities(self.species.national_dex_number().ok()?);
+        if self.ability == 1 && second != 0 {
+            Some(second)
+        } else {
+            Some(first)
+        }
+    }
+
+    pub fn ability_name(&self) -> Option<&'static str> {
+        self.ability_id().and_then(abilities::ability_name)
+    }
+
+    pub fn gender(&self) -> Gender {
+        self.species
+            .gender_ratio()
+            .gender_for_personality_value(self.personality_value)
+    }
+
+    /// Gen 3 doesn't store a nickname flag, so this compares the nickname against the default
+    /// species name for the Pokemon's language. Returns `None` for languages whose species
+    /// names aren't known.
+    pub fn is_nicknamed(&self) -> Option<bool> {
+        if self.is_egg {
+            return Some(false);
+        }
+
+        match self.origin_language {
+            // The Italian and Spanish releases use the English species names
+            Language::English | Language::Italian | Language::Spanish => {
+                Some(self.nickname != self.species.name().to_uppercase())
+            }
+            Language::Japanese | Language::French | Language::German => None,
+        }
+    }
+
+    /// Gen 3 Pokemon are shiny when the halves of the PV XORed with the OT's ids are below 8.
+    pub fn is_shiny(&self) -> bool {
+        let shiny_value = u32::from(self.original_trainer_id.public_id)
+            ^ u32::from(self.original_trainer_id.secret_id)
+            ^ (self.personality_value >> 16)
+            ^ (self.personality_value & 0xffff);
+        shiny_value < 8
+    }
+
+    /// Whether the checksum stored in the header matches the decrypted data region.
+    pub fn checksum_is_valid(&self) -> bool {
+        let stored_checksum = LittleEndian::read_u16(&self.source_data[28..30]);
+        stored_checksum == compute_checksum(&self.source_data[32..80])
+    }
+
+    /// Whether the game would show this Pokemon normally rather than as a bad egg.
+    pub fn is_valid(&self) -> bool {
+        self.checksum_is_valid() && !self.is_bad_egg
+    }
+
+    pub fn level(&self) -> u8 {
+        self.species
+            .growth_rate()
+            .level_for_experience(self.experience)
+    }
+
+    /// Sets the experience to the minimum required for the given level. Party stats are left
+    /// for the game to recalculate.
+    pub fn resync_experience_to_level(&mut self, level: u8) -> std::io::Result<()> {
+        if !(1..=MAX_LEVEL).contains(&level) {
+            log::error!("Invalid level {level}, must be in [1, {MAX_LEVEL}]");
+            return Err(std::io::ErrorKind::InvalidInput.into());
+        }
+
+        self.experience = self.species.growth_rate().experience_for_level(level);
+        let offset = get_offset_for_substructure(self.personality_value, Component::Growth)
+            + SUBSTRUCTURE_OFFSET
+            + 4;
+        let mut cursor = Cursor::new(&mut self.source_data[..]);
+        cursor.set_position(offset);
+        cursor.write_u32::<LittleEndian>(self.experience)?;
+        if self.source_data.len() == PK3_SIZE_PARTY {
+            self.source_data[PARTY_LEVEL_OFFSET] = level;
+        }
+        self.recompute_checksum();
+        Ok(())
+    }
+
+    /// Rewrites the header's OT name and id, as if the Pokemon had been caught by another
+    /// trainer. The data region, including the obedience flag, is left untouched.
+    pub fn set_original_trainer(&mut self, name: &str, id: TrainerId) -> std::io::Result<()> {
+        let encoded_name = encode_text(name, OT_NAME_LENGTH)?;
+        self.set_original_trainer_data(&encoded_name, id)
+    }
+
+    /// Like `set_original_trainer`, but with the name already encoded as a game stores it, so
+    /// names with characters that don't decode are carried over exactly.
+    pub fn set_original_trainer_data(
+        &mut self,
+        name_data: &[u8],
+        id: TrainerId,
+    ) -> std::io::Result<()> {
+        if name_data.len() > OT_NAME_LENGTH {
+            log::error!(
+                "OT name is {} bytes, expected at most {OT_NAME_LENGTH}",
+                name_data.len()
+            );
+            return Err(std::io::ErrorKind::InvalidInput.into());
+        }
+        let mut encoded_name = name_data.to_vec();
+        encoded_name.resize(OT_NAME_LENGTH, 0xff);
+        LittleEndian::write_u32(
+            &mut self.source_data[OT_ID_OFFSET..OT_ID_OFFSET + 4],
+            (u32::from(id.secret_id) << 16) | u32::from(id.public_id),
+        );
+        self.source_data[OT_NAME_OFFSET..OT_NAME_OFFSET + OT_NAME_LENGTH]
+            .copy_from_slice(&encoded_name);
+        self.original_trainer_id = id;
+        self.original_trainer_name = decode_text(&encoded_name);
+        Ok(())
+    }
+
+    pub fn set_nickname(&mut self, nickname: &str) -> std::io::Result<()> {
+        let encoded_nickname = encode_text(nickname, NICKNAME_LENGTH)?;
+        self.source_data[NICKNAME_OFFSET..NICKNAME_OFFSET + NICKNAME_LENGTH]
+            .copy_from_slice(&encoded_nickname);
+        self.nickname = decode_text(&encoded_nickname);
+        self.recompute_checksum();
+        Ok(())
+    }
+
+    /// Replaces the moveset. PP is left as is for the game to correct.
+    pub fn set_moves(&mut self, moves: [u16; 4]) {
+        self.moves = moves;
+        let offset = get_offset_for_substructure(self.personality_value, Component::Attacks)
+            + SUBSTRUCTURE_OFFSET;
+        let mut cursor = Cursor::new(&mut self.source_data[..]);
+        cursor.set_position(offset);
+        moves
+            .iter()
+            .for_each(|move_id| cursor.write_u16::<LittleEndian>(*move_id).unwrap());
+        self.recompute_checksum();
+    }
+
+    pub fn set_friendship(&mut self, friendship: u8) {
+        self.friendship = friendship;
+        let offset = get_offset_for_substructure(self.personality_value, Component::Growth)
+            + SUBSTRUCTURE_OFFSET
+            + 9;
+        self.source_data[offset as usize] = friendship;
+        self.recompute_checksum();
+    }
+
+    pub fn set_misc_flags(&mut self, misc_flags: MiscFlags) {
+        self.misc_flags = misc_flags;
+        let offset = get_offset_for_substructure(self.personality_value, Component::Miscellaneous)
+            + SUBSTRUCTURE_OFFSET
+            + 8;
+        let mut cursor = Cursor::new(&mut self.source_data[..]);
+        cursor.set_position(offset);
+        cursor.write_u32::<LittleEndian>(misc_flags.into()).unwrap();
+        self.recompute_checksum();
+    }
+
+    /// Re-encrypts the Pokemon, recomputing the data checksum so edits aren't treated as a bad
+    /// egg by the game.
     pub fn to_pk3(mut self) -> Vec<u8> {
+        self.recompute_checksum();
         encrypt_decrypt_pk3(&mut self.source_data);
         self.source_data
     }
 
+    /// Like `to_pk3`, but in the 100 byte party format. Box data gets the party stats the game
+    /// would calculate, at full HP without a status condition or mail.
+    pub fn to_party_pk3(self) -> Vec<u8> {
+        if self.source_data.len() == PK3_SIZE_PARTY {
+            return self.to_pk3();
+        }
+
+        let level = self.level();
+        let stats = self.computed_stats();
+        let mut pk3 = self.to_pk3();
+        pk3.resize(PK3_SIZE_PARTY, 0);
+        pk3[PARTY_LEVEL_OFFSET] = level;
+        pk3[PARTY_MAIL_OFFSET] = NO_MAIL;
+        let party_stats = [
+            stats.hp,
+            stats.hp,
+            stats.attack,
+            stats.defense,
+            stats.speed,
+            stats.special_attack,
+            stats.special_defense,
+        ];
+        for (idx, stat) in party_stats.into_iter().enumerate() {
+            let offset = PARTY_STATS_OFFSET + 2 * idx;
+            LittleEndian::write_u16(&mut pk3[offset..offset + 2], stat);
+        }
+        pk3
+    }
+
     pub fn clear_evs(&mut self) {
         self.evs = [0u8; 6];
+        let offset = get_offset_for_substructure(self.personality_value, Component::EvsConditions)
+            + SUBSTRUCTURE_OFFSET;
         let mut cursor = Cursor::new(&mut self.source_data[..]);
-        cursor.set_position(32 + (2 * 12));
+        cursor.set_position(offset);
         cursor.write_all(&self.evs).unwrap();
+        self.recompute_checksum();
+    }
 
+    /// Rewrites the stored checksum over the decrypted data region. Every mutator calls this.
+    pub fn recompute_checksum(&mut self) {
         let new_checksum = compute_checksum(&self.source_data[32..80]);
-
         let mut cursor = Cursor::new(&mut self.source_data[..]);
         cursor.set_position(28);
         cursor.write_u16::<LittleEndian>(new_checksum).unwrap();
     }
 }
 
+/// Reads just the species and level out of PK3 data, decrypting only the growth substructure.
+pub fn peek(pk3: &[u8]) -> std::io::Result<(Species, u8)> {
+    if pk3.len() != PK3_SIZE_BOX && pk3.len() != PK3_SIZE_PARTY {
+        log::error!("Invalid PK3 length {}", pk3.len());
+        return Err(std::io::ErrorKind::InvalidInput.into());
+    }
+    let personality_value = LittleEndian::read_u32(&pk3[0..4]);
+    let decryption_key = personality_value ^ LittleEndian::read_u32(&pk3[4..8]);
+
+    let offset = (get_offset_for_substructure(personality_value, Component::Growth)
+        + SUBSTRUCTURE_OFFSET) as usize;
+    let species = Species::try_from(
+        (LittleEndian::read_u32(&pk3[offset..offset + 4]) ^ decryption_key) as u16,
+    )?;
+    let experience = LittleEndian::read_u32(&pk3[offset + 4..offset + 8]) ^ decryption_key;
+
+    Ok((
+        species,
+        species.growth_rate().level_for_experience(experience),
+    ))
+}
+
 enum Component {
     Growth,
     Attacks,
@@ -228,19 +791,77 @@ impl TryFrom<u8> for Language {
     }
 }
 
+impl From<Language> for u8 {
+    fn from(value: Language) -> Self {
+        match value {
+            Language::Japanese => 1,
+            Language::English => 2,
+            Language::French => 3,
+            Language::Italian => 4,
+            Language::German => 5,
+            Language::Spanish => 7,
+        }
+    }
+}
+
 pub struct EggData {
-    _is_bad_egg: bool,
+    is_bad_egg: bool,
     _has_species: bool,
     _use_egg_name: bool,
 }
 
-impl TryFrom<u8> for EggData {
-    type Error = Infallible;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        Ok(EggData {
-            _is_bad_egg: (value & 0b1) != 0,
+impl From<u8> for EggData {
+    fn from(value: u8) -> Self {
+        EggData {
+            is_bad_egg: (value & 0b1) != 0,
             _has_species: (value & 0b10) != 0,
             _use_egg_name: (value & 0b100) != 0,
-        })
+        }
     }
 }
+
+/// Indexed by stat in IV order, then by the highest IV mod 5.
+const CHARACTERISTICS: [[&str; 5]; 6] = [
+    [
+        "Loves to eat",
+        "Takes plenty of siestas",
+        "Nods off a lot",
+        "Scatters things often",
+        "Likes to relax",
+    ],
+    [
+        "Proud of its power",
+        "Likes to thrash about",
+        "A little quick tempered",
+        "Likes to fight",
+        "Quick tempered",
+    ],
+    [
+        "Sturdy body",
+        "Capable of taking hits",
+        "Highly persistent",
+        "Good endurance",
+        "Good perseverance",
+    ],
+    [
+        "Likes to run",
+        "Alert to sounds",
+        "Impetuous and silly",
+        "Somewhat of a clown",
+        "Quick to flee",
+    ],
+    [
+        "Highly curious",
+        "Mischievous",
+        "Thoroughly cunning",
+        "Often lost in thought",
+        "Very finicky",
+    ],
+    [
+        "Strong willed",
+        "Somewhat vain",
+        "Strongly defiant",
+        "Hates to lose",
+        "Somewhat stubborn",
+    ],
+];
@@ -0,0 +1,370 @@
+/// The English name of a Gen 3 move, or `None` for no move and unused ids.
+pub fn move_name(move_id: u16) -> Option<&'static str> {
+    MOVE_NAMES.get(usize::from(move_id)).copied().flatten()
+}
+
+/// Like `move_name`, but renders unknown ids as "Move #<id>" for display.
+pub fn move_display_name(move_id: u16) -> String {
+    match move_name(move_id) {
+        Some(name) => name.to_string(),
+        None => format!("Move #{move_id}"),
+    }
+}
+
+const MOVE_NAMES: [Option<&str>; 355] = [
+    None,                   // 000
+    Some("Pound"),          // 001
+    Some("Karate Chop"),    // 002
+    Some("Double Slap"),    // 003
+    Some("Comet Punch"),    // 004
+    Some("Mega Punch"),     // 005
+    Some("Pay Day"),        // 006
+    Some("Fire Punch"),     // 007
+    Some("Ice Punch"),      // 008
+    Some("Thunder Punch"),  // 009
+    Some("Scratch"),        // 010
+    Some("Vise Grip"),      // 011
+    Some("Guillotine"),     // 012
+    Some("Razor Wind"),     // 013
+    Some("Swords Dance"),   // 014
+    Some("Cut"),            // 015
+    Some("Gust"),           // 016
+    Some("Wing Attack"),    // 017
+    Some("Whirlwind"),      // 018
+    Some("Fly"),            // 019
+    Some("Bind"),           // 020
+    Some("Slam"),           // 021
+    Some("Vine Whip"),      // 022
+    Some("Stomp"),          // 023
+    Some("Double Kick"),    // 024
+    Some("Mega Kick"),      // 025
+    Some("Jump Kick"),      // 026
+    Some("Rolling Kick"),   // 027
+    Some("Sand Attack"),    // 028
+    Some("Headbutt"),       // 029
+    Some("Horn Attack"),    // 030
+    Some("Fury Attack"),    // 031
+    Some("Horn Drill"),     // 032
+    Some("Tackle"),         // 033
+    Some("Body Slam"),      // 034
+    Some("Wrap"),           // 035
+    Some("Take Down"),      // 036
+    Some("Thrash"),         // 037
+    Some("Double-Edge"),    // 038
+    Some("Tail Whip"),      // 039
+    Some("Poison Sting"),   // 040
+    Some("Twineedle"),      // 041
+    Some("Pin Missile"),    // 042
+    Some("Leer"),           // 043
+    Some("Bite"),           // 044
+    Some("Growl"),          // 045
+    Some("Roar"),           // 046
+    Some("Sing"),           // 047
+    Some("Supersonic"),     // 048
+    Some("Sonic Boom"),     // 049
+    Some("Disable"),        // 050
+    Some("Acid"),           // 051
+    Some("Ember"),          // 052
+    Some("Flamethrower"),   // 053
+    Some("Mist"),           // 054
+    Some("Water Gun"),      // 055
+    Some("Hydro Pump"),     // 056
+    Some("Surf"),           // 057
+    Some("Ice Beam"),       // 058
+    Some("Blizzard"),       // 059
+    Some("Psybeam"),        // 060
+    Some("Bubble Beam"),    // 061
+    Some("Aurora Beam"),    // 062
+    Some("Hyper Beam"),     // 063
+    Some("Peck"),           // 064
+    Some("Drill Peck"),     // 065
+    Some("Submission"),     // 066
+    Some("Low Kick"),       // 067
+    Some("Counter"),        // 068
+    Some("Seismic Toss"),   // 069
+    Some("Strength"),       // 070
+    Some("Absorb"),         // 071
+    Some("Mega Drain"),     // 072
+    Some("Leech Seed"),     // 073
+    Some("Growth"),         // 074
+    Some("Razor Leaf"),     // 075
+    Some("Solar Beam"),     // 076
+    Some("Poison Powder"),  // 077
+    Some("Stun Spore"),     // 078
+    Some("Sleep Powder"),   // 079
+    Some("Petal Dance"),    // 080
+    Some("String Shot"),    // 081
+    Some("Dragon Rage"),    // 082
+    Some("Fire Spin"),      // 083
+    Some("Thunder Shock"),  // 084
+    Some("Thunderbolt"),    // 085
+    Some("Thunder Wave"),   // 086
+    Some("Thunder"),        // 087
+    Some("Rock Throw"),     // 088
+    Some("Earthquake"),     // 089
+    Some("Fissure"),        // 090
+    Some("Dig"),            // 091
+    Some("Toxic"),          // 092
+    Some("Confusion"),      // 093
+    Some("Psychic"),        // 094
+    Some("Hypnosis"),       // 095
+    Some("Meditate"),       // 096
+    Some("Agility"),        // 097
+    Some("Quick Attack"),   // 098
+    Some("Rage"),           // 099
+    Some("Teleport"),       // 100
+    Some("Night Shade"),    // 101
+    Some("Mimic"),          // 102
+    Some("Screech"),        // 103
+    Some("Double Team"),    // 104
+    Some("Recover"),        // 105
+    Some("Harden"),         // 106
+    Some("Minimize"),       // 107
+    Some("Smokescreen"),    // 108
+    Some("Confuse Ray"),    // 109
+    Some("Withdraw"),       // 110
+    Some("Defense Curl"),   // 111
+    Some("Barrier"),        // 112
+    Some("Light Screen"),   // 113
+    Some("Haze"),           // 114
+    Some("Reflect"),        // 115
+    Some("Focus Energy"),   // 116
+    Some("Bide"),           // 117
+    Some("Metronome"),      // 118
+    Some("Mirror Move"),    // 119
+    Some("Self-Destruct"),  // 120
+    Some("Egg Bomb"),       // 121
+    Some("Lick"),           // 122
+    Some("Smog"),           // 123
+    Some("Sludge"),         // 124
+    Some("Bone Club"),      // 125
+    Some("Fire Blast"),     // 126
+    Some("Waterfall"),      // 127
+    Some("Clamp"),          // 128
+    Some("Swift"),          // 129
+    Some("Skull Bash"),     // 130
+    Some("Spike Cannon"),   // 131
+    Some("Constrict"),      // 132
+    Some("Amnesia"),        // 133
+    Some("Kinesis"),        // 134
+    Some("Soft-Boiled"),    // 135
+    Some("High Jump Kick"), // 136
+    Some("Glare"),          // 137
+    Some("Dream Eater"),    // 138
+    Some("Poison Gas"),     // 139
+    Some("Barrage"),        // 140
+    Some("Leech Life"),     // 141
+    Some("Lovely Kiss"),    // 142
+    Some("Sky Attack"),     // 143
+    Some("Transform"),      // 144
+    Some("Bubble"),         // 145
+    Some("Dizzy Punch"),    // 146
+    Some("Spore"),          // 147
+    Some("Flash"),          // 148
+    Some("Psywave"),        // 149
+    Some("Splash"),         // 150
+    Some("Acid Armor"),     // 151
+    Some("Crabhammer"),     // 152
+    Some("Explosion"),      // 153
+    Some("Fury Swipes"),    // 154
+    Some("Bonemerang"),     // 155
+    Some("Rest"),           // 156
+    Some("Rock Slide"),     // 157
+    Some("Hyper Fang"),     // 158
+    Some("Sharpen"),        // 159
+    Some("Conversion"),     // 160
+    Some("Tri Attack"),     // 161
+    Some("Super Fang"),     // 162
+    Some("Slash"),          // 163
+    Some("Substitute"),     // 164
+    Some("Struggle"),       // 165
+    Some("Sketch"),         // 166
+    Some("Triple Kick"),    // 167
+    Some("Thief"),          // 168
+    Some("Spider Web"),     // 169
+    Some("Mind Reader"),    // 170
+    Some("Nightmare"),      // 171
+    Some("Flame Wheel"),    // 172
+    Some("Snore"),          // 173
+    Some("Curse"),          // 174
+    Some("Flail"),          // 175
+    Some("Conversion 2"),   // 176
+    Some("Aeroblast"),      // 177
+    Some("Cotton Spore"),   // 178
+    Some("Reversal"),       // 179
+    Some("Spite"),          // 180
+    Some("Powder Snow"),    // 181
+    Some("Protect"),        // 182
+    Some("Mach Punch"),     // 183
+    Some("Scary Face"),     // 184
+    Some("Feint Attack"),   // 185
+    Some("Sweet Kiss"),     // 186
+    Some("Belly Drum"),     // 187
+    Some("Sludge Bomb"),    // 188
+    Some("Mud-Slap"),       // 189
+    Some("Octazooka"),      // 190
+    Some("Spikes"),         // 191
+    Some("Zap Cannon"),     // 192
+    Some("Foresight"),      // 193
+    Some("Destiny Bond"),   // 194
+    Some("Perish Song"),    // 195
+    Some("Icy Wind"),       // 196
+    Some("Detect"),         // 197
+    Some("Bone Rush"),      // 198
+    Some("Lock-On"),        // 199
+    Some("Outrage"),        // 200
+    Some("Sandstorm"),      // 201
+    Some("Giga Drain"),     // 202
+    Some("Endure"),         // 203
+    Some("Charm"),          // 204
+    Some("Rollout"),        // 205
+    Some("False Swipe"),    // 206
+    Some("Swagger"),        // 207
+    Some("Milk Drink"),     // 208
+    Some("Spark"),          // 209
+    Some("Fury Cutter"),    // 210
+    Some("Steel Wing"),     // 211
+    Some("Mean Look"),      // 212
+    Some("Attract"),        // 213
+    Some("Sleep Talk"),     // 214
+    Some("Heal Bell"),      // 215
+    Some("Return"),         // 216
+    Some("Present"),        // 217
+    Some("Frustration"),    // 218
+    Some("Safeguard"),      // 219
+    Some("Pain Split"),     // 220
+    Some("Sacred Fire"),    // 221
+    Some("Magnitude"),      // 222
+    Some("Dynamic Punch"),  // 223
+    Some("Megahorn"),       // 224
+    Some("Dragon Breath"),  // 225
+    Some("Baton Pass"),     // 226
+    Some("Encore"),         // 227
+    Some("Pursuit"),        // 228
+    Some("Rapid Spin"),     // 229
+    Some("Sweet Scent"),    // 230
+    Some("Iron Tail"),      // 231
+    Some("Metal Claw"),     // 232
+    Some("Vital Throw"),    // 233
+    Some("Morning Sun"),    // 234
+    Some("Synthesis"),      // 235
+    Some("Moonlight"),      // 236
+    Some("Hidden Power"),   // 237
+    Some("Cross Chop"),     // 238
+    Some("Twister"),        // 239
+    Some("Rain Dance"),     // 240
+    Some("Sunny Day"),      // 241
+    Some("Crunch"),         // 242
+    Some("Mirror Coat"),    // 243
+    Some("Psych Up"),       // 244
+    Some("Extreme Speed"),  // 245
+    Some("Ancient Power"),  // 246
+    Some("Shadow Ball"),    // 247
+    Some("Future Sight"),   // 248
+    Some("Rock Smash"),     // 249
+    Some("Whirlpool"),      // 250
+    Some("Beat Up"),        // 251
+    Some("Fake Out"),       // 252
+    Some("Uproar"),         // 253
+    Some("Stockpile"),      // 254
+    Some("Spit Up"),        // 255
+    Some("Swallow"),        // 256
+    Some("Heat Wave"),      // 257
+    Some("Hail"),           // 258
+    Some("Torment"),        // 259
+    Some("Flatter"),        // 260
+    Some("Will-O-Wisp"),    // 261
+    Some("Memento"),        // 262
+    Some("Facade"),         // 263
+    Some("Focus Punch"),    // 264
+    Some("Smelling Salts"), // 265
+    Some("Follow Me"),      // 266
+    Some("Nature Power"),   // 267
+    Some("Charge"),         // 268
+    Some("Taunt"),          // 269
+    Some("Helping Hand"),   // 270
+    Some("Trick"),          // 271
+    Some("Role Play"),      // 272
+    Some("Wish"),           // 273
+    Some("Assist"),         // 274
+    Some("Ingrain"),        // 275
+    Some("Superpower"),     // 276
+    Some("Magic Coat"),     // 277
+    Some("Recycle"),        // 278
+    Some("Revenge"),        // 279
+    Some("Brick Break"),    // 280
+    Some("Yawn"),           // 281
+    Some("Knock Off"),      // 282
+    Some("Endeavor"),       // 283
+    Some("Eruption"),       // 284
+    Some("Skill Swap"),     // 285
+    Some("Imprison"),       // 286
+    Some("Refresh"),        // 287
+    Some("Grudge"),         // 288
+    Some("Snatch"),         // 289
+    Some("Secret Power"),   // 290
+    Some("Dive"),           // 291
+    Some("Arm Thrust"),     // 292
+    Some("Camouflage"),     // 293
+    Some("Tail Glow"),      // 294
+    Some("Luster Purge"),   // 295
+    Some("Mist Ball"),      // 296
+    Some("Feather Dance"),  // 297
+    Some("Teeter Dance"),   // 298
+    Some("Blaze Kick"),     // 299
+    Some("Mud Sport"),      // 300
+    Some("Ice Ball"),       // 301
+    Some("Needle Arm"),     // 302
+    Some("Slack Off"),      // 303
+    Some("Hyper Voice"),    // 304
+    Some("Poison Fang"),    // 305
+    Some("Crush Claw"),     // 306
+    Some("Blast Burn"),     // 307
+    Some("Hydro Cannon"),   // 308
+    Some("Meteor Mash"),    // 309
+    Some("Astonish"),       // 310
+    Some("Weather Ball"),   // 311
+    Some("Aromatherapy"),   // 312
+    Some("Fake Tears"),     // 313
+    Some("Air Cutter"),     // 314
+    Some("Overheat"),       // 315
+    Some("Odor Sleuth"),    // 316
+    Some("Rock Tomb"),      // 317
+    Some("Silver Wind"),    // 318
+    Some("Metal Sound"),    // 319
+    Some("Grass Whistle"),  // 320
+    Some("Tickle"),         // 321
+    Some("Cosmic Power"),   // 322
+    Some("Water Spout"),    // 323
+    Some("Signal Beam"),    // 324
+    Some("Shadow Punch"),   // 325
+    Some("Extrasensory"),   // 326
+    Some("Sky Uppercut"),   // 327
+    Some("Sand Tomb"),      // 328
+    Some("Sheer Cold"),     // 329
+    Some("Muddy Water"),    // 330
+    Some("Bullet Seed"),    // 331
+    Some("Aerial Ace"),     // 332
+    Some("Icicle Spear"),   // 333
+    Some("Iron Defense"),   // 334
+    Some("Block"),          // 335
+    Some("Howl"),           // 336
+    Some("Dragon Claw"),    // 337
+    Some("Frenzy Plant"),   // 338
+    Some("Bulk Up"),        // 339
+    Some("Bounce"),         // 340
+    Some("Mud Shot"),       // 341
+    Some("Poison Tail"),    // 342
+    Some("Covet"),          // 343
+    Some("Volt Tackle"),    // 344
+    Some("Magical Leaf"),   // 345
+    Some("Water Sport"),    // 346
+    Some("Calm Mind"),      // 347
+    Some("Leaf Blade"),     // 348
+    Some("Dragon Dance"),   // 349
+    Some("Rock Blast"),     // 350
+    Some("Shock Wave"),     // 351
+    Some("Water Pulse"),    // 352
+    Some("Doom Desire"),    // 353
+    Some("Psycho Boost"),   // 354
+];
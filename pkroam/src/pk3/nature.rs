@@ -0,0 +1,86 @@
+/// The nature of a Pokemon, derived from its personality value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Nature {
+    Hardy,
+    Lonely,
+    Brave,
+    Adamant,
+    Naughty,
+    Bold,
+    Docile,
+    Relaxed,
+    Impish,
+    Lax,
+    Timid,
+    Hasty,
+    Serious,
+    Jolly,
+    Naive,
+    Modest,
+    Mild,
+    Quiet,
+    Bashful,
+    Rash,
+    Calm,
+    Gentle,
+    Sassy,
+    Careful,
+    Quirky,
+}
+
+const NATURES: [Nature; 25] = [
+    Nature::Hardy,
+    Nature::Lonely,
+    Nature::Brave,
+    Nature::Adamant,
+    Nature::Naughty,
+    Nature::Bold,
+    Nature::Docile,
+    Nature::Relaxed,
+    Nature::Impish,
+    Nature::Lax,
+    Nature::Timid,
+    Nature::Hasty,
+    Nature::Serious,
+    Nature::Jolly,
+    Nature::Naive,
+    Nature::Modest,
+    Nature::Mild,
+    Nature::Quiet,
+    Nature::Bashful,
+    Nature::Rash,
+    Nature::Calm,
+    Nature::Gentle,
+    Nature::Sassy,
+    Nature::Careful,
+    Nature::Quirky,
+];
+
+impl Nature {
+    pub fn from_personality_value(personality_value: u32) -> Self {
+        NATURES[(personality_value % 25) as usize]
+    }
+
+    /// Applies the nature's +10%/-10% modifier to a stat other than HP. Stats are indexed in
+    /// the order stored in a PK3: Attack, Defense, Speed, Sp. Attack, Sp. Defense.
+    pub fn modify_stat(&self, stat_index: usize, value: u16) -> u16 {
+        let nature_index = *self as usize;
+        let increased = nature_index / 5;
+        let decreased = nature_index % 5;
+        if increased == decreased {
+            value
+        } else if stat_index == increased {
+            value * 11 / 10
+        } else if stat_index == decreased {
+            value * 9 / 10
+        } else {
+            value
+        }
+    }
+}
+
+impl std::fmt::Display for Nature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self))
+    }
+}
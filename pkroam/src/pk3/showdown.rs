@@ -0,0 +1,300 @@
+use byteorder::{ByteOrder, LittleEndian};
+
+use super::abilities::{ability_name, species_abilities};
+use super::{
+    compute_checksum, gender::Gender, get_offset_for_substructure, growth::MAX_LEVEL,
+    items::item_name, moves::move_name, nature::Nature, species::Species, Component, Language,
+    OriginGame, Pokemon, NICKNAME_LENGTH, NICKNAME_OFFSET, OT_ID_OFFSET, OT_NAME_LENGTH,
+    OT_NAME_OFFSET, PK3_SIZE_BOX, SHOWDOWN_STAT_ORDER, SUBSTRUCTURE_OFFSET,
+};
+use crate::{
+    encode_text,
+    save::{PlayerGender, TrainerInfo},
+};
+
+const MAX_EV: u16 = 255;
+const MAX_EV_TOTAL: u16 = 510;
+const MAX_IV: u8 = 31;
+/// The met location the games use for in-game trades.
+const MET_LOCATION_TRADE: u8 = 0xfe;
+const POKE_BALL: u8 = 4;
+/// Marks the data as holding a species rather than an empty slot.
+const HAS_SPECIES_FLAG: u8 = 0b10;
+
+/// Builds a Pokemon from a Showdown set, caught by `trainer` in `origin_game`. The result is
+/// box data, use `to_pk3` or `to_party_pk3` to get the encrypted blob.
+///
+/// The ability must be one the species can have in Gen 3. PP is left at zero for a Pokemon
+/// Center to restore. The PV is the first one matching the requested nature, gender and
+/// shininess.
+pub fn parse_showdown(
+    set: &str,
+    trainer: &TrainerInfo,
+    origin_game: OriginGame,
+) -> std::io::Result<Pokemon> {
+    let mut lines = set.lines().map(str::trim).filter(|line| !line.is_empty());
+    let header = lines
+        .next()
+        .ok_or_else(|| invalid(String::from("Showdown set is empty")))?;
+    let (header, item) = match header.split_once(" @ ") {
+        Some((header, item)) => (header.trim(), Some(item.trim())),
+        None => (header, None),
+    };
+    let (header, gender) = if let Some(header) = header.strip_suffix(" (M)") {
+        (header, Some(Gender::Male))
+    } else if let Some(header) = header.strip_suffix(" (F)") {
+        (header, Some(Gender::Female))
+    } else {
+        (header, None)
+    };
+    let (nickname, species_name) = match header.strip_suffix(')').and_then(|h| h.rsplit_once(" ("))
+    {
+        Some((nickname, species_name)) => (Some(nickname.trim()), species_name),
+        None => (None, header),
+    };
+
+    let species = find_species(species_name)?;
+    let held_item = match item {
+        Some(item) => find_id(item, 1..=376, item_name)
+            .ok_or_else(|| invalid(format!("Unknown item \"{item}\"")))?,
+        None => 0,
+    };
+
+    let mut level = MAX_LEVEL;
+    let mut shiny = false;
+    let mut friendship = 255;
+    let mut evs = [0u8; 6];
+    let mut ivs = [MAX_IV; 6];
+    let mut nature = None;
+    let mut second_ability = false;
+    let mut moves = Vec::new();
+    for line in lines {
+        if let Some(move_name_text) = line.strip_prefix('-') {
+            // Showdown names the Hidden Power type, which Gen 3 derives from the IVs
+            let move_name_text = move_name_text.split(" [").next().unwrap_or_default().trim();
+            let move_id = find_id(move_name_text, 1..=354, move_name)
+                .ok_or_else(|| invalid(format!("Unknown move \"{move_name_text}\"")))?;
+            moves.push(move_id);
+        } else if let Some(value) = line.strip_prefix("Level:") {
+            level = parse_number(value, "level")?;
+            if !(1..=MAX_LEVEL).contains(&level) {
+                return Err(invalid(format!("Level {level} is not in [1, {MAX_LEVEL}]")));
+            }
+        } else if let Some(value) = line.strip_prefix("Ability:") {
+            second_ability = find_ability_slot(species, value.trim())?;
+        } else if let Some(value) = line.strip_prefix("Shiny:") {
+            shiny = value.trim().eq_ignore_ascii_case("yes");
+        } else if let Some(value) = line.strip_prefix("Happiness:") {
+            friendship = parse_number(value, "happiness")?;
+        } else if let Some(value) = line.strip_prefix("EVs:") {
+            parse_stats(value, &mut evs, MAX_EV as u8)?;
+        } else if let Some(value) = line.strip_prefix("IVs:") {
+            parse_stats(value, &mut ivs, MAX_IV)?;
+        } else if let Some(value) = line.strip_suffix(" Nature") {
+            nature = Some(find_nature(value)?);
+        } else {
+            log::debug!("Ignoring Showdown line \"{line}\"");
+        }
+    }
+
+    let ev_total = evs.iter().copied().map(u16::from).sum::<u16>();
+    if ev_total > MAX_EV_TOTAL {
+        return Err(invalid(format!(
+            "EV total {ev_total} is over the limit of {MAX_EV_TOTAL}"
+        )));
+    }
+    if moves.is_empty() || moves.len() > 4 {
+        return Err(invalid(format!(
+            "A set needs between 1 and 4 moves, found {}",
+            moves.len()
+        )));
+    }
+
+    let personality_value =
+        choose_personality_value(species, nature, gender, shiny, second_ability, trainer)?;
+    let nickname = nickname.map_or_else(|| species.name().to_uppercase(), str::to_string);
+    if nickname.chars().count() > NICKNAME_LENGTH {
+        return Err(invalid(format!(
+            "Nickname \"{nickname}\" is longer than {NICKNAME_LENGTH} characters"
+        )));
+    }
+
+    let mut data = vec![0u8; PK3_SIZE_BOX];
+    LittleEndian::write_u32(&mut data[0..4], personality_value);
+    LittleEndian::write_u32(
+        &mut data[OT_ID_OFFSET..OT_ID_OFFSET + 4],
+        (u32::from(trainer.id.secret_id) << 16) | u32::from(trainer.id.public_id),
+    );
+    data[NICKNAME_OFFSET..NICKNAME_OFFSET + NICKNAME_LENGTH]
+        .copy_from_slice(&encode_text(&nickname, NICKNAME_LENGTH)?);
+    data[NICKNAME_OFFSET + NICKNAME_LENGTH] = u8::from(Language::English);
+    data[NICKNAME_OFFSET + NICKNAME_LENGTH + 1] = HAS_SPECIES_FLAG;
+    let mut ot_name = trainer.player_name_data.clone();
+    ot_name.resize(OT_NAME_LENGTH, 0xff);
+    data[OT_NAME_OFFSET..OT_NAME_OFFSET + OT_NAME_LENGTH]
+        .copy_from_slice(&ot_name[..OT_NAME_LENGTH]);
+
+    let growth = substructure(personality_value, Component::Growth);
+    LittleEndian::write_u16(&mut data[growth..], species as u16);
+    LittleEndian::write_u16(&mut data[growth + 2..], held_item);
+    LittleEndian::write_u32(
+        &mut data[growth + 4..],
+        species.growth_rate().experience_for_level(level),
+    );
+    data[growth + 9] = friendship;
+
+    let attacks = substructure(personality_value, Component::Attacks);
+    for (idx, move_id) in moves.iter().enumerate() {
+        LittleEndian::write_u16(&mut data[attacks + 2 * idx..], *move_id);
+    }
+
+    let evs_offset = substructure(personality_value, Component::EvsConditions);
+    data[evs_offset..evs_offset + 6].copy_from_slice(&evs);
+
+    let misc = substructure(personality_value, Component::Miscellaneous);
+    data[misc + 1] = MET_LOCATION_TRADE;
+    let ot_female = matches!(trainer.player_gender, PlayerGender::Female);
+    let origin_info = u16::from(level)
+        | (u16::from(u8::from(origin_game) & 0xf) << 7)
+        | (u16::from(POKE_BALL) << 11)
+        | (u16::from(ot_female) << 15);
+    LittleEndian::write_u16(&mut data[misc + 2..], origin_info);
+    let ivs_blob = ivs
+        .iter()
+        .enumerate()
+        .fold(u32::from(second_ability) << 31, |blob, (idx, iv)| {
+            blob | (u32::from(*iv) << (5 * idx))
+        });
+    LittleEndian::write_u32(&mut data[misc + 4..], ivs_blob);
+
+    let checksum = compute_checksum(&data[32..80]);
+    LittleEndian::write_u16(&mut data[28..30], checksum);
+    Pokemon::from_decrypted_pk3(&data)
+}
+
+fn invalid(message: String) -> std::io::Error {
+    log::error!("{message}");
+    std::io::Error::new(std::io::ErrorKind::InvalidInput, message)
+}
+
+fn substructure(personality_value: u32, component: Component) -> usize {
+    (get_offset_for_substructure(personality_value, component) + SUBSTRUCTURE_OFFSET) as usize
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, field: &str) -> std::io::Result<T> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| invalid(format!("Invalid {field} \"{}\"", value.trim())))
+}
+
+/// Parses a list like `252 HP / 4 Atk / 252 Spe` into stats stored in IV order.
+fn parse_stats(list: &str, stats: &mut [u8; 6], max: u8) -> std::io::Result<()> {
+    for entry in list.split('/') {
+        let (value, label) = entry
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| invalid(format!("Invalid stat \"{}\"", entry.trim())))?;
+        let (idx, _) = SHOWDOWN_STAT_ORDER
+            .iter()
+            .find(|(_, stat_label)| stat_label.eq_ignore_ascii_case(label.trim()))
+            .ok_or_else(|| invalid(format!("Unknown stat \"{}\"", label.trim())))?;
+        let value: u16 = parse_number(value, label.trim())?;
+        if value > u16::from(max) {
+            return Err(invalid(format!(
+                "{value} {} is over the Gen 3 limit of {max}",
+                label.trim()
+            )));
+        }
+        stats[*idx] = value as u8;
+    }
+    Ok(())
+}
+
+/// Compares names ignoring case, spacing and punctuation, so Showdown's spellings (e.g.
+/// "Thunder Punch" or "Nidoran-F") match the Gen 3 ones.
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '♀' => 'f',
+            '♂' => 'm',
+            _ => c.to_ascii_lowercase(),
+        })
+        .filter(char::is_ascii_alphanumeric)
+        .collect()
+}
+
+fn find_id(
+    name: &str,
+    ids: std::ops::RangeInclusive<u16>,
+    lookup: fn(u16) -> Option<&'static str>,
+) -> Option<u16> {
+    let name = normalize_name(name);
+    ids.into_iter()
+        .find(|id| lookup(*id).is_some_and(|known| normalize_name(known) == name))
+}
+
+fn find_species(name: &str) -> std::io::Result<Species> {
+    let normalized = normalize_name(name);
+    (1..=386)
+        .filter_map(Species::from_national_dex)
+        .find(|species| normalize_name(species.name()) == normalized)
+        .ok_or_else(|| invalid(format!("Unknown species \"{name}\"")))
+}
+
+/// Whether `name` is the species' second ability rather than its first.
+fn find_ability_slot(species: Species, name: &str) -> std::io::Result<bool> {
+    let national_dex_number = species.national_dex_number()?;
+    let normalized = normalize_name(name);
+    species_abilities(national_dex_number)
+        .iter()
+        .position(|ability| {
+            ability_name(*ability).is_some_and(|known| normalize_name(known) == normalized)
+        })
+        .map(|slot| slot == 1)
+        .ok_or_else(|| {
+            invalid(format!(
+                "{} can't have the ability \"{name}\"",
+                species.name()
+            ))
+        })
+}
+
+fn find_nature(name: &str) -> std::io::Result<Nature> {
+    (0..25)
+        .map(Nature::from_personality_value)
+        .find(|nature| nature.to_string().eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| invalid(format!("Unknown nature \"{}\"", name.trim())))
+}
+
+/// Searches the low half of the PV for the requested nature, gender and ability, with the high
+/// half chosen to make the Pokemon shiny or not for the trainer. The games pick between two
+/// abilities with the lowest bit of the PV.
+fn choose_personality_value(
+    species: Species,
+    nature: Option<Nature>,
+    gender: Option<Gender>,
+    shiny: bool,
+    second_ability: bool,
+    trainer: &TrainerInfo,
+) -> std::io::Result<u32> {
+    let gender_ratio = species.gender_ratio();
+    let [_, other_ability] = species_abilities(species.national_dex_number()?);
+    let trainer_value = u32::from(trainer.id.public_id ^ trainer.id.secret_id);
+    // Any shiny value of 8 or more isn't shiny
+    let shiny_value = if shiny { 0 } else { 0xff00 };
+    (0..=0xffffu32)
+        .map(|low| ((trainer_value ^ low ^ shiny_value) << 16) | low)
+        .find(|pv| {
+            nature.is_none_or(|nature| Nature::from_personality_value(*pv) == nature)
+                && gender
+                    .is_none_or(|gender| gender_ratio.gender_for_personality_value(*pv) == gender)
+                && (other_ability == 0 || (pv & 1) == u32::from(second_ability))
+        })
+        .ok_or_else(|| {
+            invalid(format!(
+                "{} can't have the requested nature, gender and ability",
+                species.name()
+            ))
+        })
+}
@@ -1,3 +1,38 @@
+use super::{gender::GenderRatio, growth::GrowthRate, stats::BaseStats};
+use crate::save::GameCode;
+
+/// A national dex number within the range of species known to Gen 3.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NationalDexNumber(u16);
+
+impl NationalDexNumber {
+    pub const MAX: u16 = 386;
+
+    pub fn new(national_dex_number: u16) -> std::io::Result<Self> {
+        if (1..=Self::MAX).contains(&national_dex_number) {
+            Ok(Self(national_dex_number))
+        } else {
+            log::error!("Invalid national dex number {national_dex_number}");
+            Err(std::io::ErrorKind::InvalidInput.into())
+        }
+    }
+
+    pub fn get(&self) -> u16 {
+        self.0
+    }
+
+    /// The zero-based position of this entry, as used by per-species tables and dex flags.
+    pub fn index(&self) -> usize {
+        usize::from(self.0 - 1)
+    }
+}
+
+impl std::fmt::Display for NationalDexNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[repr(u16)]
 pub enum Species {
@@ -446,71 +481,132 @@ pub enum Species {
 }
 
 impl Species {
-    pub fn national_dex_number(&self) -> std::io::Result<u16> {
+    pub fn national_dex_number(&self) -> std::io::Result<NationalDexNumber> {
         let id = *self as u16;
-        match id {
-            1..=151 => Ok(id),
-            152..=251 => Ok(id),
-            252..=276 | 413..=439 => Ok(201),
-            277..=300 => Ok(id - 25),
-            301 | 302 => Ok(id - 11),
-            304 | 305 => Ok(id - 28),
-            306 | 307 => Ok(id - 20),
-            308 => Ok(327),
-            309 | 310 => Ok(id - 31),
-            311 | 312 => Ok(id - 28),
-            313 | 314 => Ok(id + 7),
-            315 | 316 => Ok(id - 15),
-            317 => Ok(352),
-            318 | 319 => Ok(id + 25),
-            320 => Ok(299),
-            321 => Ok(324),
-            322 => Ok(302),
-            323 | 324 => Ok(id + 16),
-            325 => Ok(370),
-            326 | 327 => Ok(id + 17),
-            328 | 329 => Ok(id + 21),
-            330 | 331 => Ok(id - 12),
-            332..=334 => Ok(id - 4),
-            335 | 336 => Ok(id - 39),
-            337 | 338 => Ok(id - 28),
-            339 | 340 => Ok(id - 17),
-            341..=343 => Ok(id + 22),
-            344 | 345 => Ok(id - 13),
-            346 | 347 => Ok(id + 15),
-            348 | 349 => Ok(id - 11),
-            350 => Ok(298),
-            351 | 352 => Ok(id - 26),
-            353 | 354 => Ok(id - 42),
-            355 => Ok(303),
-            356 | 357 => Ok(id - 49),
-            358 | 359 => Ok(id - 25),
-            360 => Ok(360),
-            361 | 362 => Ok(id - 6),
-            363 => Ok(315),
-            364..=366 => Ok(id - 77),
-            367 | 368 => Ok(id - 51),
-            369 => Ok(357),
-            370..=372 => Ok(id - 77),
-            373..=375 => Ok(id - 7),
-            376 => Ok(359),
-            377 | 378 => Ok(id - 24),
-            379 => Ok(336),
-            380 => Ok(335),
-            381 => Ok(369),
-            382..=384 => Ok(id - 78),
-            385 => Ok(351),
-            386 | 387 => Ok(id - 73),
-            388..=391 => Ok(id - 43),
-            392..=394 => Ok(id - 112),
-            395..=403 => Ok(id - 24),
-            404..=406 => Ok(id - 22),
-            407 | 408 => Ok(id - 27),
-            409 | 410 => Ok(id - 24),
-            411 => Ok(358),
-            _ => Err(std::io::ErrorKind::InvalidInput.into()),
+        let national_dex_number = match id {
+            1..=151 => id,
+            152..=251 => id,
+            252..=276 | 413..=439 => 201,
+            277..=300 => id - 25,
+            301..=303 => id - 11,
+            304 | 305 => id - 28,
+            306 | 307 => id - 21,
+            308 => 327,
+            309 | 310 => id - 31,
+            311 | 312 => id - 28,
+            313 | 314 => id + 7,
+            315 | 316 => id - 15,
+            317 => 352,
+            318 | 319 => id + 25,
+            320 => 299,
+            321 => 324,
+            322 => 302,
+            323 | 324 => id + 16,
+            325 => 370,
+            326 | 327 => id + 15,
+            328 | 329 => id + 21,
+            330 | 331 => id - 12,
+            332..=334 => id - 4,
+            335 | 336 => id - 39,
+            337 | 338 => id - 28,
+            339 | 340 => id - 17,
+            341..=343 => id + 22,
+            344 | 345 => id - 13,
+            346 | 347 => id + 15,
+            348 | 349 => id - 11,
+            350 => 298,
+            351 | 352 => id - 26,
+            353 | 354 => id - 42,
+            355 => 303,
+            356 | 357 => id - 49,
+            358 | 359 => id - 25,
+            360 => 360,
+            361 | 362 => id - 6,
+            363 => 315,
+            364..=366 => id - 77,
+            367 | 368 => id - 51,
+            369 => 357,
+            370..=372 => id - 77,
+            373..=375 => id - 7,
+            376 => 359,
+            377 | 378 => id - 24,
+            379 => 336,
+            380 => 335,
+            381 => 369,
+            382..=384 => id - 78,
+            385 => 351,
+            386 | 387 => id - 73,
+            388..=391 => id - 43,
+            392..=394 => id - 112,
+            395..=403 => id - 24,
+            404..=406 => id - 22,
+            407 | 408 => id - 27,
+            409 | 410 => id - 24,
+            411 => 358,
+            _ => return Err(std::io::ErrorKind::InvalidInput.into()),
+        };
+        NationalDexNumber::new(national_dex_number)
+    }
+
+    /// The species with the given national dex number. Unown resolves to its base form.
+    pub fn from_national_dex(national_dex_number: u16) -> Option<Species> {
+        let national_dex_number = NationalDexNumber::new(national_dex_number).ok()?;
+        (1..=411)
+            .filter_map(|id| Species::try_from(id).ok())
+            .find(|species| species.national_dex_number().ok() == Some(national_dex_number))
+    }
+
+    /// Species without a national dex entry (i.e. eggs) have zeroed base stats.
+    pub fn base_stats(&self) -> BaseStats {
+        self.national_dex_number()
+            .map(BaseStats::for_national_dex_number)
+            .unwrap_or_default()
+    }
+
+    /// Species without a national dex entry are treated as genderless.
+    pub fn gender_ratio(&self) -> GenderRatio {
+        self.national_dex_number()
+            .map(GenderRatio::for_national_dex_number)
+            .unwrap_or(GenderRatio::Genderless)
+    }
+
+    pub fn base_stat_total(&self) -> u16 {
+        self.base_stats().total()
+    }
+
+    /// The English name of the species.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Species::Egg => "Egg",
+            _ => self
+                .national_dex_number()
+                .ok()
+                .map(|dex_number| SPECIES_NAMES[dex_number.index()])
+                .unwrap_or("?"),
+        }
+    }
+
+    /// The entry number in the regional dex of `game`, Hoenn for RSE and Kanto for FRLG, or
+    /// `None` if the species only appears in the national dex.
+    pub fn regional_dex_number(&self, game: GameCode) -> Option<u16> {
+        let national_dex_number = self.national_dex_number().ok()?.get();
+        match game {
+            GameCode::RubySapphire | GameCode::Emerald => HOENN_DEX
+                .iter()
+                .position(|entry| *entry == national_dex_number)
+                .map(|index| index as u16 + 1),
+            GameCode::FireRedLeafGreen => {
+                (national_dex_number <= 151).then_some(national_dex_number)
+            }
         }
     }
+
+    /// Species without a national dex entry use the medium fast curve, as the games do.
+    pub fn growth_rate(&self) -> GrowthRate {
+        self.national_dex_number()
+            .map(GrowthRate::for_national_dex_number)
+            .unwrap_or(GrowthRate::MediumFast)
+    }
 }
 
 impl TryFrom<u16> for Species {
@@ -966,6 +1062,601 @@ impl TryFrom<u16> for Species {
 
 impl std::fmt::Display for Species {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("{:?}", self))
+        f.write_str(self.name())
     }
 }
+
+const SPECIES_NAMES: [&str; 386] = [
+    "Bulbasaur",
+    "Ivysaur",
+    "Venusaur",
+    "Charmander",
+    "Charmeleon",
+    "Charizard",
+    "Squirtle",
+    "Wartortle",
+    "Blastoise",
+    "Caterpie",
+    "Metapod",
+    "Butterfree",
+    "Weedle",
+    "Kakuna",
+    "Beedrill",
+    "Pidgey",
+    "Pidgeotto",
+    "Pidgeot",
+    "Rattata",
+    "Raticate",
+    "Spearow",
+    "Fearow",
+    "Ekans",
+    "Arbok",
+    "Pikachu",
+    "Raichu",
+    "Sandshrew",
+    "Sandslash",
+    "Nidoran♀",
+    "Nidorina",
+    "Nidoqueen",
+    "Nidoran♂",
+    "Nidorino",
+    "Nidoking",
+    "Clefairy",
+    "Clefable",
+    "Vulpix",
+    "Ninetales",
+    "Jigglypuff",
+    "Wigglytuff",
+    "Zubat",
+    "Golbat",
+    "Oddish",
+    "Gloom",
+    "Vileplume",
+    "Paras",
+    "Parasect",
+    "Venonat",
+    "Venomoth",
+    "Diglett",
+    "Dugtrio",
+    "Meowth",
+    "Persian",
+    "Psyduck",
+    "Golduck",
+    "Mankey",
+    "Primeape",
+    "Growlithe",
+    "Arcanine",
+    "Poliwag",
+    "Poliwhirl",
+    "Poliwrath",
+    "Abra",
+    "Kadabra",
+    "Alakazam",
+    "Machop",
+    "Machoke",
+    "Machamp",
+    "Bellsprout",
+    "Weepinbell",
+    "Victreebel",
+    "Tentacool",
+    "Tentacruel",
+    "Geodude",
+    "Graveler",
+    "Golem",
+    "Ponyta",
+    "Rapidash",
+    "Slowpoke",
+    "Slowbro",
+    "Magnemite",
+    "Magneton",
+    "Farfetch'd",
+    "Doduo",
+    "Dodrio",
+    "Seel",
+    "Dewgong",
+    "Grimer",
+    "Muk",
+    "Shellder",
+    "Cloyster",
+    "Gastly",
+    "Haunter",
+    "Gengar",
+    "Onix",
+    "Drowzee",
+    "Hypno",
+    "Krabby",
+    "Kingler",
+    "Voltorb",
+    "Electrode",
+    "Exeggcute",
+    "Exeggutor",
+    "Cubone",
+    "Marowak",
+    "Hitmonlee",
+    "Hitmonchan",
+    "Lickitung",
+    "Koffing",
+    "Weezing",
+    "Rhyhorn",
+    "Rhydon",
+    "Chansey",
+    "Tangela",
+    "Kangaskhan",
+    "Horsea",
+    "Seadra",
+    "Goldeen",
+    "Seaking",
+    "Staryu",
+    "Starmie",
+    "Mr. Mime",
+    "Scyther",
+    "Jynx",
+    "Electabuzz",
+    "Magmar",
+    "Pinsir",
+    "Tauros",
+    "Magikarp",
+    "Gyarados",
+    "Lapras",
+    "Ditto",
+    "Eevee",
+    "Vaporeon",
+    "Jolteon",
+    "Flareon",
+    "Porygon",
+    "Omanyte",
+    "Omastar",
+    "Kabuto",
+    "Kabutops",
+    "Aerodactyl",
+    "Snorlax",
+    "Articuno",
+    "Zapdos",
+    "Moltres",
+    "Dratini",
+    "Dragonair",
+    "Dragonite",
+    "Mewtwo",
+    "Mew",
+    "Chikorita",
+    "Bayleef",
+    "Meganium",
+    "Cyndaquil",
+    "Quilava",
+    "Typhlosion",
+    "Totodile",
+    "Croconaw",
+    "Feraligatr",
+    "Sentret",
+    "Furret",
+    "Hoothoot",
+    "Noctowl",
+    "Ledyba",
+    "Ledian",
+    "Spinarak",
+    "Ariados",
+    "Crobat",
+    "Chinchou",
+    "Lanturn",
+    "Pichu",
+    "Cleffa",
+    "Igglybuff",
+    "Togepi",
+    "Togetic",
+    "Natu",
+    "Xatu",
+    "Mareep",
+    "Flaaffy",
+    "Ampharos",
+    "Bellossom",
+    "Marill",
+    "Azumarill",
+    "Sudowoodo",
+    "Politoed",
+    "Hoppip",
+    "Skiploom",
+    "Jumpluff",
+    "Aipom",
+    "Sunkern",
+    "Sunflora",
+    "Yanma",
+    "Wooper",
+    "Quagsire",
+    "Espeon",
+    "Umbreon",
+    "Murkrow",
+    "Slowking",
+    "Misdreavus",
+    "Unown",
+    "Wobbuffet",
+    "Girafarig",
+    "Pineco",
+    "Forretress",
+    "Dunsparce",
+    "Gligar",
+    "Steelix",
+    "Snubbull",
+    "Granbull",
+    "Qwilfish",
+    "Scizor",
+    "Shuckle",
+    "Heracross",
+    "Sneasel",
+    "Teddiursa",
+    "Ursaring",
+    "Slugma",
+    "Magcargo",
+    "Swinub",
+    "Piloswine",
+    "Corsola",
+    "Remoraid",
+    "Octillery",
+    "Delibird",
+    "Mantine",
+    "Skarmory",
+    "Houndour",
+    "Houndoom",
+    "Kingdra",
+    "Phanpy",
+    "Donphan",
+    "Porygon2",
+    "Stantler",
+    "Smeargle",
+    "Tyrogue",
+    "Hitmontop",
+    "Smoochum",
+    "Elekid",
+    "Magby",
+    "Miltank",
+    "Blissey",
+    "Raikou",
+    "Entei",
+    "Suicune",
+    "Larvitar",
+    "Pupitar",
+    "Tyranitar",
+    "Lugia",
+    "Ho-Oh",
+    "Celebi",
+    "Treecko",
+    "Grovyle",
+    "Sceptile",
+    "Torchic",
+    "Combusken",
+    "Blaziken",
+    "Mudkip",
+    "Marshtomp",
+    "Swampert",
+    "Poochyena",
+    "Mightyena",
+    "Zigzagoon",
+    "Linoone",
+    "Wurmple",
+    "Silcoon",
+    "Beautifly",
+    "Cascoon",
+    "Dustox",
+    "Lotad",
+    "Lombre",
+    "Ludicolo",
+    "Seedot",
+    "Nuzleaf",
+    "Shiftry",
+    "Taillow",
+    "Swellow",
+    "Wingull",
+    "Pelipper",
+    "Ralts",
+    "Kirlia",
+    "Gardevoir",
+    "Surskit",
+    "Masquerain",
+    "Shroomish",
+    "Breloom",
+    "Slakoth",
+    "Vigoroth",
+    "Slaking",
+    "Nincada",
+    "Ninjask",
+    "Shedinja",
+    "Whismur",
+    "Loudred",
+    "Exploud",
+    "Makuhita",
+    "Hariyama",
+    "Azurill",
+    "Nosepass",
+    "Skitty",
+    "Delcatty",
+    "Sableye",
+    "Mawile",
+    "Aron",
+    "Lairon",
+    "Aggron",
+    "Meditite",
+    "Medicham",
+    "Electrike",
+    "Manectric",
+    "Plusle",
+    "Minun",
+    "Volbeat",
+    "Illumise",
+    "Roselia",
+    "Gulpin",
+    "Swalot",
+    "Carvanha",
+    "Sharpedo",
+    "Wailmer",
+    "Wailord",
+    "Numel",
+    "Camerupt",
+    "Torkoal",
+    "Spoink",
+    "Grumpig",
+    "Spinda",
+    "Trapinch",
+    "Vibrava",
+    "Flygon",
+    "Cacnea",
+    "Cacturne",
+    "Swablu",
+    "Altaria",
+    "Zangoose",
+    "Seviper",
+    "Lunatone",
+    "Solrock",
+    "Barboach",
+    "Whiscash",
+    "Corphish",
+    "Crawdaunt",
+    "Baltoy",
+    "Claydol",
+    "Lileep",
+    "Cradily",
+    "Anorith",
+    "Armaldo",
+    "Feebas",
+    "Milotic",
+    "Castform",
+    "Kecleon",
+    "Shuppet",
+    "Banette",
+    "Duskull",
+    "Dusclops",
+    "Tropius",
+    "Chimecho",
+    "Absol",
+    "Wynaut",
+    "Snorunt",
+    "Glalie",
+    "Spheal",
+    "Sealeo",
+    "Walrein",
+    "Clamperl",
+    "Huntail",
+    "Gorebyss",
+    "Relicanth",
+    "Luvdisc",
+    "Bagon",
+    "Shelgon",
+    "Salamence",
+    "Beldum",
+    "Metang",
+    "Metagross",
+    "Regirock",
+    "Regice",
+    "Registeel",
+    "Latias",
+    "Latios",
+    "Kyogre",
+    "Groudon",
+    "Rayquaza",
+    "Jirachi",
+    "Deoxys",
+];
+
+/// National dex numbers in Hoenn dex order.
+const HOENN_DEX: [u16; 202] = [
+    252, // 001 Treecko
+    253, // 002 Grovyle
+    254, // 003 Sceptile
+    255, // 004 Torchic
+    256, // 005 Combusken
+    257, // 006 Blaziken
+    258, // 007 Mudkip
+    259, // 008 Marshtomp
+    260, // 009 Swampert
+    261, // 010 Poochyena
+    262, // 011 Mightyena
+    263, // 012 Zigzagoon
+    264, // 013 Linoone
+    265, // 014 Wurmple
+    266, // 015 Silcoon
+    267, // 016 Beautifly
+    268, // 017 Cascoon
+    269, // 018 Dustox
+    270, // 019 Lotad
+    271, // 020 Lombre
+    272, // 021 Ludicolo
+    273, // 022 Seedot
+    274, // 023 Nuzleaf
+    275, // 024 Shiftry
+    276, // 025 Taillow
+    277, // 026 Swellow
+    278, // 027 Wingull
+    279, // 028 Pelipper
+    280, // 029 Ralts
+    281, // 030 Kirlia
+    282, // 031 Gardevoir
+    283, // 032 Surskit
+    284, // 033 Masquerain
+    285, // 034 Shroomish
+    286, // 035 Breloom
+    287, // 036 Slakoth
+    288, // 037 Vigoroth
+    289, // 038 Slaking
+    63,  // 039 Abra
+    64,  // 040 Kadabra
+    65,  // 041 Alakazam
+    290, // 042 Nincada
+    291, // 043 Ninjask
+    292, // 044 Shedinja
+    293, // 045 Whismur
+    294, // 046 Loudred
+    295, // 047 Exploud
+    296, // 048 Makuhita
+    297, // 049 Hariyama
+    118, // 050 Goldeen
+    119, // 051 Seaking
+    129, // 052 Magikarp
+    130, // 053 Gyarados
+    298, // 054 Azurill
+    183, // 055 Marill
+    184, // 056 Azumarill
+    74,  // 057 Geodude
+    75,  // 058 Graveler
+    76,  // 059 Golem
+    299, // 060 Nosepass
+    300, // 061 Skitty
+    301, // 062 Delcatty
+    41,  // 063 Zubat
+    42,  // 064 Golbat
+    169, // 065 Crobat
+    72,  // 066 Tentacool
+    73,  // 067 Tentacruel
+    302, // 068 Sableye
+    303, // 069 Mawile
+    304, // 070 Aron
+    305, // 071 Lairon
+    306, // 072 Aggron
+    66,  // 073 Machop
+    67,  // 074 Machoke
+    68,  // 075 Machamp
+    307, // 076 Meditite
+    308, // 077 Medicham
+    309, // 078 Electrike
+    310, // 079 Manectric
+    311, // 080 Plusle
+    312, // 081 Minun
+    81,  // 082 Magnemite
+    82,  // 083 Magneton
+    100, // 084 Voltorb
+    101, // 085 Electrode
+    313, // 086 Volbeat
+    314, // 087 Illumise
+    43,  // 088 Oddish
+    44,  // 089 Gloom
+    45,  // 090 Vileplume
+    182, // 091 Bellossom
+    84,  // 092 Doduo
+    85,  // 093 Dodrio
+    315, // 094 Roselia
+    316, // 095 Gulpin
+    317, // 096 Swalot
+    318, // 097 Carvanha
+    319, // 098 Sharpedo
+    320, // 099 Wailmer
+    321, // 100 Wailord
+    322, // 101 Numel
+    323, // 102 Camerupt
+    218, // 103 Slugma
+    219, // 104 Magcargo
+    324, // 105 Torkoal
+    88,  // 106 Grimer
+    89,  // 107 Muk
+    109, // 108 Koffing
+    110, // 109 Weezing
+    325, // 110 Spoink
+    326, // 111 Grumpig
+    27,  // 112 Sandshrew
+    28,  // 113 Sandslash
+    327, // 114 Spinda
+    227, // 115 Skarmory
+    328, // 116 Trapinch
+    329, // 117 Vibrava
+    330, // 118 Flygon
+    331, // 119 Cacnea
+    332, // 120 Cacturne
+    333, // 121 Swablu
+    334, // 122 Altaria
+    335, // 123 Zangoose
+    336, // 124 Seviper
+    337, // 125 Lunatone
+    338, // 126 Solrock
+    339, // 127 Barboach
+    340, // 128 Whiscash
+    341, // 129 Corphish
+    342, // 130 Crawdaunt
+    343, // 131 Baltoy
+    344, // 132 Claydol
+    345, // 133 Lileep
+    346, // 134 Cradily
+    347, // 135 Anorith
+    348, // 136 Armaldo
+    174, // 137 Igglybuff
+    39,  // 138 Jigglypuff
+    40,  // 139 Wigglytuff
+    349, // 140 Feebas
+    350, // 141 Milotic
+    351, // 142 Castform
+    120, // 143 Staryu
+    121, // 144 Starmie
+    352, // 145 Kecleon
+    353, // 146 Shuppet
+    354, // 147 Banette
+    355, // 148 Duskull
+    356, // 149 Dusclops
+    357, // 150 Tropius
+    358, // 151 Chimecho
+    359, // 152 Absol
+    37,  // 153 Vulpix
+    38,  // 154 Ninetales
+    172, // 155 Pichu
+    25,  // 156 Pikachu
+    26,  // 157 Raichu
+    54,  // 158 Psyduck
+    55,  // 159 Golduck
+    360, // 160 Wynaut
+    202, // 161 Wobbuffet
+    177, // 162 Natu
+    178, // 163 Xatu
+    203, // 164 Girafarig
+    231, // 165 Phanpy
+    232, // 166 Donphan
+    127, // 167 Pinsir
+    214, // 168 Heracross
+    111, // 169 Rhyhorn
+    112, // 170 Rhydon
+    361, // 171 Snorunt
+    362, // 172 Glalie
+    363, // 173 Spheal
+    364, // 174 Sealeo
+    365, // 175 Walrein
+    366, // 176 Clamperl
+    367, // 177 Huntail
+    368, // 178 Gorebyss
+    369, // 179 Relicanth
+    222, // 180 Corsola
+    170, // 181 Chinchou
+    171, // 182 Lanturn
+    370, // 183 Luvdisc
+    116, // 184 Horsea
+    117, // 185 Seadra
+    230, // 186 Kingdra
+    371, // 187 Bagon
+    372, // 188 Shelgon
+    373, // 189 Salamence
+    374, // 190 Beldum
+    375, // 191 Metang
+    376, // 192 Metagross
+    377, // 193 Regirock
+    378, // 194 Regice
+    379, // 195 Registeel
+    380, // 196 Latias
+    381, // 197 Latios
+    382, // 198 Kyogre
+    383, // 199 Groudon
+    384, // 200 Rayquaza
+    385, // 201 Jirachi
+    386, // 202 Deoxys
+];
@@ -0,0 +1,441 @@
+use super::species::NationalDexNumber;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BaseStats {
+    pub hp: u8,
+    pub attack: u8,
+    pub defense: u8,
+    pub special_attack: u8,
+    pub special_defense: u8,
+    pub speed: u8,
+}
+
+impl BaseStats {
+    pub fn for_national_dex_number(national_dex_number: NationalDexNumber) -> Self {
+        let stats = BASE_STATS[national_dex_number.index()];
+        BaseStats {
+            hp: stats[0],
+            attack: stats[1],
+            defense: stats[2],
+            special_attack: stats[3],
+            special_defense: stats[4],
+            speed: stats[5],
+        }
+    }
+
+    pub fn total(&self) -> u16 {
+        [
+            self.hp,
+            self.attack,
+            self.defense,
+            self.special_attack,
+            self.special_defense,
+            self.speed,
+        ]
+        .into_iter()
+        .map(u16::from)
+        .sum()
+    }
+}
+
+/// The stats a Pokemon has in battle, computed from its base stats, IVs, EVs, level and nature.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub special_attack: u16,
+    pub special_defense: u16,
+    pub speed: u16,
+}
+
+/// Base stats for each species, indexed by national dex number - 1. Columns are in the order
+/// HP, Attack, Defense, Sp. Attack, Sp. Defense, Speed.
+const BASE_STATS: [[u8; 6]; 386] = [
+    [45, 49, 49, 65, 65, 45],       // 001 Bulbasaur
+    [60, 62, 63, 80, 80, 60],       // 002 Ivysaur
+    [80, 82, 83, 100, 100, 80],     // 003 Venusaur
+    [39, 52, 43, 60, 50, 65],       // 004 Charmander
+    [58, 64, 58, 80, 65, 80],       // 005 Charmeleon
+    [78, 84, 78, 109, 85, 100],     // 006 Charizard
+    [44, 48, 65, 50, 64, 43],       // 007 Squirtle
+    [59, 63, 80, 65, 80, 58],       // 008 Wartortle
+    [79, 83, 100, 85, 105, 78],     // 009 Blastoise
+    [45, 30, 35, 20, 20, 45],       // 010 Caterpie
+    [50, 20, 55, 25, 25, 30],       // 011 Metapod
+    [60, 45, 50, 80, 80, 70],       // 012 Butterfree
+    [40, 35, 30, 20, 20, 50],       // 013 Weedle
+    [45, 25, 50, 25, 25, 35],       // 014 Kakuna
+    [65, 80, 40, 45, 80, 75],       // 015 Beedrill
+    [40, 45, 40, 35, 35, 56],       // 016 Pidgey
+    [63, 60, 55, 50, 50, 71],       // 017 Pidgeotto
+    [83, 80, 75, 70, 70, 91],       // 018 Pidgeot
+    [30, 56, 35, 25, 35, 72],       // 019 Rattata
+    [55, 81, 60, 50, 70, 97],       // 020 Raticate
+    [40, 60, 30, 31, 31, 70],       // 021 Spearow
+    [65, 90, 65, 61, 61, 100],      // 022 Fearow
+    [35, 60, 44, 40, 54, 55],       // 023 Ekans
+    [60, 85, 69, 65, 79, 80],       // 024 Arbok
+    [35, 55, 30, 50, 40, 90],       // 025 Pikachu
+    [60, 90, 55, 90, 80, 100],      // 026 Raichu
+    [50, 75, 85, 20, 30, 40],       // 027 Sandshrew
+    [75, 100, 110, 45, 55, 65],     // 028 Sandslash
+    [55, 47, 52, 40, 40, 41],       // 029 NidoranF
+    [70, 62, 67, 55, 55, 56],       // 030 Nidorina
+    [90, 82, 87, 75, 85, 76],       // 031 Nidoqueen
+    [46, 57, 40, 40, 40, 50],       // 032 NidoranM
+    [61, 72, 57, 55, 55, 65],       // 033 Nidorino
+    [81, 92, 77, 85, 75, 85],       // 034 Nidoking
+    [70, 45, 48, 60, 65, 35],       // 035 Clefairy
+    [95, 70, 73, 85, 90, 60],       // 036 Clefable
+    [38, 41, 40, 50, 65, 65],       // 037 Vulpix
+    [73, 76, 75, 81, 100, 100],     // 038 Ninetales
+    [115, 45, 20, 45, 25, 20],      // 039 Jigglypuff
+    [140, 70, 45, 75, 50, 45],      // 040 Wigglytuff
+    [40, 45, 35, 30, 40, 55],       // 041 Zubat
+    [75, 80, 70, 65, 75, 90],       // 042 Golbat
+    [45, 50, 55, 75, 65, 30],       // 043 Oddish
+    [60, 65, 70, 85, 75, 40],       // 044 Gloom
+    [75, 80, 85, 100, 90, 50],      // 045 Vileplume
+    [35, 70, 55, 45, 55, 25],       // 046 Paras
+    [60, 95, 80, 60, 80, 30],       // 047 Parasect
+    [60, 55, 50, 40, 55, 45],       // 048 Venonat
+    [70, 65, 60, 90, 75, 90],       // 049 Venomoth
+    [10, 55, 25, 35, 45, 95],       // 050 Diglett
+    [35, 80, 50, 50, 70, 120],      // 051 Dugtrio
+    [40, 45, 35, 40, 40, 90],       // 052 Meowth
+    [65, 70, 60, 65, 65, 115],      // 053 Persian
+    [50, 52, 48, 65, 50, 55],       // 054 Psyduck
+    [80, 82, 78, 95, 80, 85],       // 055 Golduck
+    [40, 80, 35, 35, 45, 70],       // 056 Mankey
+    [65, 105, 60, 60, 70, 95],      // 057 Primeape
+    [55, 70, 45, 70, 50, 60],       // 058 Growlithe
+    [90, 110, 80, 100, 80, 95],     // 059 Arcanine
+    [40, 50, 40, 40, 40, 90],       // 060 Poliwag
+    [65, 65, 65, 50, 50, 90],       // 061 Poliwhirl
+    [90, 85, 95, 70, 90, 70],       // 062 Poliwrath
+    [25, 20, 15, 105, 55, 90],      // 063 Abra
+    [40, 35, 30, 120, 70, 105],     // 064 Kadabra
+    [55, 50, 45, 135, 85, 120],     // 065 Alakazam
+    [70, 80, 50, 35, 35, 35],       // 066 Machop
+    [80, 100, 70, 50, 60, 45],      // 067 Machoke
+    [90, 130, 80, 65, 85, 55],      // 068 Machamp
+    [50, 75, 35, 70, 30, 40],       // 069 Bellsprout
+    [65, 90, 50, 85, 45, 55],       // 070 Weepinbell
+    [80, 105, 65, 100, 60, 70],     // 071 Victreebel
+    [40, 40, 35, 50, 100, 70],      // 072 Tentacool
+    [80, 70, 65, 80, 120, 100],     // 073 Tentacruel
+    [40, 80, 100, 30, 30, 20],      // 074 Geodude
+    [55, 95, 115, 45, 45, 35],      // 075 Graveler
+    [80, 110, 130, 55, 65, 45],     // 076 Golem
+    [50, 85, 55, 65, 65, 90],       // 077 Ponyta
+    [65, 100, 70, 80, 80, 105],     // 078 Rapidash
+    [90, 65, 65, 40, 40, 15],       // 079 Slowpoke
+    [95, 75, 110, 100, 80, 30],     // 080 Slowbro
+    [25, 35, 70, 95, 55, 45],       // 081 Magnemite
+    [50, 60, 95, 120, 70, 70],      // 082 Magneton
+    [52, 65, 55, 58, 62, 60],       // 083 Farfetchd
+    [35, 85, 45, 35, 35, 75],       // 084 Doduo
+    [60, 110, 70, 60, 60, 100],     // 085 Dodrio
+    [65, 45, 55, 45, 70, 45],       // 086 Seel
+    [90, 70, 80, 70, 95, 70],       // 087 Dewgong
+    [80, 80, 50, 40, 50, 25],       // 088 Grimer
+    [105, 105, 75, 65, 100, 50],    // 089 Muk
+    [30, 65, 100, 45, 25, 40],      // 090 Shellder
+    [50, 95, 180, 85, 45, 70],      // 091 Cloyster
+    [30, 35, 30, 100, 35, 80],      // 092 Gastly
+    [45, 50, 45, 115, 55, 95],      // 093 Haunter
+    [60, 65, 60, 130, 75, 110],     // 094 Gengar
+    [35, 45, 160, 30, 45, 70],      // 095 Onix
+    [60, 48, 45, 43, 90, 42],       // 096 Drowzee
+    [85, 73, 70, 73, 115, 67],      // 097 Hypno
+    [30, 105, 90, 25, 25, 50],      // 098 Krabby
+    [55, 130, 115, 50, 50, 75],     // 099 Kingler
+    [40, 30, 50, 55, 55, 100],      // 100 Voltorb
+    [60, 50, 70, 80, 80, 140],      // 101 Electrode
+    [60, 40, 80, 60, 45, 40],       // 102 Exeggcute
+    [95, 95, 85, 125, 65, 55],      // 103 Exeggutor
+    [50, 50, 95, 40, 50, 35],       // 104 Cubone
+    [60, 80, 110, 50, 80, 45],      // 105 Marowak
+    [50, 120, 53, 35, 110, 87],     // 106 Hitmonlee
+    [50, 105, 79, 35, 110, 76],     // 107 Hitmonchan
+    [90, 55, 75, 60, 75, 30],       // 108 Lickitung
+    [40, 65, 95, 60, 45, 35],       // 109 Koffing
+    [65, 90, 120, 85, 70, 60],      // 110 Weezing
+    [80, 85, 95, 30, 30, 25],       // 111 Rhyhorn
+    [105, 130, 120, 45, 45, 40],    // 112 Rhydon
+    [250, 5, 5, 35, 105, 50],       // 113 Chansey
+    [65, 55, 115, 100, 40, 60],     // 114 Tangela
+    [105, 95, 80, 40, 80, 90],      // 115 Kangaskhan
+    [30, 40, 70, 70, 25, 60],       // 116 Horsea
+    [55, 65, 95, 95, 45, 85],       // 117 Seadra
+    [45, 67, 60, 35, 50, 63],       // 118 Goldeen
+    [80, 92, 65, 65, 80, 68],       // 119 Seaking
+    [30, 45, 55, 70, 55, 85],       // 120 Staryu
+    [60, 75, 85, 100, 85, 115],     // 121 Starmie
+    [40, 45, 65, 100, 120, 90],     // 122 MrMime
+    [70, 110, 80, 55, 80, 105],     // 123 Scyther
+    [65, 50, 35, 115, 95, 95],      // 124 Jynx
+    [65, 83, 57, 95, 85, 105],      // 125 Electabuzz
+    [65, 95, 57, 100, 85, 93],      // 126 Magmar
+    [65, 125, 100, 55, 70, 85],     // 127 Pinsir
+    [75, 100, 95, 40, 70, 110],     // 128 Tauros
+    [20, 10, 55, 15, 20, 80],       // 129 Magikarp
+    [95, 125, 79, 60, 100, 81],     // 130 Gyarados
+    [130, 85, 80, 85, 95, 60],      // 131 Lapras
+    [48, 48, 48, 48, 48, 48],       // 132 Ditto
+    [55, 55, 50, 45, 65, 55],       // 133 Eevee
+    [130, 65, 60, 110, 95, 65],     // 134 Vaporeon
+    [65, 65, 60, 110, 95, 130],     // 135 Jolteon
+    [65, 130, 60, 95, 110, 65],     // 136 Flareon
+    [65, 60, 70, 85, 75, 40],       // 137 Porygon
+    [35, 40, 100, 90, 55, 35],      // 138 Omanyte
+    [70, 60, 125, 115, 70, 55],     // 139 Omastar
+    [30, 80, 90, 55, 45, 55],       // 140 Kabuto
+    [60, 115, 105, 65, 70, 80],     // 141 Kabutops
+    [80, 105, 65, 60, 75, 130],     // 142 Aerodactyl
+    [160, 110, 65, 65, 110, 30],    // 143 Snorlax
+    [90, 85, 100, 95, 125, 85],     // 144 Articuno
+    [90, 90, 85, 125, 90, 100],     // 145 Zapdos
+    [90, 100, 90, 125, 85, 90],     // 146 Moltres
+    [41, 64, 45, 50, 50, 50],       // 147 Dratini
+    [61, 84, 65, 70, 70, 70],       // 148 Dragonair
+    [91, 134, 95, 100, 100, 80],    // 149 Dragonite
+    [106, 110, 90, 154, 90, 130],   // 150 Mewtwo
+    [100, 100, 100, 100, 100, 100], // 151 Mew
+    [45, 49, 65, 49, 65, 45],       // 152 Chikorita
+    [60, 62, 80, 63, 80, 60],       // 153 Bayleef
+    [80, 82, 100, 83, 100, 80],     // 154 Meganium
+    [39, 52, 43, 60, 50, 65],       // 155 Cyndaquil
+    [58, 64, 58, 80, 65, 80],       // 156 Quilava
+    [78, 84, 78, 109, 85, 100],     // 157 Typhlosion
+    [50, 65, 64, 44, 48, 43],       // 158 Totodile
+    [65, 80, 80, 59, 63, 58],       // 159 Croconaw
+    [85, 105, 100, 79, 83, 78],     // 160 Feraligatr
+    [35, 46, 34, 35, 45, 20],       // 161 Sentret
+    [85, 76, 64, 45, 55, 90],       // 162 Furret
+    [60, 30, 30, 36, 56, 50],       // 163 Hoothoot
+    [100, 50, 50, 76, 96, 70],      // 164 Noctowl
+    [40, 20, 30, 40, 80, 55],       // 165 Ledyba
+    [55, 35, 50, 55, 110, 85],      // 166 Ledian
+    [40, 60, 40, 40, 40, 30],       // 167 Spinarak
+    [70, 90, 70, 60, 60, 40],       // 168 Ariados
+    [85, 90, 80, 70, 80, 130],      // 169 Crobat
+    [75, 38, 38, 56, 56, 67],       // 170 Chinchou
+    [125, 58, 58, 76, 76, 67],      // 171 Lanturn
+    [20, 40, 15, 35, 35, 60],       // 172 Pichu
+    [50, 25, 28, 45, 55, 15],       // 173 Cleffa
+    [90, 30, 15, 40, 20, 15],       // 174 Igglybuff
+    [35, 20, 65, 40, 65, 20],       // 175 Togepi
+    [55, 40, 85, 80, 105, 40],      // 176 Togetic
+    [40, 50, 45, 70, 45, 70],       // 177 Natu
+    [65, 75, 70, 95, 70, 95],       // 178 Xatu
+    [55, 40, 40, 65, 45, 35],       // 179 Mareep
+    [70, 55, 55, 80, 60, 45],       // 180 Flaaffy
+    [90, 75, 75, 115, 90, 55],      // 181 Ampharos
+    [75, 80, 85, 90, 100, 50],      // 182 Bellossom
+    [70, 20, 50, 20, 50, 40],       // 183 Marill
+    [100, 50, 80, 50, 80, 50],      // 184 Azumarill
+    [70, 100, 115, 30, 65, 30],     // 185 Sudowoodo
+    [90, 75, 75, 90, 100, 70],      // 186 Politoed
+    [35, 35, 40, 35, 55, 50],       // 187 Hoppip
+    [55, 45, 50, 45, 65, 80],       // 188 Skiploom
+    [75, 55, 70, 55, 85, 110],      // 189 Jumpluff
+    [55, 70, 55, 40, 55, 85],       // 190 Aipom
+    [30, 30, 30, 30, 30, 30],       // 191 Sunkern
+    [75, 75, 55, 105, 85, 30],      // 192 Sunflora
+    [65, 65, 45, 75, 45, 95],       // 193 Yanma
+    [55, 45, 45, 25, 25, 15],       // 194 Wooper
+    [95, 85, 85, 65, 65, 35],       // 195 Quagsire
+    [65, 65, 60, 130, 95, 110],     // 196 Espeon
+    [95, 65, 110, 60, 130, 65],     // 197 Umbreon
+    [60, 85, 42, 85, 42, 91],       // 198 Murkrow
+    [95, 75, 80, 100, 110, 30],     // 199 Slowking
+    [60, 60, 60, 85, 85, 85],       // 200 Misdreavus
+    [48, 72, 48, 72, 48, 48],       // 201 Unown
+    [190, 33, 58, 33, 58, 33],      // 202 Wobbuffet
+    [70, 80, 65, 90, 65, 85],       // 203 Girafarig
+    [50, 65, 90, 35, 35, 15],       // 204 Pineco
+    [75, 90, 140, 60, 60, 40],      // 205 Forretress
+    [100, 70, 70, 65, 65, 45],      // 206 Dunsparce
+    [65, 75, 105, 35, 65, 85],      // 207 Gligar
+    [75, 85, 200, 55, 65, 30],      // 208 Steelix
+    [60, 80, 50, 40, 40, 30],       // 209 Snubbull
+    [90, 120, 75, 60, 60, 45],      // 210 Granbull
+    [65, 95, 75, 55, 55, 85],       // 211 Qwilfish
+    [70, 130, 100, 55, 80, 65],     // 212 Scizor
+    [20, 10, 230, 10, 230, 5],      // 213 Shuckle
+    [80, 125, 75, 40, 95, 85],      // 214 Heracross
+    [55, 95, 55, 35, 75, 115],      // 215 Sneasel
+    [60, 80, 50, 50, 50, 40],       // 216 Teddiursa
+    [90, 130, 75, 75, 75, 55],      // 217 Ursaring
+    [40, 40, 40, 70, 40, 20],       // 218 Slugma
+    [50, 50, 120, 80, 80, 30],      // 219 Magcargo
+    [50, 50, 40, 30, 30, 50],       // 220 Swinub
+    [100, 100, 80, 60, 60, 50],     // 221 Piloswine
+    [55, 55, 85, 65, 85, 35],       // 222 Corsola
+    [35, 65, 35, 65, 35, 65],       // 223 Remoraid
+    [75, 105, 75, 105, 75, 45],     // 224 Octillery
+    [45, 55, 45, 65, 45, 75],       // 225 Delibird
+    [65, 40, 70, 80, 140, 70],      // 226 Mantine
+    [65, 80, 140, 40, 70, 70],      // 227 Skarmory
+    [45, 60, 30, 80, 50, 65],       // 228 Houndour
+    [75, 90, 50, 110, 80, 95],      // 229 Houndoom
+    [75, 95, 95, 95, 95, 85],       // 230 Kingdra
+    [90, 60, 60, 40, 40, 40],       // 231 Phanpy
+    [90, 120, 120, 60, 60, 50],     // 232 Donphan
+    [85, 80, 90, 105, 95, 60],      // 233 Porygon2
+    [73, 95, 62, 85, 65, 85],       // 234 Stantler
+    [55, 20, 35, 20, 45, 75],       // 235 Smeargle
+    [35, 35, 35, 35, 35, 35],       // 236 Tyrogue
+    [50, 95, 95, 35, 110, 70],      // 237 Hitmontop
+    [45, 30, 15, 85, 65, 65],       // 238 Smoochum
+    [45, 63, 37, 65, 55, 95],       // 239 Elekid
+    [45, 75, 37, 70, 55, 83],       // 240 Magby
+    [95, 80, 105, 40, 70, 100],     // 241 Miltank
+    [255, 10, 10, 75, 135, 55],     // 242 Blissey
+    [90, 85, 75, 115, 100, 115],    // 243 Raikou
+    [115, 115, 85, 90, 75, 100],    // 244 Entei
+    [100, 75, 115, 90, 115, 85],    // 245 Suicune
+    [50, 64, 50, 45, 50, 41],       // 246 Larvitar
+    [70, 84, 70, 65, 70, 51],       // 247 Pupitar
+    [100, 134, 110, 95, 100, 61],   // 248 Tyranitar
+    [106, 90, 130, 90, 154, 110],   // 249 Lugia
+    [106, 130, 90, 110, 154, 90],   // 250 HoOh
+    [100, 100, 100, 100, 100, 100], // 251 Celebi
+    [40, 45, 35, 65, 55, 70],       // 252 Treecko
+    [50, 65, 45, 85, 65, 95],       // 253 Grovyle
+    [70, 85, 65, 105, 85, 120],     // 254 Sceptile
+    [45, 60, 40, 70, 50, 45],       // 255 Torchic
+    [60, 85, 60, 85, 60, 55],       // 256 Combusken
+    [80, 120, 70, 110, 70, 80],     // 257 Blaziken
+    [50, 70, 50, 50, 50, 40],       // 258 Mudkip
+    [70, 85, 70, 60, 70, 50],       // 259 Marshtomp
+    [100, 110, 90, 85, 90, 60],     // 260 Swampert
+    [35, 55, 35, 30, 30, 35],       // 261 Poochyena
+    [70, 90, 70, 60, 60, 70],       // 262 Mightyena
+    [38, 30, 41, 30, 41, 60],       // 263 Zigzagoon
+    [78, 70, 61, 50, 61, 100],      // 264 Linoone
+    [45, 45, 35, 20, 30, 20],       // 265 Wurmple
+    [50, 35, 55, 25, 25, 15],       // 266 Silcoon
+    [60, 70, 50, 90, 50, 65],       // 267 Beautifly
+    [50, 35, 55, 25, 25, 15],       // 268 Cascoon
+    [60, 50, 70, 50, 90, 65],       // 269 Dustox
+    [40, 30, 30, 40, 50, 30],       // 270 Lotad
+    [60, 50, 50, 60, 70, 50],       // 271 Lombre
+    [80, 70, 70, 90, 100, 70],      // 272 Ludicolo
+    [40, 40, 50, 30, 30, 30],       // 273 Seedot
+    [70, 70, 40, 60, 40, 60],       // 274 Nuzleaf
+    [90, 100, 60, 90, 60, 80],      // 275 Shiftry
+    [40, 55, 30, 30, 30, 85],       // 276 Taillow
+    [60, 85, 60, 50, 50, 125],      // 277 Swellow
+    [40, 30, 30, 55, 30, 85],       // 278 Wingull
+    [60, 50, 100, 85, 70, 65],      // 279 Pelipper
+    [28, 25, 25, 45, 35, 40],       // 280 Ralts
+    [38, 35, 35, 65, 55, 50],       // 281 Kirlia
+    [68, 65, 65, 125, 115, 80],     // 282 Gardevoir
+    [40, 30, 32, 50, 52, 65],       // 283 Surskit
+    [70, 60, 62, 80, 82, 60],       // 284 Masquerain
+    [60, 40, 60, 40, 60, 35],       // 285 Shroomish
+    [60, 130, 80, 60, 60, 70],      // 286 Breloom
+    [60, 60, 60, 35, 35, 30],       // 287 Slakoth
+    [80, 80, 80, 55, 55, 90],       // 288 Vigoroth
+    [150, 160, 100, 95, 65, 100],   // 289 Slaking
+    [31, 45, 90, 30, 30, 40],       // 290 Nincada
+    [61, 90, 45, 50, 50, 160],      // 291 Ninjask
+    [1, 90, 45, 30, 30, 40],        // 292 Shedinja
+    [64, 51, 23, 51, 23, 28],       // 293 Whismur
+    [84, 71, 43, 71, 43, 48],       // 294 Loudred
+    [104, 91, 63, 91, 63, 68],      // 295 Exploud
+    [72, 60, 30, 20, 30, 25],       // 296 Makuhita
+    [144, 120, 60, 40, 60, 50],     // 297 Hariyama
+    [50, 20, 40, 20, 40, 20],       // 298 Azurill
+    [30, 45, 135, 45, 90, 30],      // 299 Nosepass
+    [50, 45, 45, 35, 35, 50],       // 300 Skitty
+    [70, 65, 65, 55, 55, 70],       // 301 Delcatty
+    [50, 75, 75, 65, 65, 50],       // 302 Sableye
+    [50, 85, 85, 55, 55, 50],       // 303 Mawile
+    [50, 70, 100, 40, 40, 30],      // 304 Aron
+    [60, 90, 140, 50, 50, 40],      // 305 Lairon
+    [70, 110, 180, 60, 60, 50],     // 306 Aggron
+    [30, 40, 55, 40, 55, 60],       // 307 Meditite
+    [60, 60, 75, 60, 75, 80],       // 308 Medicham
+    [40, 45, 40, 65, 40, 65],       // 309 Electrike
+    [70, 75, 60, 105, 60, 105],     // 310 Manectric
+    [60, 50, 40, 85, 75, 95],       // 311 Plusle
+    [60, 40, 50, 75, 85, 95],       // 312 Minun
+    [65, 73, 55, 47, 75, 85],       // 313 Volbeat
+    [65, 47, 55, 73, 75, 85],       // 314 Illumise
+    [50, 60, 45, 100, 80, 65],      // 315 Roselia
+    [70, 43, 53, 43, 53, 40],       // 316 Gulpin
+    [100, 73, 83, 73, 83, 55],      // 317 Swalot
+    [45, 90, 20, 65, 20, 65],       // 318 Carvanha
+    [70, 120, 40, 95, 40, 95],      // 319 Sharpedo
+    [130, 70, 35, 70, 35, 60],      // 320 Wailmer
+    [170, 90, 45, 90, 45, 60],      // 321 Wailord
+    [60, 60, 40, 65, 45, 35],       // 322 Numel
+    [70, 100, 70, 105, 75, 40],     // 323 Camerupt
+    [70, 85, 140, 85, 70, 20],      // 324 Torkoal
+    [60, 25, 35, 70, 80, 60],       // 325 Spoink
+    [80, 45, 65, 90, 110, 80],      // 326 Grumpig
+    [60, 60, 60, 60, 60, 60],       // 327 Spinda
+    [45, 100, 45, 45, 45, 10],      // 328 Trapinch
+    [50, 70, 50, 50, 50, 70],       // 329 Vibrava
+    [80, 100, 80, 80, 80, 100],     // 330 Flygon
+    [50, 85, 40, 85, 40, 35],       // 331 Cacnea
+    [70, 115, 60, 115, 60, 55],     // 332 Cacturne
+    [45, 40, 60, 40, 75, 50],       // 333 Swablu
+    [75, 70, 90, 70, 105, 80],      // 334 Altaria
+    [73, 115, 60, 60, 60, 90],      // 335 Zangoose
+    [73, 100, 60, 100, 60, 65],     // 336 Seviper
+    [70, 55, 65, 95, 85, 70],       // 337 Lunatone
+    [70, 95, 85, 55, 65, 70],       // 338 Solrock
+    [50, 48, 43, 46, 41, 60],       // 339 Barboach
+    [110, 78, 73, 76, 71, 60],      // 340 Whiscash
+    [43, 80, 65, 50, 35, 35],       // 341 Corphish
+    [63, 120, 85, 90, 55, 55],      // 342 Crawdaunt
+    [40, 40, 55, 40, 70, 55],       // 343 Baltoy
+    [60, 70, 105, 70, 120, 75],     // 344 Claydol
+    [66, 41, 77, 61, 87, 23],       // 345 Lileep
+    [86, 81, 97, 81, 107, 43],      // 346 Cradily
+    [45, 95, 50, 40, 50, 75],       // 347 Anorith
+    [75, 125, 100, 70, 80, 45],     // 348 Armaldo
+    [20, 15, 20, 10, 55, 80],       // 349 Feebas
+    [95, 60, 79, 100, 125, 81],     // 350 Milotic
+    [70, 70, 70, 70, 70, 70],       // 351 Castform
+    [60, 90, 70, 60, 120, 40],      // 352 Kecleon
+    [44, 75, 35, 63, 33, 45],       // 353 Shuppet
+    [64, 115, 65, 83, 63, 65],      // 354 Banette
+    [20, 40, 90, 30, 90, 25],       // 355 Duskull
+    [40, 70, 130, 60, 130, 25],     // 356 Dusclops
+    [99, 68, 83, 72, 87, 51],       // 357 Tropius
+    [65, 50, 70, 95, 80, 65],       // 358 Chimecho
+    [65, 130, 60, 75, 60, 75],      // 359 Absol
+    [95, 23, 48, 23, 48, 23],       // 360 Wynaut
+    [50, 50, 50, 50, 50, 50],       // 361 Snorunt
+    [80, 80, 80, 80, 80, 80],       // 362 Glalie
+    [70, 40, 50, 55, 50, 25],       // 363 Spheal
+    [90, 60, 70, 75, 70, 45],       // 364 Sealeo
+    [110, 80, 90, 95, 90, 65],      // 365 Walrein
+    [35, 64, 85, 74, 55, 32],       // 366 Clamperl
+    [55, 104, 105, 94, 75, 52],     // 367 Huntail
+    [55, 84, 105, 114, 75, 52],     // 368 Gorebyss
+    [100, 90, 130, 45, 65, 55],     // 369 Relicanth
+    [43, 30, 55, 40, 65, 97],       // 370 Luvdisc
+    [45, 75, 60, 40, 30, 50],       // 371 Bagon
+    [65, 95, 100, 60, 50, 50],      // 372 Shelgon
+    [95, 135, 80, 110, 80, 100],    // 373 Salamence
+    [40, 55, 80, 35, 60, 30],       // 374 Beldum
+    [60, 75, 100, 55, 80, 50],      // 375 Metang
+    [80, 135, 130, 95, 90, 70],     // 376 Metagross
+    [80, 100, 200, 50, 100, 50],    // 377 Regirock
+    [80, 50, 100, 100, 200, 50],    // 378 Regice
+    [80, 75, 150, 75, 150, 50],     // 379 Registeel
+    [80, 80, 90, 110, 130, 110],    // 380 Latias
+    [80, 90, 80, 130, 110, 110],    // 381 Latios
+    [100, 100, 90, 150, 140, 90],   // 382 Kyogre
+    [100, 150, 140, 100, 90, 90],   // 383 Groudon
+    [105, 150, 90, 150, 90, 95],    // 384 Rayquaza
+    [100, 100, 100, 100, 100, 100], // 385 Jirachi
+    [50, 150, 50, 150, 50, 150],    // 386 Deoxys
+];
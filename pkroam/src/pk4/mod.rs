@@ -0,0 +1,376 @@
+use byteorder::{ByteOrder, LittleEndian};
+
+use super::{
+    pk3::{self, gender::Gender, species::Species},
+    TrainerId,
+};
+
+pub const PK4_SIZE_PARTY: usize = 236;
+pub const PK4_SIZE_BOX: usize = 136;
+const CHECKSUM_OFFSET: usize = 6;
+const BLOCK_OFFSET: usize = 8;
+const BLOCK_SIZE: usize = 32;
+const NICKNAME_OFFSET: usize = 0x48;
+const NICKNAME_LENGTH: usize = 11;
+const ORIGIN_GAME_OFFSET: usize = 0x5f;
+const OT_NAME_OFFSET: usize = 0x68;
+const OT_NAME_LENGTH: usize = 8;
+const TEXT_TERMINATOR: u16 = 0xffff;
+/// The met location the Pal Park gives to every Pokemon migrated from Gen 3.
+pub const PAL_PARK_LOCATION: u16 = 0x37;
+/// Friendship is reset when a Pokemon goes through the Pal Park.
+const PAL_PARK_FRIENDSHIP: u8 = 70;
+const GEN3_AIR_LOCK: u8 = 77;
+const GEN4_AIR_LOCK: u8 = 76;
+
+/// A Gen 4 Pokemon. Unlike the Gen 3 data, `source_data` holds the decrypted blocks already
+/// put back in A, B, C, D order.
+#[derive(Clone, Debug)]
+pub struct Pokemon {
+    pub source_data: Vec<u8>,
+    pub personality_value: u32,
+    pub national_dex_number: u16,
+    pub held_item: u16,
+    pub original_trainer_id: TrainerId,
+    pub experience: u32,
+    pub language: u8,
+    pub evs: [u8; 6],
+    pub moves: [u16; 4],
+    pub ivs: [u8; 6],
+    pub is_egg: bool,
+    pub is_nicknamed: bool,
+    pub nickname: String,
+    pub origin_game: u8,
+    pub original_trainer_name: String,
+}
+
+impl Pokemon {
+    /// Parses the box portion of box or party format PK4 data. The party stats are ignored.
+    pub fn from_pk4(pk4: &[u8]) -> std::io::Result<Self> {
+        if pk4.len() != PK4_SIZE_BOX && pk4.len() != PK4_SIZE_PARTY {
+            log::error!("Invalid PK4 length {}", pk4.len());
+            return Err(std::io::ErrorKind::InvalidInput.into());
+        }
+        let mut source_data = pk4[..PK4_SIZE_BOX].to_vec();
+        let checksum = LittleEndian::read_u16(&source_data[CHECKSUM_OFFSET..CHECKSUM_OFFSET + 2]);
+        crypt_blocks(&mut source_data[BLOCK_OFFSET..PK4_SIZE_BOX], checksum);
+        let personality_value = LittleEndian::read_u32(&source_data[0..4]);
+        unshuffle_blocks(&mut source_data, personality_value);
+
+        let data = &source_data[..];
+        let ivs_blob = LittleEndian::read_u32(&data[0x38..0x3c]);
+        let mut evs = [0u8; 6];
+        evs.copy_from_slice(&data[0x18..0x1e]);
+        let mut moves = [0u16; 4];
+        (0..4).for_each(|idx| moves[idx] = LittleEndian::read_u16(&data[0x28 + 2 * idx..]));
+        let mut ivs = [0u8; 6];
+        (0..6).for_each(|idx| ivs[idx] = ((ivs_blob >> (5 * idx)) & 0b11111) as u8);
+
+        Ok(Pokemon {
+            personality_value,
+            national_dex_number: LittleEndian::read_u16(&data[0x08..0x0a]),
+            held_item: LittleEndian::read_u16(&data[0x0a..0x0c]),
+            original_trainer_id: TrainerId {
+                public_id: LittleEndian::read_u16(&data[0x0c..0x0e]),
+                secret_id: LittleEndian::read_u16(&data[0x0e..0x10]),
+            },
+            experience: LittleEndian::read_u32(&data[0x10..0x14]),
+            language: data[0x17],
+            evs,
+            moves,
+            ivs,
+            is_egg: (ivs_blob >> 30) & 0b1 != 0,
+            is_nicknamed: (ivs_blob >> 31) & 0b1 != 0,
+            nickname: decode_text(&data[NICKNAME_OFFSET..NICKNAME_OFFSET + 2 * NICKNAME_LENGTH]),
+            origin_game: data[ORIGIN_GAME_OFFSET],
+            original_trainer_name: decode_text(
+                &data[OT_NAME_OFFSET..OT_NAME_OFFSET + 2 * OT_NAME_LENGTH],
+            ),
+            source_data,
+        })
+    }
+
+    /// The species, if it's one which also exists in Gen 3.
+    pub fn species(&self) -> Option<Species> {
+        Species::from_national_dex(self.national_dex_number)
+    }
+
+    pub fn checksum_is_valid(&self) -> bool {
+        let stored_checksum =
+            LittleEndian::read_u16(&self.source_data[CHECKSUM_OFFSET..CHECKSUM_OFFSET + 2]);
+        stored_checksum == compute_checksum(&self.source_data[BLOCK_OFFSET..PK4_SIZE_BOX])
+    }
+}
+
+/// Converts a Gen 3 Pokemon to box format PK4 data the way the Pal Park migrates it. Eggs
+/// can't be migrated and Pokemon holding an item are rejected since the Gen 3 item ids aren't
+/// mapped to Gen 4 yet.
+pub fn pk3_to_pk4(pkmn: &pk3::Pokemon) -> std::io::Result<Vec<u8>> {
+    if pkmn.is_egg || pkmn.is_bad_egg {
+        log::error!("Eggs can't be migrated to Gen 4");
+        return Err(std::io::ErrorKind::InvalidInput.into());
+    }
+    if pkmn.held_item != 0 {
+        log::error!("Held item {} has no Gen 4 mapping", pkmn.held_item);
+        return Err(std::io::ErrorKind::Unsupported.into());
+    }
+
+    let mut data = vec![0u8; PK4_SIZE_BOX];
+    LittleEndian::write_u32(&mut data[0..4], pkmn.personality_value);
+    LittleEndian::write_u16(
+        &mut data[0x08..0x0a],
+        pkmn.species.national_dex_number()?.get(),
+    );
+    LittleEndian::write_u16(&mut data[0x0c..0x0e], pkmn.original_trainer_id.public_id);
+    LittleEndian::write_u16(&mut data[0x0e..0x10], pkmn.original_trainer_id.secret_id);
+    LittleEndian::write_u32(&mut data[0x10..0x14], pkmn.experience);
+    data[0x14] = PAL_PARK_FRIENDSHIP;
+    data[0x15] = pkmn.ability_id().map(gen4_ability_id).unwrap_or_default();
+    data[0x17] = u8::from(pkmn.origin_language);
+    data[0x18..0x1e].copy_from_slice(&pkmn.evs);
+
+    let pp_ups = pkmn.pp_ups();
+    for idx in 0..4 {
+        LittleEndian::write_u16(&mut data[0x28 + 2 * idx..], pkmn.moves[idx]);
+        data[0x30 + idx] = pkmn.pp[idx];
+        data[0x34 + idx] = pp_ups[idx];
+    }
+    let ivs_blob = pkmn.ivs.iter().enumerate().fold(0u32, |blob, (idx, iv)| {
+        blob | (u32::from(*iv & 0b11111) << (5 * idx))
+    }) | (u32::from(pkmn.is_nicknamed().unwrap_or(false)) << 31);
+    LittleEndian::write_u32(&mut data[0x38..0x3c], ivs_blob);
+    let gender_bits = match pkmn.gender() {
+        Gender::Male => 0,
+        Gender::Female => 1,
+        Gender::Genderless => 2,
+    };
+    data[0x40] = u8::from(pkmn.misc_flags.obedience) | (gender_bits << 1);
+    // Platinum stores its own copy of the met location
+    LittleEndian::write_u16(&mut data[0x46..0x48], PAL_PARK_LOCATION);
+
+    data[NICKNAME_OFFSET..NICKNAME_OFFSET + 2 * NICKNAME_LENGTH]
+        .copy_from_slice(&encode_text(&pkmn.nickname, NICKNAME_LENGTH)?);
+    data[ORIGIN_GAME_OFFSET] = u8::from(pkmn.met_info.origin_game);
+    data[OT_NAME_OFFSET..OT_NAME_OFFSET + 2 * OT_NAME_LENGTH]
+        .copy_from_slice(&encode_text(&pkmn.original_trainer_name, OT_NAME_LENGTH)?);
+    LittleEndian::write_u16(&mut data[0x80..0x82], PAL_PARK_LOCATION);
+    data[0x83] = u8::from(pkmn.met_info.ball);
+    let ot_female = pkmn.met_info.original_trainer_gender == Gender::Female;
+    data[0x84] = (pkmn.level() & 0x7f) | (u8::from(ot_female) << 7);
+    data[0x86] = u8::from(pkmn.met_info.ball);
+
+    let checksum = compute_checksum(&data[BLOCK_OFFSET..]);
+    LittleEndian::write_u16(&mut data[CHECKSUM_OFFSET..CHECKSUM_OFFSET + 2], checksum);
+    shuffle_blocks(&mut data, pkmn.personality_value);
+    crypt_blocks(&mut data[BLOCK_OFFSET..], checksum);
+    Ok(data)
+}
+
+/// Gen 4 dropped Cacophony, which no species has, moving Air Lock down into its id.
+fn gen4_ability_id(gen3_ability_id: u8) -> u8 {
+    match gen3_ability_id {
+        GEN3_AIR_LOCK => GEN4_AIR_LOCK,
+        ability_id => ability_id,
+    }
+}
+
+/// Encodes text into a field of `max_len` characters including the terminator. Fails if the
+/// text is too long or has characters outside of the Western character set.
+pub fn encode_text(text: &str, max_len: usize) -> std::io::Result<Vec<u8>> {
+    let mut codes = text
+        .chars()
+        .map(|c| {
+            WESTERN_CHARMAP
+                .iter()
+                .find(|(_, mapped)| *mapped == c)
+                .map(|(code, _)| *code)
+                .ok_or_else(|| {
+                    log::error!("Character '{c}' can't be encoded for Gen 4");
+                    std::io::Error::from(std::io::ErrorKind::InvalidInput)
+                })
+        })
+        .collect::<std::io::Result<Vec<_>>>()?;
+    if codes.len() >= max_len {
+        log::error!("Text \"{text}\" is too long for a field of {max_len}");
+        return Err(std::io::ErrorKind::InvalidInput.into());
+    }
+    codes.resize(max_len, TEXT_TERMINATOR);
+    Ok(codes.into_iter().flat_map(u16::to_le_bytes).collect())
+}
+
+/// Decodes Gen 4 text up to the terminator. Characters outside of the Western letters, digits
+/// and common punctuation become `*`.
+pub fn decode_text(text_data: &[u8]) -> String {
+    text_data
+        .chunks_exact(2)
+        .map(LittleEndian::read_u16)
+        .take_while(|code| *code != TEXT_TERMINATOR)
+        .map(|code| {
+            WESTERN_CHARMAP
+                .iter()
+                .find(|(mapped, _)| *mapped == code)
+                .map(|(_, c)| *c)
+                .unwrap_or('*')
+        })
+        .collect()
+}
+
+/// The sum of the 16-bit words of the decrypted blocks.
+fn compute_checksum(block_data: &[u8]) -> u16 {
+    block_data
+        .chunks_exact(2)
+        .map(LittleEndian::read_u16)
+        .fold(0u16, u16::wrapping_add)
+}
+
+/// XORs each 16-bit word with the next value of the games' PRNG, which both encrypts and
+/// decrypts. The blocks are seeded with the checksum.
+fn crypt_blocks(data: &mut [u8], seed: u16) {
+    let mut seed = u32::from(seed);
+    for word in data.chunks_exact_mut(2) {
+        seed = seed.wrapping_mul(0x41c6_4e6d).wrapping_add(0x6073);
+        let value = LittleEndian::read_u16(word) ^ (seed >> 16) as u16;
+        LittleEndian::write_u16(word, value);
+    }
+}
+
+/// Moves the blocks from the order they're stored in for this PV back to A, B, C, D.
+fn unshuffle_blocks(data: &mut [u8], personality_value: u32) {
+    let stored_order = BLOCK_ORDERS[(((personality_value & 0x3e000) >> 13) % 24) as usize];
+    let stored = data[BLOCK_OFFSET..PK4_SIZE_BOX].to_vec();
+    for (position, block) in stored_order.iter().enumerate() {
+        let dest = BLOCK_OFFSET + BLOCK_SIZE * usize::from(*block);
+        data[dest..dest + BLOCK_SIZE]
+            .copy_from_slice(&stored[BLOCK_SIZE * position..BLOCK_SIZE * (position + 1)]);
+    }
+}
+
+/// Moves the blocks from A, B, C, D into the order they're stored in for this PV.
+fn shuffle_blocks(data: &mut [u8], personality_value: u32) {
+    let stored_order = BLOCK_ORDERS[(((personality_value & 0x3e000) >> 13) % 24) as usize];
+    let ordered = data[BLOCK_OFFSET..PK4_SIZE_BOX].to_vec();
+    for (position, block) in stored_order.iter().enumerate() {
+        let src = BLOCK_SIZE * usize::from(*block);
+        let dest = BLOCK_OFFSET + BLOCK_SIZE * position;
+        data[dest..dest + BLOCK_SIZE].copy_from_slice(&ordered[src..src + BLOCK_SIZE]);
+    }
+}
+
+/// The block stored at each position for each shuffle value, with A as 0.
+const BLOCK_ORDERS: [[u8; 4]; 24] = [
+    [0, 1, 2, 3],
+    [0, 1, 3, 2],
+    [0, 2, 1, 3],
+    [0, 2, 3, 1],
+    [0, 3, 1, 2],
+    [0, 3, 2, 1],
+    [1, 0, 2, 3],
+    [1, 0, 3, 2],
+    [1, 2, 0, 3],
+    [1, 2, 3, 0],
+    [1, 3, 0, 2],
+    [1, 3, 2, 0],
+    [2, 0, 1, 3],
+    [2, 0, 3, 1],
+    [2, 1, 0, 3],
+    [2, 1, 3, 0],
+    [2, 3, 0, 1],
+    [2, 3, 1, 0],
+    [3, 0, 1, 2],
+    [3, 0, 2, 1],
+    [3, 1, 0, 2],
+    [3, 1, 2, 0],
+    [3, 2, 0, 1],
+    [3, 2, 1, 0],
+];
+
+/// The Western subset of the Gen 4 character set.
+const WESTERN_CHARMAP: [(u16, char); 87] = [
+    (0x0121, '0'),
+    (0x0122, '1'),
+    (0x0123, '2'),
+    (0x0124, '3'),
+    (0x0125, '4'),
+    (0x0126, '5'),
+    (0x0127, '6'),
+    (0x0128, '7'),
+    (0x0129, '8'),
+    (0x012a, '9'),
+    (0x012b, 'A'),
+    (0x012c, 'B'),
+    (0x012d, 'C'),
+    (0x012e, 'D'),
+    (0x012f, 'E'),
+    (0x0130, 'F'),
+    (0x0131, 'G'),
+    (0x0132, 'H'),
+    (0x0133, 'I'),
+    (0x0134, 'J'),
+    (0x0135, 'K'),
+    (0x0136, 'L'),
+    (0x0137, 'M'),
+    (0x0138, 'N'),
+    (0x0139, 'O'),
+    (0x013a, 'P'),
+    (0x013b, 'Q'),
+    (0x013c, 'R'),
+    (0x013d, 'S'),
+    (0x013e, 'T'),
+    (0x013f, 'U'),
+    (0x0140, 'V'),
+    (0x0141, 'W'),
+    (0x0142, 'X'),
+    (0x0143, 'Y'),
+    (0x0144, 'Z'),
+    (0x0145, 'a'),
+    (0x0146, 'b'),
+    (0x0147, 'c'),
+    (0x0148, 'd'),
+    (0x0149, 'e'),
+    (0x014a, 'f'),
+    (0x014b, 'g'),
+    (0x014c, 'h'),
+    (0x014d, 'i'),
+    (0x014e, 'j'),
+    (0x014f, 'k'),
+    (0x0150, 'l'),
+    (0x0151, 'm'),
+    (0x0152, 'n'),
+    (0x0153, 'o'),
+    (0x0154, 'p'),
+    (0x0155, 'q'),
+    (0x0156, 'r'),
+    (0x0157, 's'),
+    (0x0158, 't'),
+    (0x0159, 'u'),
+    (0x015a, 'v'),
+    (0x015b, 'w'),
+    (0x015c, 'x'),
+    (0x015d, 'y'),
+    (0x015e, 'z'),
+    (0x01ab, '!'),
+    (0x01ac, '?'),
+    (0x01ad, ','),
+    (0x01ae, '.'),
+    (0x01af, '…'),
+    (0x01b0, '・'),
+    (0x01b1, '/'),
+    (0x01b2, '‘'),
+    (0x01b3, '’'),
+    (0x01b4, '“'),
+    (0x01b5, '”'),
+    (0x01b6, '„'),
+    (0x01b7, '«'),
+    (0x01b8, '»'),
+    (0x01b9, '('),
+    (0x01ba, ')'),
+    (0x01bb, '♂'),
+    (0x01bc, '♀'),
+    (0x01bd, '+'),
+    (0x01be, '-'),
+    (0x01bf, '*'),
+    (0x01c0, '#'),
+    (0x01c1, '='),
+    (0x01c2, '&'),
+    (0x01de, ' '),
+];
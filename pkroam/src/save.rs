@@ -3,24 +3,36 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use byteorder::{ByteOrder, LittleEndian, ReadBytesExt, WriteBytesExt};
 
-use super::{decode_text, TrainerId};
+use super::{
+    decode_text, decode_text_for_language,
+    pk3::{Language, OriginGame},
+    TrainerId,
+};
 use crate::{
-    pk3::{self as pokemon, species::Species},
+    pk3::{
+        self as pokemon,
+        species::{NationalDexNumber, Species},
+    },
     Pokemon,
 };
 
 pub struct SaveFile {
-    source: PathBuf,
+    source: Option<PathBuf>,
     full_contents: Vec<u8>,
     latest_save_offset: u64,
     section_rotation: u8,
     game_code: Option<GameCode>,
     trainer_info: Option<TrainerInfo>,
+    backup_dir: Option<PathBuf>,
+    /// Bytes some emulators and flashcarts append after the save data, written back as is.
+    footer: Vec<u8>,
 }
 
 const GAME_SAVE_DATA_LENGTH: usize = 131072;
+/// Some dumps hold only the first save block.
+const SINGLE_BLOCK_SAVE_DATA_LENGTH: usize = SAVE_B_OFFSET as usize;
 const SAVE_INDEX_OFFSET: u64 = 0x0FFC;
 const SAVE_A_OFFSET: u64 = 0x0000;
 const SAVE_B_OFFSET: u64 = 0xE000;
@@ -28,8 +40,18 @@ const SECTION_SIZE: u64 = 0x1000;
 const SECTION_DATA_SIZE: usize = 3968;
 const SECTION_CHECKSUM_OFFSET: u64 = 0x0ff6;
 const NUMBER_OF_SECTIONS: u8 = 14;
-
-#[derive(Clone, Copy)]
+pub const NUMBER_OF_BOXES: u8 = 14;
+pub const MAX_PARTY_SIZE: u8 = 6;
+pub const MAX_MONEY: u32 = 999_999;
+const DEX_FLAGS_LENGTH: usize = 49;
+const TRAINER_NAME_LENGTH: usize = 7;
+const TRAINER_ID_OFFSET: usize = 0x000a;
+const TIME_PLAYED_OFFSET: usize = 0x000e;
+const TIME_PLAYED_LENGTH: usize = 5;
+const PC_BUFFER_FIRST_SECTION: u8 = 5;
+const PC_BUFFER_WALLPAPERS_OFFSET: usize = 0x83c2;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum GameCode {
     RubySapphire,
     FireRedLeafGreen,
@@ -44,6 +66,19 @@ impl GameCode {
         }
     }
 
+    fn pokedex_national_magic(&self) -> u64 {
+        0x001a
+    }
+
+    /// The value the games write to the dex's national magic byte once the national dex is
+    /// obtained.
+    fn national_magic_value(&self) -> u8 {
+        match self {
+            GameCode::RubySapphire | GameCode::Emerald => 0xda,
+            GameCode::FireRedLeafGreen => 0xb9,
+        }
+    }
+
     fn pokedex_owned(&self) -> u64 {
         0x0028
     }
@@ -60,6 +95,59 @@ impl GameCode {
         }
     }
 
+    /// Offset of the event flags within the save block which starts at section 1.
+    fn flags(&self) -> usize {
+        match self {
+            GameCode::RubySapphire => 0x1220,
+            GameCode::Emerald => 0x1270,
+            GameCode::FireRedLeafGreen => 0x0ee0,
+        }
+    }
+
+    /// Set once the player has entered the Hall of Fame.
+    fn game_clear_flag(&self) -> u16 {
+        match self {
+            GameCode::RubySapphire => 0x0804,
+            GameCode::Emerald => 0x0864,
+            GameCode::FireRedLeafGreen => 0x082c,
+        }
+    }
+
+    /// Offset in section 0 of the key used to obscure money and item counts.
+    fn security_key(&self) -> Option<u64> {
+        match self {
+            GameCode::RubySapphire => None,
+            GameCode::Emerald => Some(0x00ac),
+            GameCode::FireRedLeafGreen => Some(0x0f20),
+        }
+    }
+
+    fn money(&self) -> u64 {
+        match self {
+            GameCode::RubySapphire | GameCode::Emerald => 0x0490,
+            GameCode::FireRedLeafGreen => 0x0290,
+        }
+    }
+
+    fn coins(&self) -> u64 {
+        self.money() + 4
+    }
+
+    fn battle_points(&self) -> Option<u64> {
+        match self {
+            GameCode::Emerald => Some(0x0eb8),
+            GameCode::RubySapphire | GameCode::FireRedLeafGreen => None,
+        }
+    }
+
+    /// Emerald adds the "Friends" wallpaper on top of the 16 from the other games.
+    fn wallpaper_count(&self) -> u8 {
+        match self {
+            GameCode::RubySapphire | GameCode::FireRedLeafGreen => 16,
+            GameCode::Emerald => 17,
+        }
+    }
+
     fn pokedex_seen_c(&self) -> u64 {
         match self {
             GameCode::RubySapphire => 0x0c0c,
@@ -67,6 +155,71 @@ impl GameCode {
             GameCode::FireRedLeafGreen => 0x0b98,
         }
     }
+
+    /// The flag for the first badge, the other seven follow it. These are the Kanto badges in
+    /// FRLG and the Hoenn badges in RSE.
+    fn first_badge_flag(&self) -> u16 {
+        match self {
+            GameCode::RubySapphire => 0x0807,
+            GameCode::Emerald => 0x0867,
+            GameCode::FireRedLeafGreen => 0x0820,
+        }
+    }
+
+    /// The names of the eight badges in order, from the region the game is set in.
+    pub fn badge_names(&self) -> [&'static str; 8] {
+        match self {
+            GameCode::RubySapphire | GameCode::Emerald => [
+                "Stone", "Knuckle", "Dynamo", "Heat", "Balance", "Feather", "Mind", "Rain",
+            ],
+            GameCode::FireRedLeafGreen => [
+                "Boulder", "Cascade", "Thunder", "Rainbow", "Soul", "Marsh", "Volcano", "Earth",
+            ],
+        }
+    }
+
+    /// Offset in section 1 and slot count of each bag pocket: items, key items, Poke Balls,
+    /// TMs and HMs, then berries.
+    fn bag_pockets(&self) -> &'static [(u64, u16)] {
+        match self {
+            GameCode::RubySapphire => &[
+                (0x0560, 20),
+                (0x05b0, 20),
+                (0x0600, 16),
+                (0x0640, 64),
+                (0x0740, 46),
+            ],
+            GameCode::Emerald => &[
+                (0x0560, 30),
+                (0x05d8, 30),
+                (0x0650, 16),
+                (0x0690, 64),
+                (0x0790, 46),
+            ],
+            GameCode::FireRedLeafGreen => &[
+                (0x0310, 42),
+                (0x03b8, 30),
+                (0x0430, 13),
+                (0x0464, 58),
+                (0x054c, 43),
+            ],
+        }
+    }
+
+    /// Entries in the regional dex, Hoenn for RSE and Kanto for FRLG.
+    pub fn regional_dex_size(&self) -> u16 {
+        match self {
+            GameCode::RubySapphire | GameCode::Emerald => 202,
+            GameCode::FireRedLeafGreen => 151,
+        }
+    }
+}
+
+/// One of the two copies of the save data, the games alternate between them on each save.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SaveBlock {
+    A,
+    B,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -83,12 +236,36 @@ pub struct TimePlayed {
     pub frames: u8,
 }
 
+/// Counters the player accumulates, with fields not present in a game left as `None`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Resources {
+    pub money: u32,
+    pub coins: u16,
+    pub battle_points: Option<u16>,
+}
+
+/// Pokedex progress in both the regional dex of the game and the national dex.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DexCompletion {
+    pub national_dex_unlocked: bool,
+    pub regional_owned: u16,
+    pub regional_seen: u16,
+    pub regional_total: u16,
+    pub national_owned: u16,
+    pub national_seen: u16,
+    pub national_total: u16,
+}
+
 #[derive(Clone, Debug)]
 pub struct TrainerInfo {
     pub player_name: String,
+    /// The name as stored, which survives a round-trip even when it doesn't decode cleanly.
+    pub player_name_data: Vec<u8>,
     pub player_gender: PlayerGender,
     pub id: TrainerId,
     pub time_played: TimePlayed,
+    /// Detected from the player's own party, Western unless shown to be Japanese.
+    pub language: Language,
 }
 
 impl SaveFile {
@@ -97,34 +274,78 @@ impl SaveFile {
             let file = std::fs::File::open(&p)?;
             let mut reader = std::io::BufReader::new(file);
             let mut full_contents = Vec::new();
-            let read_len = reader.read_to_end(&mut full_contents)?;
-            if read_len >= GAME_SAVE_DATA_LENGTH {
-                let latest_save_offset = determine_latest_game_save_offset(&full_contents)?;
-                let section_rotation =
-                    determine_section_rotation(latest_save_offset, &full_contents)?;
-                let mut save = SaveFile {
-                    source: p.as_ref().to_path_buf(),
-                    full_contents,
-                    latest_save_offset,
-                    section_rotation,
-                    game_code: None,
-                    trainer_info: None,
-                };
-                let (trainer_info, game_code) = save.parse_trainer_info()?;
-                save.trainer_info = Some(trainer_info);
-                save.game_code = Some(game_code);
-
-                Ok(save)
-            } else {
-                log::error!("Invalid file length for a game save. Found: {read_len}, Expected: {GAME_SAVE_DATA_LENGTH}");
-                Err(std::io::ErrorKind::InvalidInput.into())
-            }
+            let _ = reader.read_to_end(&mut full_contents)?;
+            let mut save = Self::from_bytes(full_contents)?;
+            save.source = Some(p.as_ref().to_path_buf());
+            save.backup_dir = p.as_ref().parent().map(Path::to_path_buf);
+            Ok(save)
         } else {
             log::error!("No file at path: {}", p.as_ref().display());
             Err(std::io::ErrorKind::InvalidInput.into())
         }
     }
 
+    /// Parses save data which didn't come from a file, such as a generated save. It can only be
+    /// written out with `write_to_file`.
+    pub fn from_bytes(mut full_contents: Vec<u8>) -> io::Result<Self> {
+        let read_len = full_contents.len();
+        if read_len < SINGLE_BLOCK_SAVE_DATA_LENGTH {
+            log::error!("Invalid file length for a game save. Found: {read_len}, Expected: {GAME_SAVE_DATA_LENGTH}");
+            return Err(std::io::ErrorKind::InvalidInput.into());
+        } else if read_len < GAME_SAVE_DATA_LENGTH {
+            log::warn!("Save is only {read_len} bytes, treating it as a single save block");
+        }
+        let footer = if read_len > GAME_SAVE_DATA_LENGTH {
+            log::info!(
+                "Save has a {} byte footer after the save data",
+                read_len - GAME_SAVE_DATA_LENGTH
+            );
+            full_contents.split_off(GAME_SAVE_DATA_LENGTH)
+        } else {
+            Vec::new()
+        };
+
+        let latest_save_offset = determine_latest_game_save_offset(&full_contents)?;
+        let section_rotation = determine_section_rotation(latest_save_offset, &full_contents)?;
+        let mut save = SaveFile {
+            source: None,
+            full_contents,
+            latest_save_offset,
+            section_rotation,
+            game_code: None,
+            trainer_info: None,
+            backup_dir: None,
+            footer,
+        };
+        let (mut trainer_info, game_code) = save.parse_trainer_info()?;
+        save.game_code = Some(game_code);
+        let language = save.detect_language(trainer_info.id);
+        save.set_trainer_language(&mut trainer_info, language)?;
+        save.trainer_info = Some(trainer_info);
+
+        if !save.blocks_same_game() {
+            log::warn!(
+                "Save blocks disagree on the game, latest block is {game_code:?} but previous block is {:?}",
+                save.previous_block_game_code()
+            );
+        }
+
+        Ok(save)
+    }
+
+    /// Borrows `len` bytes at `offset`, erroring rather than panicking if the file is too short.
+    fn get_bytes(&self, offset: usize, len: usize) -> io::Result<&[u8]> {
+        self.full_contents
+            .get(offset..offset + len)
+            .ok_or_else(|| truncated_error(offset, len))
+    }
+
+    fn get_bytes_mut(&mut self, offset: usize, len: usize) -> io::Result<&mut [u8]> {
+        self.full_contents
+            .get_mut(offset..offset + len)
+            .ok_or_else(|| truncated_error(offset, len))
+    }
+
     fn get_offset_for_section(&self, section_id: u8) -> u64 {
         let new_section_id = (section_id + self.section_rotation) % NUMBER_OF_SECTIONS;
         self.latest_save_offset + (SECTION_SIZE * new_section_id as u64)
@@ -138,6 +359,174 @@ impl SaveFile {
         self.trainer_info.clone().unwrap()
     }
 
+    pub fn national_dex_unlocked(&self) -> io::Result<bool> {
+        let game_code = self.get_game_code();
+        let mut cursor = Cursor::new(&self.full_contents[..]);
+        cursor.set_position(self.get_offset_for_section(0) + game_code.pokedex_national_magic());
+        Ok(cursor.read_u8()? == game_code.national_magic_value())
+    }
+
+    /// The game reported by the save block which wasn't chosen as the latest, if that block has
+    /// been written and its trainer section is intact.
+    pub fn previous_block_game_code(&self) -> Option<GameCode> {
+        determine_block_game_code(self.previous_save_offset(), &self.full_contents)
+    }
+
+    /// Whether both save blocks belong to the same game. A mismatch means the file was stitched
+    /// together from different saves.
+    pub fn blocks_same_game(&self) -> bool {
+        self.previous_block_game_code()
+            .map(|game_code| game_code == self.get_game_code())
+            .unwrap_or(true)
+    }
+
+    /// Whether both save blocks, where both have been written, belong to the same trainer. A
+    /// mismatch signals a merged or corrupt save which isn't safe to edit.
+    pub fn blocks_consistent(&self) -> bool {
+        determine_block_trainer_id(self.previous_save_offset(), &self.full_contents)
+            .map(|trainer_id| trainer_id == self.get_trainer_info().id)
+            .unwrap_or(true)
+    }
+
+    /// The block holding the latest save, which all reads and writes go to.
+    pub fn latest_block(&self) -> SaveBlock {
+        if self.latest_save_offset == SAVE_A_OFFSET {
+            SaveBlock::A
+        } else {
+            SaveBlock::B
+        }
+    }
+
+    fn previous_save_offset(&self) -> u64 {
+        if self.latest_save_offset == SAVE_A_OFFSET {
+            SAVE_B_OFFSET
+        } else {
+            SAVE_A_OFFSET
+        }
+    }
+
+    /// The stars on the trainer card, scoped to the feats stored outside of the game stats: one
+    /// for entering the Hall of Fame and one for owning every regional dex entry (Hoenn
+    /// without Jirachi and Deoxys for RSE, Kanto without Mew for FRLG).
+    pub fn trainer_card_stars(&self) -> io::Result<u8> {
+        let game_code = self.get_game_code();
+        let mut stars = 0;
+        if self.get_flag(game_code.game_clear_flag())? {
+            stars += 1;
+        }
+
+        let dex_complete_count = match game_code {
+            GameCode::RubySapphire | GameCode::Emerald => 200,
+            GameCode::FireRedLeafGreen => 150,
+        };
+        let mut owned_count = 0;
+        for national_dex_number in 1..=NationalDexNumber::MAX {
+            let counts_for_star = Species::from_national_dex(national_dex_number)
+                .and_then(|species| species.regional_dex_number(game_code))
+                .is_some_and(|regional_dex_number| regional_dex_number <= dex_complete_count);
+            if counts_for_star
+                && self.pokemon_owned_in_dex(NationalDexNumber::new(national_dex_number)?)?
+            {
+                owned_count += 1;
+            }
+        }
+        if owned_count == dex_complete_count {
+            stars += 1;
+        }
+
+        Ok(stars)
+    }
+
+    pub fn pokedex_owned_count(&self) -> io::Result<u32> {
+        let owned = self.dex_flags(0, self.get_game_code().pokedex_owned())?;
+        Ok(count_dex_flags(owned))
+    }
+
+    /// Like the games, a species only counts as seen if all three copies of the seen flags agree.
+    pub fn pokedex_seen_count(&self) -> io::Result<u32> {
+        Ok(count_dex_flags(&self.seen_flags()?))
+    }
+
+    /// Owned and seen counts scoped to the regional dex as well as the national dex. The
+    /// national counts are reported even before the player has unlocked the national dex.
+    pub fn dex_completion(&self) -> io::Result<DexCompletion> {
+        let game_code = self.get_game_code();
+        let owned = self.dex_flags(0, game_code.pokedex_owned())?;
+        let seen = self.seen_flags()?;
+        let regional_count = |flags: &[u8]| {
+            (1..=NationalDexNumber::MAX)
+                .filter(|national_dex_number| {
+                    Species::from_national_dex(*national_dex_number)
+                        .and_then(|species| species.regional_dex_number(game_code))
+                        .is_some()
+                })
+                .filter(|national_dex_number| {
+                    let idx = usize::from(national_dex_number - 1);
+                    flags[idx >> 3] & (1 << (idx & 0b111)) != 0
+                })
+                .count() as u16
+        };
+
+        Ok(DexCompletion {
+            national_dex_unlocked: self.national_dex_unlocked()?,
+            regional_owned: regional_count(owned),
+            regional_seen: regional_count(&seen),
+            regional_total: game_code.regional_dex_size(),
+            national_owned: count_dex_flags(owned) as u16,
+            national_seen: count_dex_flags(&seen) as u16,
+            national_total: NationalDexNumber::MAX,
+        })
+    }
+
+    fn seen_flags(&self) -> io::Result<Vec<u8>> {
+        let game_code = self.get_game_code();
+        let seen_a = self.dex_flags(0, game_code.pokedex_seen_a())?;
+        let seen_b = self.dex_flags(1, game_code.pokedex_seen_b())?;
+        let seen_c = self.dex_flags(4, game_code.pokedex_seen_c())?;
+        if seen_a != seen_b || seen_a != seen_c {
+            log::warn!("The copies of the dex seen flags disagree, only counting species seen in all of them");
+        }
+
+        Ok(seen_a
+            .iter()
+            .zip(seen_b)
+            .zip(seen_c)
+            .map(|((a, b), c)| a & b & c)
+            .collect())
+    }
+
+    fn dex_flags(&self, section_id: u8, offset: u64) -> io::Result<&[u8]> {
+        let offset = (self.get_offset_for_section(section_id) + offset) as usize;
+        self.get_bytes(offset, DEX_FLAGS_LENGTH)
+    }
+
+    fn pokemon_owned_in_dex(&self, national_dex_number: NationalDexNumber) -> io::Result<bool> {
+        let bit_position = national_dex_number.index();
+        let offset = self.get_offset_for_section(0) as usize
+            + self.get_game_code().pokedex_owned() as usize
+            + (bit_position >> 3);
+        Ok(self.get_bytes(offset, 1)?[0] & (1 << (bit_position & 0b111)) != 0)
+    }
+
+    /// Whether each of the eight badges of the game's region has been obtained, in order.
+    pub fn get_badges(&self) -> io::Result<[bool; 8]> {
+        let first_badge_flag = self.get_game_code().first_badge_flag();
+        let mut badges = [false; 8];
+        for (badge, flag) in badges.iter_mut().zip(first_badge_flag..) {
+            *badge = self.get_flag(flag)?;
+        }
+        Ok(badges)
+    }
+
+    /// Reads an event flag out of the flags array in the save block starting at section 1.
+    fn get_flag(&self, flag: u16) -> io::Result<bool> {
+        let flags_offset = self.get_game_code().flags() + usize::from(flag >> 3);
+        let section_id = 1 + (flags_offset / SECTION_DATA_SIZE) as u8;
+        let offset =
+            self.get_offset_for_section(section_id) as usize + flags_offset % SECTION_DATA_SIZE;
+        Ok(self.get_bytes(offset, 1)?[0] & (1 << (flag & 0b111)) != 0)
+    }
+
     pub fn get_party(&self) -> io::Result<Vec<Pokemon>> {
         let section_offset = self.get_offset_for_section(1);
         let mut cursor = Cursor::new(&self.full_contents[..]);
@@ -154,6 +543,160 @@ impl SaveFile {
             .collect::<Result<Vec<_>, _>>()
     }
 
+    /// Replaces the party Pokemon in `slot` (1-6) with party-format PK3 data. A slot right after
+    /// the last party member adds to the party instead, the party can't have gaps.
+    pub fn set_party_pokemon(&mut self, slot: u8, pk3_data: &[u8]) -> io::Result<()> {
+        if pk3_data.len() != pokemon::PK3_SIZE_PARTY {
+            log::error!(
+                "Expected {}, got {} bytes for party pk3 data format",
+                pokemon::PK3_SIZE_PARTY,
+                pk3_data.len()
+            );
+            return Err(io::ErrorKind::InvalidInput.into());
+        }
+
+        let team_size_offset = self.team_size_offset();
+        let team_size = LittleEndian::read_u32(self.get_bytes(team_size_offset, 4)?);
+        if !(1..=MAX_PARTY_SIZE).contains(&slot) || u32::from(slot) > team_size + 1 {
+            log::error!("Invalid party slot {slot} for a party of {team_size}");
+            return Err(io::ErrorKind::InvalidInput.into());
+        }
+
+        let pk3_offset = team_size_offset + 4 + (slot as usize - 1) * pokemon::PK3_SIZE_PARTY;
+        self.get_bytes_mut(pk3_offset, pokemon::PK3_SIZE_PARTY)?
+            .copy_from_slice(pk3_data);
+        if u32::from(slot) > team_size {
+            LittleEndian::write_u32(self.get_bytes_mut(team_size_offset, 4)?, u32::from(slot));
+        }
+        self.recompute_checksums()
+    }
+
+    pub fn get_money(&self) -> io::Result<u32> {
+        let offset = (self.get_offset_for_section(1) + self.get_game_code().money()) as usize;
+        Ok(LittleEndian::read_u32(self.get_bytes(offset, 4)?) ^ self.read_security_key()?)
+    }
+
+    /// The item id and quantity of each occupied slot across the bag pockets. Emerald and FRLG
+    /// obscure the quantities with the security key.
+    pub fn get_items(&self) -> io::Result<Vec<(u16, u16)>> {
+        let quantity_key = (self.read_security_key()? & 0xffff) as u16;
+        let section_offset = self.get_offset_for_section(1);
+        let mut items = Vec::new();
+        for (pocket_offset, slots) in self.get_game_code().bag_pockets() {
+            let offset = (section_offset + pocket_offset) as usize;
+            let pocket = self.get_bytes(offset, usize::from(*slots) * 4)?;
+            for slot in pocket.chunks_exact(4) {
+                let item_id = LittleEndian::read_u16(&slot[..2]);
+                if item_id != 0 {
+                    items.push((item_id, LittleEndian::read_u16(&slot[2..]) ^ quantity_key));
+                }
+            }
+        }
+        Ok(items)
+    }
+
+    /// Sets the player's money, which the games cap at `MAX_MONEY`. The section checksum is
+    /// recomputed when the save is written.
+    pub fn set_money(&mut self, money: u32) -> io::Result<()> {
+        if money > MAX_MONEY {
+            log::error!("Invalid money {money}, must be at most {MAX_MONEY}");
+            return Err(io::ErrorKind::InvalidInput.into());
+        }
+        let security_key = self.read_security_key()?;
+        let offset = (self.get_offset_for_section(1) + self.get_game_code().money()) as usize;
+        LittleEndian::write_u32(self.get_bytes_mut(offset, 4)?, money ^ security_key);
+        Ok(())
+    }
+
+    /// Adds party-format PK3 data after the last party member, returning the slot it went to.
+    pub fn add_to_party(&mut self, pk3_data: &[u8]) -> io::Result<u8> {
+        let party_size = self.party_size()?;
+        if party_size >= MAX_PARTY_SIZE {
+            log::error!("Cannot add to the party, it already has {party_size} members");
+            return Err(io::Error::new(io::ErrorKind::StorageFull, "party is full"));
+        }
+
+        self.set_party_pokemon(party_size + 1, pk3_data)?;
+        Ok(party_size + 1)
+    }
+
+    pub fn party_size(&self) -> io::Result<u8> {
+        let team_size = LittleEndian::read_u32(self.get_bytes(self.team_size_offset(), 4)?);
+        u8::try_from(team_size).map_err(|_| io::ErrorKind::InvalidData.into())
+    }
+
+    fn team_size_offset(&self) -> usize {
+        (self.get_offset_for_section(1) + self.get_game_code().team_size_offset()) as usize
+    }
+
+    pub fn get_resources(&self) -> io::Result<Resources> {
+        let game_code = self.get_game_code();
+        let security_key = self.read_security_key()?;
+        let mut cursor = Cursor::new(&self.full_contents[..]);
+
+        let money = self.get_money()?;
+        cursor.set_position(self.get_offset_for_section(1) + game_code.coins());
+        let coins = cursor.read_u16::<LittleEndian>()? ^ (security_key & 0xffff) as u16;
+        let battle_points = match game_code.battle_points() {
+            Some(offset) => {
+                cursor.set_position(self.get_offset_for_section(0) + offset);
+                Some(cursor.read_u16::<LittleEndian>()?)
+            }
+            None => None,
+        };
+
+        Ok(Resources {
+            money,
+            coins,
+            battle_points,
+        })
+    }
+
+    /// The key Emerald and FRLG XOR money, coins and item quantities with. Ruby and Sapphire
+    /// store those in the clear, which a key of 0 leaves unchanged.
+    pub fn read_security_key(&self) -> io::Result<u32> {
+        match self.get_game_code().security_key() {
+            Some(offset) => {
+                let mut cursor = Cursor::new(&self.full_contents[..]);
+                cursor.set_position(self.get_offset_for_section(0) + offset);
+                cursor.read_u32::<LittleEndian>()
+            }
+            None => Ok(0),
+        }
+    }
+
+    /// Gets the wallpaper id for each of the 14 PC boxes.
+    pub fn get_box_wallpapers(&self) -> io::Result<Vec<u8>> {
+        (1..=NUMBER_OF_BOXES)
+            .map(|box_number| {
+                let offset = self.get_offset_for_box_wallpaper(box_number);
+                Ok(self.get_bytes(offset, 1)?[0])
+            })
+            .collect()
+    }
+
+    pub fn set_box_wallpaper(&mut self, box_number: u8, wallpaper: u8) -> io::Result<()> {
+        if !(1..=NUMBER_OF_BOXES).contains(&box_number) {
+            log::error!("Invalid box number {box_number}, must be in [1, {NUMBER_OF_BOXES}]");
+            return Err(io::ErrorKind::InvalidInput.into());
+        }
+        let wallpaper_count = self.get_game_code().wallpaper_count();
+        if wallpaper >= wallpaper_count {
+            log::error!("Invalid wallpaper {wallpaper}, must be less than {wallpaper_count}");
+            return Err(io::ErrorKind::InvalidInput.into());
+        }
+
+        let offset = self.get_offset_for_box_wallpaper(box_number);
+        self.get_bytes_mut(offset, 1)?[0] = wallpaper;
+        Ok(())
+    }
+
+    fn get_offset_for_box_wallpaper(&self, box_number: u8) -> usize {
+        let pc_buffer_offset = PC_BUFFER_WALLPAPERS_OFFSET + (box_number - 1) as usize;
+        let section_id = PC_BUFFER_FIRST_SECTION + (pc_buffer_offset / SECTION_DATA_SIZE) as u8;
+        self.get_offset_for_section(section_id) as usize + (pc_buffer_offset % SECTION_DATA_SIZE)
+    }
+
     pub fn get_box(&self, box_number: u8) -> io::Result<Vec<(u8, Pokemon)>> {
         let box_pokemon = (1..=30)
             .map(|slot| self.get_pokemon_from_box(box_number, slot))
@@ -166,18 +709,9 @@ impl SaveFile {
     }
 
     pub fn verify_sections(&self) -> io::Result<()> {
-        for section_id in 0..NUMBER_OF_SECTIONS {
-            let section_offset = self.get_offset_for_section(section_id) as usize;
-            let section_data =
-                &self.full_contents[section_offset..section_offset + SECTION_SIZE as usize];
-            let checksum = compute_section_checksum(&section_data[..SECTION_DATA_SIZE])?;
-
-            let mut cursor = Cursor::new(section_data);
-            cursor.seek(SeekFrom::Start(SECTION_CHECKSUM_OFFSET))?;
-            let actual_checksum = cursor.read_u16::<LittleEndian>()?;
-
-            if checksum != actual_checksum {
-                log::error!("Computed checksum 0x{checksum:x} for section {section_id}, but checksum was 0x{actual_checksum:x}");
+        for (section_id, stored_checksum, computed_checksum) in self.section_checksums()? {
+            if computed_checksum != stored_checksum {
+                log::error!("Computed checksum 0x{computed_checksum:x} for section {section_id}, but checksum was 0x{stored_checksum:x}");
                 return Err(std::io::ErrorKind::InvalidData.into());
             }
         }
@@ -185,11 +719,40 @@ impl SaveFile {
         Ok(())
     }
 
+    /// The (section id, stored checksum, computed checksum) of each section in the latest save
+    /// block, for diagnosing corrupt saves.
+    pub fn section_checksums(&self) -> io::Result<Vec<(u8, u16, u16)>> {
+        (0..NUMBER_OF_SECTIONS)
+            .map(|section_id| {
+                let section_offset = self.get_offset_for_section(section_id) as usize;
+                let section_data = self.get_bytes(section_offset, SECTION_SIZE as usize)?;
+                let computed_checksum =
+                    compute_section_checksum(&section_data[..SECTION_DATA_SIZE])?;
+
+                let mut cursor = Cursor::new(section_data);
+                cursor.seek(SeekFrom::Start(SECTION_CHECKSUM_OFFSET))?;
+                let stored_checksum = cursor.read_u16::<LittleEndian>()?;
+                Ok((section_id, stored_checksum, computed_checksum))
+            })
+            .collect()
+    }
+
+    /// Rewrites any section checksums in the latest save block which don't match their data,
+    /// returning how many were repaired.
+    pub fn repair_checksums(&mut self) -> io::Result<usize> {
+        let mismatched = self
+            .section_checksums()?
+            .iter()
+            .filter(|(_, stored_checksum, computed_checksum)| stored_checksum != computed_checksum)
+            .count();
+        self.recompute_checksums()?;
+        Ok(mismatched)
+    }
+
     fn recompute_checksums(&mut self) -> io::Result<()> {
         for section_id in 0..NUMBER_OF_SECTIONS {
             let section_offset = self.get_offset_for_section(section_id) as usize;
-            let section_data =
-                &mut self.full_contents[section_offset..section_offset + SECTION_SIZE as usize];
+            let section_data = self.get_bytes_mut(section_offset, SECTION_SIZE as usize)?;
             let checksum = compute_section_checksum(&section_data[..SECTION_DATA_SIZE])?;
 
             let mut cursor = Cursor::new(section_data);
@@ -200,6 +763,43 @@ impl SaveFile {
         Ok(())
     }
 
+    /// Blanks the trainer name and zeroes the trainer and secret ids in both save blocks, and
+    /// optionally the playtime, so the save can be shared. Mons caught by the player will no
+    /// longer match their OT and so will be treated as traded for obedience.
+    pub fn anonymize(&mut self, clear_playtime: bool) -> io::Result<()> {
+        let previous_save_offset = self.previous_save_offset();
+        let previous_block_written = self.previous_block_game_code().is_some();
+
+        let mut section_offsets = vec![self.get_offset_for_section(0) as usize];
+        if previous_block_written {
+            let rotation = determine_section_rotation(previous_save_offset, &self.full_contents)?;
+            section_offsets.push(
+                (previous_save_offset + SECTION_SIZE * (rotation % NUMBER_OF_SECTIONS) as u64)
+                    as usize,
+            );
+        }
+
+        for section_offset in section_offsets {
+            let section_data = self.get_bytes_mut(section_offset, SECTION_SIZE as usize)?;
+            section_data[..TRAINER_NAME_LENGTH].fill(0xff);
+            section_data[TRAINER_ID_OFFSET..TRAINER_ID_OFFSET + 4].fill(0x00);
+            if clear_playtime {
+                section_data[TIME_PLAYED_OFFSET..TIME_PLAYED_OFFSET + TIME_PLAYED_LENGTH]
+                    .fill(0x00);
+            }
+            let checksum = compute_section_checksum(&section_data[..SECTION_DATA_SIZE])?;
+            let mut cursor = Cursor::new(section_data);
+            cursor.set_position(SECTION_CHECKSUM_OFFSET);
+            cursor.write_u16::<LittleEndian>(checksum)?;
+        }
+
+        let language = self.get_trainer_info().language;
+        let (mut trainer_info, _) = self.parse_trainer_info()?;
+        self.set_trainer_language(&mut trainer_info, language)?;
+        self.trainer_info = Some(trainer_info);
+        Ok(())
+    }
+
     pub fn get_pokemon_from_box(
         &self,
         box_number: u8,
@@ -211,7 +811,8 @@ impl SaveFile {
         log::trace!("Getting pokemon from box {box_number}-{slot_number}");
 
         let (section_id, relative_offset) =
-            compute_section_id_and_offset_for_box_slot(box_number, slot_number).unwrap();
+            compute_section_id_and_offset_for_box_slot(box_number, slot_number)
+                .ok_or(io::ErrorKind::InvalidInput)?;
         let section_offset = self.get_offset_for_section(section_id) as usize;
         if relative_offset + pokemon::PK3_SIZE_BOX > SECTION_DATA_SIZE {
             log::debug!("Retrieving straddling PK3 at box {box_number} position {slot_number}");
@@ -223,8 +824,7 @@ impl SaveFile {
             let section_offset = self.get_offset_for_section(start_section_id) as usize;
             let bytes_from_first_section = SECTION_DATA_SIZE - relative_offset;
             pk3_data[..bytes_from_first_section].copy_from_slice(
-                &self.full_contents
-                    [section_offset + relative_offset..section_offset + SECTION_DATA_SIZE],
+                self.get_bytes(section_offset + relative_offset, bytes_from_first_section)?,
             );
 
             // Next we grab the trailing part and copy that as well
@@ -234,9 +834,8 @@ impl SaveFile {
             let section_offset = self.get_offset_for_section(next_section_id) as usize;
 
             log::debug!("Copied {bytes_from_first_section} bytes, remaining {bytes_from_next_section} at offset {section_offset:x}");
-            pk3_data[bytes_from_first_section..].copy_from_slice(
-                &self.full_contents[section_offset..section_offset + bytes_from_next_section],
-            );
+            pk3_data[bytes_from_first_section..]
+                .copy_from_slice(self.get_bytes(section_offset, bytes_from_next_section)?);
 
             // Now we can check if there's even valid data here and attempt to parse
             if pk3_data.iter().any(|byte| *byte != 0x00) {
@@ -248,7 +847,7 @@ impl SaveFile {
         } else {
             log::debug!("Getting contiguous PK3 data from box {box_number} position {slot_number}");
             let pk3_offset = section_offset + relative_offset;
-            let pk3_data = &self.full_contents[pk3_offset..pk3_offset + pokemon::PK3_SIZE_BOX];
+            let pk3_data = self.get_bytes(pk3_offset, pokemon::PK3_SIZE_BOX)?;
             if pk3_data.iter().any(|byte| *byte != 0x00) {
                 log::trace!("Parsing PK3 from contiguous data");
                 Ok(Some(Pokemon::from_pk3(pk3_data)?))
@@ -292,13 +891,22 @@ impl SaveFile {
             );
             return Err(io::ErrorKind::InvalidInput.into());
         }
+        // Checked before the dex is touched so a bad slot leaves the save unchanged
+        let (section_id, relative_offset) =
+            compute_section_id_and_offset_for_box_slot(box_number, slot_number)
+                .ok_or(io::ErrorKind::InvalidInput)?;
 
+        // A species without a dex entry shouldn't stop the Pokemon from being stored
         if let Ok(pk3) = Pokemon::from_pk3(pk3_data) {
-            self.mark_pokemon_owned_in_dex(pk3.species)?;
+            match pk3.species.national_dex_number() {
+                Ok(national_dex_number) => self.mark_pokemon_owned_in_dex(national_dex_number)?,
+                Err(_) => log::warn!(
+                    "Not marking {:?} as owned in the dex, it has no national dex number",
+                    pk3.species
+                ),
+            }
         }
 
-        let (section_id, relative_offset) =
-            compute_section_id_and_offset_for_box_slot(box_number, slot_number).unwrap();
         let section_offset = self.get_offset_for_section(section_id) as usize;
 
         if relative_offset + pokemon::PK3_SIZE_BOX > SECTION_DATA_SIZE {
@@ -307,12 +915,16 @@ impl SaveFile {
             );
             let bytes_from_first_section = SECTION_DATA_SIZE - relative_offset;
             let bytes_from_next_section = pokemon::PK3_SIZE_BOX - bytes_from_first_section;
+            // The next section may wrap around to the start of the save block
+            let next_section_id = (section_id + 1) % NUMBER_OF_SECTIONS;
+            let next_section_offset = self.get_offset_for_section(next_section_id) as usize;
 
-            let pokemon_present = self.full_contents
-                [section_offset + relative_offset..section_offset + SECTION_DATA_SIZE]
+            let pokemon_present = self
+                .get_bytes(section_offset + relative_offset, bytes_from_first_section)?
                 .iter()
                 .any(|byte| *byte != 0x00)
-                || self.full_contents[section_offset..section_offset + bytes_from_next_section]
+                || self
+                    .get_bytes(next_section_offset, bytes_from_next_section)?
                     .iter()
                     .any(|byte| *byte != 0x00);
             if pokemon_present && !force {
@@ -320,19 +932,16 @@ impl SaveFile {
             }
 
             // First clear the first section up until the end of the section data
-            self.full_contents
-                [section_offset + relative_offset..section_offset + SECTION_DATA_SIZE]
+            self.get_bytes_mut(section_offset + relative_offset, bytes_from_first_section)?
                 .copy_from_slice(&pk3_data[..bytes_from_first_section]);
 
             // Next we grab the trailing part and clear that as well
-            let section_offset = self.get_offset_for_section(section_id + 1) as usize;
-            self.full_contents[section_offset..section_offset + bytes_from_next_section]
+            self.get_bytes_mut(next_section_offset, bytes_from_next_section)?
                 .copy_from_slice(&pk3_data[bytes_from_first_section..]);
             Ok(true)
         } else {
             let pk3_offset = section_offset + relative_offset;
-            let existing_pk3_data =
-                &mut self.full_contents[pk3_offset..pk3_offset + pokemon::PK3_SIZE_BOX];
+            let existing_pk3_data = self.get_bytes_mut(pk3_offset, pokemon::PK3_SIZE_BOX)?;
             let pokemon_present = existing_pk3_data.iter().any(|byte| *byte != 0x00);
 
             if pokemon_present && !force {
@@ -344,13 +953,58 @@ impl SaveFile {
         }
     }
 
+    /// The game which wrote the save. Saves only record which pair of games they're from, so the
+    /// version is taken from the player's own party mons, defaulting to the first of the pair.
+    pub fn detect_game(&self) -> OriginGame {
+        let candidates = match self.get_game_code() {
+            GameCode::RubySapphire => [OriginGame::Ruby, OriginGame::Sapphire],
+            GameCode::Emerald => [OriginGame::Emerald, OriginGame::Emerald],
+            GameCode::FireRedLeafGreen => [OriginGame::FireRed, OriginGame::LeafGreen],
+        };
+        let trainer_id = self.get_trainer_info().id;
+        self.get_party()
+            .unwrap_or_default()
+            .iter()
+            .filter(|pkmn| pkmn.original_trainer_id == trainer_id)
+            .map(|pkmn| pkmn.met_info.origin_game)
+            .find(|origin_game| candidates.contains(origin_game))
+            .unwrap_or(candidates[0])
+    }
+
+    /// Saves don't record their language, but mons the player caught carry it. Falls back to
+    /// English when the party has none of the player's own mons.
+    fn detect_language(&self, trainer_id: TrainerId) -> Language {
+        self.get_party()
+            .unwrap_or_default()
+            .iter()
+            .find(|pkmn| pkmn.original_trainer_id == trainer_id)
+            .map(|pkmn| pkmn.origin_language)
+            .unwrap_or(Language::English)
+    }
+
+    /// `parse_trainer_info` reads the name as Western text, so Japanese names are decoded again.
+    fn set_trainer_language(
+        &self,
+        trainer_info: &mut TrainerInfo,
+        language: Language,
+    ) -> io::Result<()> {
+        trainer_info.language = language;
+        if language == Language::Japanese {
+            let name_offset = self.get_offset_for_section(0) as usize;
+            trainer_info.player_name = decode_text_for_language(
+                self.get_bytes(name_offset, TRAINER_NAME_LENGTH)?,
+                Language::Japanese,
+            );
+        }
+        Ok(())
+    }
+
     fn parse_trainer_info(&self) -> io::Result<(TrainerInfo, GameCode)> {
         let section_offset = self.get_offset_for_section(0) as usize;
-        let section_data =
-            &self.full_contents[section_offset..section_offset + SECTION_SIZE as usize];
+        let section_data = self.get_bytes(section_offset, SECTION_SIZE as usize)?;
         let mut cursor = Cursor::new(section_data);
 
-        let mut player_name = [0u8; 7];
+        let mut player_name = [0u8; TRAINER_NAME_LENGTH];
         cursor.read_exact(&mut player_name)?;
         let _ = cursor.read_u8()?;
         let player_gender = determine_player_gender(cursor.read_u8()?)?;
@@ -373,16 +1027,21 @@ impl SaveFile {
         Ok((
             TrainerInfo {
                 player_name: decode_text(&player_name),
+                player_name_data: player_name.to_vec(),
                 player_gender,
                 id: trainer_id,
                 time_played: playtime,
+                language: Language::English,
             },
             game_code,
         ))
     }
 
-    fn mark_pokemon_owned_in_dex(&mut self, species: Species) -> io::Result<()> {
-        let bit_position = species.national_dex_number()? - 1;
+    fn mark_pokemon_owned_in_dex(
+        &mut self,
+        national_dex_number: NationalDexNumber,
+    ) -> io::Result<()> {
+        let bit_position = national_dex_number.index();
         let byte_number = bit_position >> 3;
         let bit_position = bit_position & 0b111;
 
@@ -414,15 +1073,76 @@ impl SaveFile {
         Ok(())
     }
 
+    /// Any bytes after the save data in the file it was read from, such as an emulator's RTC data.
+    pub fn footer(&self) -> &[u8] {
+        &self.footer
+    }
+
+    /// Writes the save to a temporary file next to `filepath` and renames it into place so that
+    /// an interrupted write can't leave a truncated save behind.
     pub fn write_to_file(&mut self, filepath: impl AsRef<Path>) -> io::Result<()> {
         self.recompute_checksums()?;
-        std::fs::write(filepath, &self.full_contents)
+        let filepath = filepath.as_ref();
+        let mut temp_name = std::ffi::OsString::from(".");
+        temp_name.push(filepath.file_name().ok_or(io::ErrorKind::InvalidInput)?);
+        temp_name.push(".tmp");
+        let temp_path = filepath.with_file_name(temp_name);
+
+        let contents = [&self.full_contents[..], &self.footer[..]].concat();
+        std::fs::write(&temp_path, &contents)?;
+        if let Err(err) = std::fs::rename(&temp_path, filepath) {
+            log::warn!(
+                "Could not rename {} over {}, writing it directly: {err}",
+                temp_path.display(),
+                filepath.display()
+            );
+            let _ = std::fs::remove_file(&temp_path);
+            std::fs::write(filepath, &contents)?;
+        }
+        Ok(())
     }
 
+    /// Overwrites the file the save was loaded from, first backing it up unless backups were
+    /// turned off with `set_backup_dir`.
     pub fn write_in_place(&mut self) -> io::Result<()> {
-        let source_file = self.source.clone();
+        let Some(source_file) = self.source.clone() else {
+            log::error!("Save data was not loaded from a file");
+            return Err(io::ErrorKind::NotFound.into());
+        };
+        if let Some(backup_dir) = self.backup_dir.clone() {
+            let backup = self.backup_to(&backup_dir)?;
+            log::debug!(
+                "Backed up {} to {}",
+                source_file.display(),
+                backup.display()
+            );
+        }
         self.write_to_file(source_file)
     }
+
+    /// Sets where `write_in_place` backs up the file before overwriting it, by default the
+    /// directory the save is in. `None` turns backups off for callers that manage their own.
+    pub fn set_backup_dir(&mut self, backup_dir: Option<PathBuf>) {
+        self.backup_dir = backup_dir;
+    }
+
+    /// Copies the file the save was loaded from into `backup_dir` under a name stamped with the
+    /// current time. An existing backup from the same second is kept since it's the older one.
+    pub fn backup_to(&self, backup_dir: &Path) -> io::Result<PathBuf> {
+        let Some(file_name) = self.source.as_ref().and_then(|source| source.file_name()) else {
+            log::error!("Save data was not loaded from a file");
+            return Err(io::ErrorKind::NotFound.into());
+        };
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        let backup = backup_dir.join(format!("{}.{timestamp}.bak", file_name.to_string_lossy()));
+        if !backup.exists() {
+            let _ = std::fs::copy(self.source.as_ref().unwrap(), &backup)?;
+        }
+        Ok(backup)
+    }
 }
 
 fn determine_latest_game_save_offset(save_data: &[u8]) -> std::io::Result<u64> {
@@ -430,21 +1150,47 @@ fn determine_latest_game_save_offset(save_data: &[u8]) -> std::io::Result<u64> {
     cursor.seek(SeekFrom::Start(SAVE_A_OFFSET + SAVE_INDEX_OFFSET))?;
     let save_index_a = cursor.read_u32::<LittleEndian>()?;
 
+    // A dump of only the first block is treated as if the second was never written
     cursor.seek(SeekFrom::Start(SAVE_B_OFFSET + SAVE_INDEX_OFFSET))?;
-    let save_index_b = cursor.read_u32::<LittleEndian>()?;
+    let save_index_b = cursor.read_u32::<LittleEndian>().unwrap_or(0xffffffff);
 
     log::debug!("Save Index A: {save_index_a}");
     log::debug!("Save Index B: {save_index_b}");
 
-    let offset = if save_index_a == 0xffffffff {
-        SAVE_B_OFFSET
+    let (offset, other_offset) = if save_index_a == 0xffffffff {
+        (SAVE_B_OFFSET, SAVE_A_OFFSET)
     } else if save_index_b == 0xffffffff || save_index_a > save_index_b {
-        SAVE_A_OFFSET
+        (SAVE_A_OFFSET, SAVE_B_OFFSET)
     } else {
-        SAVE_B_OFFSET
+        (SAVE_B_OFFSET, SAVE_A_OFFSET)
     };
 
-    Ok(offset)
+    // A block which was never written may still be picked if its save index happens to look valid
+    if block_section_ids_valid(offset, save_data) {
+        Ok(offset)
+    } else if block_section_ids_valid(other_offset, save_data) {
+        log::warn!("Save block at 0x{offset:x} has invalid section ids, falling back to the block at 0x{other_offset:x}");
+        Ok(other_offset)
+    } else {
+        log::error!("Neither save block has a valid set of section ids");
+        Err(io::ErrorKind::InvalidData.into())
+    }
+}
+
+fn block_section_ids_valid(save_offset: u64, save_data: &[u8]) -> bool {
+    let mut seen_sections = [false; NUMBER_OF_SECTIONS as usize];
+    for physical_section in 0..NUMBER_OF_SECTIONS as u64 {
+        let offset = (save_offset + (SECTION_SIZE * physical_section) + 0x0ff4) as usize;
+        let Some(section_id) = save_data.get(offset..offset + 2) else {
+            return false;
+        };
+        let section_id = u16::from_le_bytes([section_id[0], section_id[1]]) as usize;
+        match seen_sections.get_mut(section_id) {
+            Some(seen) if !*seen => *seen = true,
+            _ => return false,
+        }
+    }
+    true
 }
 
 fn determine_section_rotation(save_offset: u64, save_data: &[u8]) -> io::Result<u8> {
@@ -456,6 +1202,59 @@ fn determine_section_rotation(save_offset: u64, save_data: &[u8]) -> io::Result<
     Ok(section_rotation)
 }
 
+/// The trainer section of the block at `save_offset`, if the block has been written and the
+/// section's checksum is intact.
+fn block_trainer_section(save_offset: u64, save_data: &[u8]) -> Option<&[u8]> {
+    let mut cursor = Cursor::new(save_data);
+    cursor.set_position(save_offset + SAVE_INDEX_OFFSET);
+    if cursor.read_u32::<LittleEndian>().ok()? == 0xffffffff {
+        return None;
+    }
+
+    (0..NUMBER_OF_SECTIONS as u64).find_map(|physical_section| {
+        let section_offset = (save_offset + (SECTION_SIZE * physical_section)) as usize;
+        let section_data = save_data.get(section_offset..section_offset + SECTION_SIZE as usize)?;
+        let mut cursor = Cursor::new(section_data);
+        cursor.set_position(0x0ff4);
+        if cursor.read_u16::<LittleEndian>().ok()? != 0 {
+            return None;
+        }
+        cursor.set_position(SECTION_CHECKSUM_OFFSET);
+        let checksum = cursor.read_u16::<LittleEndian>().ok()?;
+        if compute_section_checksum(&section_data[..SECTION_DATA_SIZE]).ok()? != checksum {
+            return None;
+        }
+        Some(section_data)
+    })
+}
+
+fn determine_block_game_code(save_offset: u64, save_data: &[u8]) -> Option<GameCode> {
+    let mut cursor = Cursor::new(block_trainer_section(save_offset, save_data)?);
+    cursor.set_position(0xac);
+    Some(determine_game_code(cursor.read_u32::<LittleEndian>().ok()?))
+}
+
+fn determine_block_trainer_id(save_offset: u64, save_data: &[u8]) -> Option<TrainerId> {
+    let mut cursor = Cursor::new(block_trainer_section(save_offset, save_data)?);
+    cursor.set_position(0xa);
+    Some(TrainerId {
+        public_id: cursor.read_u16::<LittleEndian>().ok()?,
+        secret_id: cursor.read_u16::<LittleEndian>().ok()?,
+    })
+}
+
+fn truncated_error(offset: usize, len: usize) -> io::Error {
+    log::error!("Save data is truncated, unable to access {len} bytes at offset 0x{offset:x}");
+    io::ErrorKind::UnexpectedEof.into()
+}
+
+/// Counts the flags set for national dex species, ignoring the padding bits of the last byte.
+fn count_dex_flags(flags: &[u8]) -> u32 {
+    (0..usize::from(NationalDexNumber::MAX))
+        .filter(|idx| flags[idx >> 3] & (1 << (idx & 0b111)) != 0)
+        .count() as u32
+}
+
 fn compute_section_checksum(data: &[u8]) -> io::Result<u16> {
     assert_eq!(data.len(), SECTION_DATA_SIZE);
 
@@ -478,7 +1277,7 @@ fn determine_player_gender(data: u8) -> io::Result<PlayerGender> {
         Ok(PlayerGender::Female)
     } else {
         eprintln!("Invalid player gender: 0x{data:x}");
-        return Err(std::io::ErrorKind::InvalidData.into());
+        Err(std::io::ErrorKind::InvalidData.into())
     }
 }
 
@@ -497,12 +1296,12 @@ fn compute_section_id_and_offset_for_box_slot(
     box_number: u8,
     box_entry: u8,
 ) -> Option<(u8, usize)> {
-    let box_number = box_number as usize;
-    let box_entry = box_entry as usize;
-    if !(1..=16).contains(&box_number) || !(1..=30).contains(&box_entry) {
-        eprintln!("Invalid box entry: {box_entry} in box number: {box_number}");
+    if !(1..=NUMBER_OF_BOXES).contains(&box_number) || !(1..=30).contains(&box_entry) {
+        log::error!("Invalid box entry: {box_entry} in box number: {box_number}");
         return None;
     }
+    let box_number = box_number as usize;
+    let box_entry = box_entry as usize;
 
     let absolute_entry = ((box_number - 1) * 30) + (box_entry - 1);
     // Including the 4 bytes at the start of section 5 to make the math easier
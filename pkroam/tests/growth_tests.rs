@@ -0,0 +1,48 @@
+use pkroam::pk3::growth::GrowthRate;
+
+#[test]
+fn test_growth_rate_experience() {
+    let expected = [
+        (GrowthRate::MediumFast, 1000, 1_000_000),
+        (GrowthRate::Erratic, 1800, 600_000),
+        (GrowthRate::Fluctuating, 540, 1_640_000),
+        (GrowthRate::MediumSlow, 560, 1_059_860),
+        (GrowthRate::Fast, 800, 800_000),
+        (GrowthRate::Slow, 1250, 1_250_000),
+    ];
+    for (growth_rate, level_10, level_100) in expected {
+        assert_eq!(growth_rate.experience_for_level(1), 0, "{growth_rate:?}");
+        assert_eq!(
+            growth_rate.experience_for_level(10),
+            level_10,
+            "{growth_rate:?}"
+        );
+        assert_eq!(
+            growth_rate.experience_for_level(100),
+            level_100,
+            "{growth_rate:?}"
+        );
+
+        assert_eq!(growth_rate.level_for_experience(0), 1, "{growth_rate:?}");
+        assert_eq!(
+            growth_rate.level_for_experience(level_10 - 1),
+            9,
+            "{growth_rate:?}"
+        );
+        assert_eq!(
+            growth_rate.level_for_experience(level_10),
+            10,
+            "{growth_rate:?}"
+        );
+        assert_eq!(
+            growth_rate.level_for_experience(level_100),
+            100,
+            "{growth_rate:?}"
+        );
+        assert_eq!(
+            growth_rate.level_for_experience(u32::MAX),
+            100,
+            "{growth_rate:?}"
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use pkroam::pk4::{pk3_to_pk4, Pokemon, PAL_PARK_LOCATION, PK4_SIZE_BOX, PK4_SIZE_PARTY};
+
+const WURMPLE_PK3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "wurmple.pk3"
+);
+
+/// Encodes a name of capital letters with the terminator after it.
+fn encode_name(name: &str, len: usize) -> Vec<u8> {
+    let mut out = name
+        .bytes()
+        .map(|c| 0x012b + u16::from(c - b'A'))
+        .chain(std::iter::once(0xffff))
+        .flat_map(u16::to_le_bytes)
+        .collect::<Vec<_>>();
+    out.resize(2 * len, 0xff);
+    out
+}
+
+/// Builds a PK4 the way the games store it: checksummed, shuffled by the PV and encrypted.
+fn build_pk4(personality_value: u32) -> Vec<u8> {
+    let mut plain = [0u8; PK4_SIZE_BOX];
+    plain[0..4].copy_from_slice(&personality_value.to_le_bytes());
+    plain[0x08..0x0a].copy_from_slice(&265u16.to_le_bytes()); // Wurmple
+    plain[0x0a..0x0c].copy_from_slice(&13u16.to_le_bytes());
+    plain[0x0c..0x0e].copy_from_slice(&4437u16.to_le_bytes());
+    plain[0x0e..0x10].copy_from_slice(&28113u16.to_le_bytes());
+    plain[0x10..0x14].copy_from_slice(&1000u32.to_le_bytes());
+    plain[0x17] = 2;
+    plain[0x18] = 252;
+    plain[0x28..0x2a].copy_from_slice(&33u16.to_le_bytes());
+    plain[0x38..0x3c].copy_from_slice(&(31u32 | (31 << 25)).to_le_bytes());
+    plain[0x48..0x5e].copy_from_slice(&encode_name("WURMPLE", 11));
+    plain[0x5f] = 3;
+    plain[0x68..0x78].copy_from_slice(&encode_name("SHANE", 8));
+
+    let checksum = plain[8..]
+        .chunks_exact(2)
+        .map(|word| u16::from_le_bytes([word[0], word[1]]))
+        .fold(0u16, u16::wrapping_add);
+    plain[6..8].copy_from_slice(&checksum.to_le_bytes());
+
+    let orders = [
+        "ABCD", "ABDC", "ACBD", "ACDB", "ADBC", "ADCB", "BACD", "BADC", "BCAD", "BCDA", "BDAC",
+        "BDCA", "CABD", "CADB", "CBAD", "CBDA", "CDAB", "CDBA", "DABC", "DACB", "DBAC", "DBCA",
+        "DCAB", "DCBA",
+    ];
+    let order = orders[((personality_value & 0x3e000) >> 13) as usize % 24];
+    let mut pk4 = plain[..8].to_vec();
+    for block in order.bytes() {
+        let start = 8 + 32 * usize::from(block - b'A');
+        pk4.extend_from_slice(&plain[start..start + 32]);
+    }
+
+    let mut seed = u32::from(checksum);
+    for word in pk4[8..].chunks_exact_mut(2) {
+        seed = seed.wrapping_mul(0x41c64e6d).wrapping_add(0x6073);
+        let value = u16::from_le_bytes([word[0], word[1]]) ^ (seed >> 16) as u16;
+        word.copy_from_slice(&value.to_le_bytes());
+    }
+    pk4
+}
+
+#[test]
+fn test_from_pk4() {
+    // A PV with a shuffle value of 9, storing the blocks as B, C, D, A
+    let pk4 = build_pk4(9 << 13);
+    let pkmn = Pokemon::from_pk4(&pk4).unwrap();
+    assert!(pkmn.checksum_is_valid());
+    assert_eq!(pkmn.national_dex_number, 265);
+    assert_eq!(pkmn.species(), Some(pkroam::pk3::species::Species::Wurmple));
+    assert_eq!(pkmn.held_item, 13);
+    assert_eq!(pkmn.original_trainer_id.public_id, 4437);
+    assert_eq!(pkmn.original_trainer_id.secret_id, 28113);
+    assert_eq!(pkmn.experience, 1000);
+    assert_eq!(pkmn.language, 2);
+    assert_eq!(pkmn.evs, [252, 0, 0, 0, 0, 0]);
+    assert_eq!(pkmn.moves, [33, 0, 0, 0]);
+    assert_eq!(pkmn.ivs, [31, 0, 0, 0, 0, 31]);
+    assert!(!pkmn.is_egg);
+    assert_eq!(pkmn.nickname, "WURMPLE");
+    assert_eq!(pkmn.origin_game, 3);
+    assert_eq!(pkmn.original_trainer_name, "SHANE");
+}
+
+#[test]
+fn test_from_pk4_every_shuffle() {
+    for shuffle in 0..24 {
+        let pkmn = Pokemon::from_pk4(&build_pk4(0x1234 | (shuffle << 13))).unwrap();
+        assert!(pkmn.checksum_is_valid(), "shuffle {shuffle}");
+        assert_eq!(pkmn.nickname, "WURMPLE");
+    }
+}
+
+#[test]
+fn test_from_pk4_party_and_bad_data() {
+    let mut pk4 = build_pk4(0xdead_beef);
+    pk4.resize(PK4_SIZE_PARTY, 0);
+    assert_eq!(Pokemon::from_pk4(&pk4).unwrap().nickname, "WURMPLE");
+
+    assert!(Pokemon::from_pk4(&pk4[..100]).is_err());
+    pk4[0x50] ^= 0xff;
+    assert!(!Pokemon::from_pk4(&pk4).unwrap().checksum_is_valid());
+}
+
+#[test]
+fn test_pk3_to_pk4() {
+    let pk3 = pkroam::pk3::Pokemon::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let pk4 = pk3_to_pk4(&pk3).unwrap();
+    assert_eq!(pk4.len(), PK4_SIZE_BOX);
+
+    let pkmn = Pokemon::from_pk4(&pk4).unwrap();
+    assert!(pkmn.checksum_is_valid());
+    assert_eq!(pkmn.personality_value, pk3.personality_value);
+    assert_eq!(pkmn.species(), Some(pk3.species));
+    assert_eq!(pkmn.original_trainer_id, pk3.original_trainer_id);
+    assert_eq!(pkmn.experience, pk3.experience);
+    assert_eq!(pkmn.ivs, [16, 16, 30, 15, 31, 27]);
+    assert_eq!(pkmn.moves, pk3.moves);
+    assert!(!pkmn.is_nicknamed);
+    assert_eq!(pkmn.nickname, "WURMPLE");
+    assert_eq!(pkmn.original_trainer_name, pk3.original_trainer_name);
+    assert_eq!(pkmn.origin_game, u8::from(pk3.met_info.origin_game));
+    assert_eq!(
+        u16::from_le_bytes([pkmn.source_data[0x80], pkmn.source_data[0x81]]),
+        PAL_PARK_LOCATION
+    );
+    assert_eq!(pkmn.source_data[0x84] & 0x7f, pk3.level());
+    // Shield Dust keeps its id
+    assert_eq!(pkmn.source_data[0x15], 19);
+
+    let mut holding = pk3.clone();
+    holding.held_item = 13;
+    assert!(pk3_to_pk4(&holding).is_err());
+}
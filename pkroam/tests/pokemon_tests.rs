@@ -0,0 +1,422 @@
+use pkroam::{
+    pk3::{
+        gender::Gender,
+        items::item_name,
+        moves::{move_display_name, move_name},
+        nature::Nature,
+        peek,
+        species::Species,
+        stats::Stats,
+        Ball, Language, MetInfo, MiscFlags, OriginGame, Pokemon,
+    },
+    TrainerId,
+};
+
+const WURMPLE_PK3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "wurmple.pk3"
+);
+const TREECKO_PK3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "treecko.pk3"
+);
+
+fn read_wurmple() -> Pokemon {
+    Pokemon::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap()
+}
+
+#[test]
+fn test_resync_experience_to_level() {
+    let mut wurmple = read_wurmple();
+    wurmple.resync_experience_to_level(23).unwrap();
+    assert_eq!(wurmple.level(), 23);
+    assert_eq!(wurmple.experience, 23 * 23 * 23);
+
+    let wurmple = Pokemon::from_pk3(&wurmple.to_pk3()).unwrap();
+    assert_eq!(wurmple.level(), 23);
+
+    let mut wurmple = read_wurmple();
+    assert!(wurmple.resync_experience_to_level(0).is_err());
+    assert!(wurmple.resync_experience_to_level(101).is_err());
+}
+
+#[test]
+fn test_is_nicknamed() {
+    assert_eq!(read_wurmple().is_nicknamed(), Some(false));
+
+    let treecko = Pokemon::from_pk3(&std::fs::read(TREECKO_PK3).unwrap()).unwrap();
+    assert_eq!(treecko.nickname, "Ghilli");
+    assert_eq!(treecko.is_nicknamed(), Some(true));
+}
+
+#[test]
+fn test_peek() {
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    assert_eq!(peek(&wurmple_pk3).unwrap(), (Species::Wurmple, 3));
+    let treecko_pk3 = std::fs::read(TREECKO_PK3).unwrap();
+    assert_eq!(peek(&treecko_pk3).unwrap(), (Species::Treecko, 5));
+    assert!(peek(&wurmple_pk3[..40]).is_err());
+}
+
+#[test]
+fn test_is_shiny() {
+    let mut wurmple = read_wurmple();
+    assert!(!wurmple.is_shiny());
+
+    let pv = wurmple.personality_value;
+    let shiny_id = TrainerId {
+        public_id: (pv & 0xffff) as u16,
+        secret_id: ((pv >> 16) ^ 7) as u16,
+    };
+    wurmple.set_original_trainer("Shane", shiny_id).unwrap();
+    assert!(wurmple.is_shiny());
+    let wurmple = Pokemon::from_pk3(&wurmple.to_pk3()).unwrap();
+    assert!(wurmple.is_shiny());
+
+    let mut wurmple = read_wurmple();
+    let zero_id = TrainerId {
+        public_id: 0,
+        secret_id: 0,
+    };
+    wurmple.set_original_trainer("Shane", zero_id).unwrap();
+    assert_eq!(wurmple.is_shiny(), ((pv >> 16) ^ (pv & 0xffff)) < 8);
+}
+
+#[test]
+fn test_misc_flags_round_trip() {
+    let raw = 0x8000_0003;
+    let misc_flags = MiscFlags::from(raw);
+    assert_eq!(misc_flags.ribbons, 3);
+    assert!(misc_flags.obedience);
+    assert_eq!(u32::from(misc_flags), raw);
+    assert!(!read_wurmple().misc_flags.obedience);
+}
+
+#[test]
+fn test_held_item() {
+    let wurmple = read_wurmple();
+    assert_eq!(wurmple.held_item, 0);
+    assert_eq!(wurmple.held_item_name(), None);
+
+    assert_eq!(item_name(1), Some("Master Ball"));
+    assert_eq!(item_name(186), Some("Choice Band"));
+    assert_eq!(item_name(200), Some("Leftovers"));
+    assert_eq!(item_name(52), None);
+    assert_eq!(item_name(376), Some("Old Sea Map"));
+    assert_eq!(item_name(377), None);
+}
+
+#[test]
+fn test_move_pp() {
+    let wurmple = read_wurmple();
+    assert_eq!(wurmple.moves, [33, 81, 0, 0]);
+    assert_eq!(wurmple.pp, [35, 40, 0, 0]);
+    assert_eq!(wurmple.pp_ups(), [0; 4]);
+
+    let mut wurmple = read_wurmple();
+    wurmple.pp_bonuses = 0b11_00_10_01;
+    assert_eq!(wurmple.pp_ups(), [1, 2, 0, 3]);
+}
+
+#[test]
+fn test_computed_stats() {
+    let mut wurmple = read_wurmple();
+    wurmple.resync_experience_to_level(50).unwrap();
+    wurmple.ivs = [31; 6];
+    wurmple.evs = [0; 6];
+    wurmple.personality_value = 25;
+    assert_eq!(wurmple.nature(), Nature::Hardy);
+    assert_eq!(
+        wurmple.computed_stats(),
+        Stats {
+            hp: 120,
+            attack: 65,
+            defense: 55,
+            special_attack: 40,
+            special_defense: 50,
+            speed: 40,
+        }
+    );
+
+    wurmple.personality_value = 28;
+    assert_eq!(wurmple.nature(), Nature::Adamant);
+    wurmple.evs = [252, 252, 0, 0, 0, 4];
+    let stats = wurmple.computed_stats();
+    assert_eq!(stats.hp, 152);
+    assert_eq!(stats.attack, 106);
+    assert_eq!(stats.special_attack, 36);
+    assert_eq!(stats.special_defense, 51);
+
+    wurmple.species = Species::Shedinja;
+    assert_eq!(wurmple.computed_stats().hp, 1);
+}
+
+#[test]
+fn test_to_pk3_checksum_round_trip() {
+    let mut wurmple = read_wurmple();
+    wurmple.clear_evs();
+    let pk3 = wurmple.to_pk3();
+
+    // Decrypt the data region the way the game does and check it against the stored checksum
+    let key = u32::from_le_bytes(pk3[0..4].try_into().unwrap())
+        ^ u32::from_le_bytes(pk3[4..8].try_into().unwrap());
+    let decrypted = pk3[32..80]
+        .chunks(4)
+        .flat_map(|dword| (u32::from_le_bytes(dword.try_into().unwrap()) ^ key).to_le_bytes())
+        .collect::<Vec<_>>();
+    let checksum = decrypted
+        .chunks(2)
+        .map(|word| u16::from_le_bytes(word.try_into().unwrap()))
+        .fold(0u16, |acc, word| acc.wrapping_add(word));
+    assert_eq!(u16::from_le_bytes([pk3[28], pk3[29]]), checksum);
+
+    let wurmple = Pokemon::from_pk3(&pk3).unwrap();
+    assert!(wurmple.checksum_is_valid());
+    assert_eq!(wurmple.evs, [0; 6]);
+}
+
+#[test]
+fn test_field_setters() {
+    for path in [WURMPLE_PK3, TREECKO_PK3] {
+        let mut pkmn = Pokemon::from_pk3(&std::fs::read(path).unwrap()).unwrap();
+        let (species, experience, evs) = (pkmn.species, pkmn.experience, pkmn.evs);
+        pkmn.set_nickname("Buddy").unwrap();
+        pkmn.set_moves([33, 45, 0, 0]);
+        pkmn.set_friendship(255);
+        assert!(pkmn.set_nickname("Bud\u{1f600}").is_err());
+
+        let pkmn = Pokemon::from_pk3(&pkmn.to_pk3()).unwrap();
+        assert!(pkmn.checksum_is_valid());
+        assert_eq!(pkmn.nickname, "Buddy");
+        assert_eq!(pkmn.moves, [33, 45, 0, 0]);
+        assert_eq!(pkmn.friendship, 255);
+        assert_eq!(pkmn.species, species);
+        assert_eq!(pkmn.experience, experience);
+        assert_eq!(pkmn.evs, evs);
+    }
+}
+
+#[test]
+fn test_is_valid() {
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    let wurmple = Pokemon::from_pk3(&wurmple_pk3).unwrap();
+    assert!(!wurmple.is_bad_egg);
+    assert!(wurmple.is_valid());
+
+    let mut bad_egg_pk3 = wurmple_pk3.clone();
+    bad_egg_pk3[19] |= 0b1;
+    let bad_egg = Pokemon::from_pk3(&bad_egg_pk3).unwrap();
+    assert!(bad_egg.is_bad_egg);
+    assert!(bad_egg.checksum_is_valid());
+    assert!(!bad_egg.is_valid());
+
+    let mut corrupt_pk3 = wurmple_pk3;
+    corrupt_pk3[28] ^= 0xff;
+    assert!(!Pokemon::from_pk3(&corrupt_pk3).unwrap().is_valid());
+}
+
+#[test]
+fn test_recompute_checksum_after_edit() {
+    let mut wurmple = read_wurmple();
+    wurmple.clear_evs();
+    assert!(wurmple.checksum_is_valid());
+
+    // The EVs substructure isn't in the third position for this personality value, so clearing
+    // them must leave the moves alone
+    let wurmple = Pokemon::from_pk3(&wurmple.to_pk3()).unwrap();
+    assert!(wurmple.checksum_is_valid());
+    assert_eq!(wurmple.evs, [0; 6]);
+    assert_eq!(wurmple.moves, [33, 81, 0, 0]);
+    assert_eq!(wurmple.pp, [35, 40, 0, 0]);
+}
+
+#[test]
+fn test_met_info() {
+    assert_eq!(
+        read_wurmple().met_info,
+        MetInfo {
+            location: 17,
+            level: 3,
+            origin_game: OriginGame::Emerald,
+            ball: Ball::Poke,
+            original_trainer_gender: Gender::Male,
+        }
+    );
+
+    let treecko = Pokemon::from_pk3(&std::fs::read(TREECKO_PK3).unwrap()).unwrap();
+    assert_eq!(treecko.met_info.location, 16);
+    assert_eq!(treecko.met_info.level, 5);
+    assert_eq!(treecko.met_info.origin_game, OriginGame::Ruby);
+}
+
+#[test]
+fn test_move_names() {
+    let wurmple = read_wurmple();
+    let names = wurmple
+        .moves
+        .iter()
+        .map(|move_id| move_name(*move_id))
+        .collect::<Vec<_>>();
+    assert_eq!(names, [Some("Tackle"), Some("String Shot"), None, None]);
+    assert_eq!(move_name(1), Some("Pound"));
+    assert_eq!(move_name(354), Some("Psycho Boost"));
+    assert_eq!(move_name(355), None);
+    assert_eq!(move_display_name(33), "Tackle");
+    assert_eq!(move_display_name(355), "Move #355");
+}
+
+#[test]
+fn test_decode_digits_and_punctuation() {
+    let mut wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    // "ASH 2!" followed by the terminator, written over the nickname in the unencrypted header
+    wurmple_pk3[8..18].copy_from_slice(&[0xbb, 0xcd, 0xc2, 0x00, 0xa3, 0xab, 0xff, 0, 0, 0]);
+    let wurmple = Pokemon::from_pk3(&wurmple_pk3).unwrap();
+    assert_eq!(wurmple.nickname, "ASH 2!");
+
+    wurmple_pk3[8..18].copy_from_slice(&[0xa1, 0xaa, 0xad, 0xae, 0xb8, 0xba, 0xac, 0xff, 0, 0]);
+    let wurmple = Pokemon::from_pk3(&wurmple_pk3).unwrap();
+    assert_eq!(wurmple.nickname, "09.-,/?");
+}
+
+#[test]
+fn test_decode_western_charmap() {
+    let mut wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    // "Zoé♀" then "Müñ’s·♂", each ended by the terminator
+    wurmple_pk3[8..18].copy_from_slice(&[0xd4, 0xe3, 0x1b, 0xb6, 0xff, 0xd9, 0, 0, 0, 0]);
+    assert_eq!(Pokemon::from_pk3(&wurmple_pk3).unwrap().nickname, "Zoé♀");
+
+    wurmple_pk3[8..18].copy_from_slice(&[0xc7, 0xf6, 0x29, 0xb4, 0xe7, 0xaf, 0xb5, 0xfa, 0, 0]);
+    assert_eq!(Pokemon::from_pk3(&wurmple_pk3).unwrap().nickname, "Müñ’s·♂");
+}
+
+#[test]
+fn test_encode_text() {
+    let encoded = pkroam::encode_text("Zoé♀ 2!", 10).unwrap();
+    assert_eq!(
+        encoded,
+        [0xd4, 0xe3, 0x1b, 0xb6, 0x00, 0xa3, 0xab, 0xff, 0xff, 0xff]
+    );
+    assert_eq!(pkroam::decode_text(&encoded), "Zoé♀ 2!");
+
+    assert_eq!(pkroam::encode_text("ABCDEFGHIJKL", 10).unwrap().len(), 10);
+    assert!(pkroam::encode_text("Bud\u{1f600}", 10).is_err());
+}
+
+#[test]
+fn test_decode_text_policy() {
+    use pkroam::{decode_text_with_policy, DecodePolicy};
+    // 0x0a has no character in the Western charmap
+    let text_data = [0xbc, 0xe6, 0x0a, 0xff];
+    assert_eq!(
+        decode_text_with_policy(&text_data, Language::English, DecodePolicy::Lossy).unwrap(),
+        "Br*"
+    );
+    assert!(decode_text_with_policy(&text_data, Language::English, DecodePolicy::Strict).is_err());
+    assert_eq!(
+        decode_text_with_policy(&text_data[..2], Language::English, DecodePolicy::Strict).unwrap(),
+        "Br"
+    );
+}
+
+#[test]
+fn test_decode_japanese_nickname() {
+    let mut wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    // "ケムッソ", with anything past the 5 character limit ignored
+    wurmple_pk3[8..18].copy_from_slice(&[0x59, 0x71, 0xa0, 0x5f, 0xff, 0xbb, 0xbb, 0, 0, 0]);
+    wurmple_pk3[18] = 1;
+    let wurmple = Pokemon::from_pk3(&wurmple_pk3).unwrap();
+    assert_eq!(wurmple.origin_language, Language::Japanese);
+    assert_eq!(wurmple.nickname, "ケムッソ");
+
+    assert_eq!(
+        pkroam::decode_text_for_language(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06], Language::Japanese),
+        "あいうえお"
+    );
+}
+
+#[test]
+fn test_characteristic() {
+    let mut wurmple = read_wurmple();
+    wurmple.personality_value = 0;
+    wurmple.ivs = [31, 12, 5, 20, 3, 30];
+    assert_eq!(wurmple.characteristic(), "Takes plenty of siestas");
+    wurmple.ivs = [10, 12, 5, 20, 3, 30];
+    assert_eq!(wurmple.characteristic(), "Strong willed");
+
+    // A tie between Attack and Sp. Defense goes to the first stat from PV % 6 onwards
+    wurmple.personality_value = 10;
+    wurmple.ivs = [0, 28, 0, 0, 0, 28];
+    assert_eq!(wurmple.characteristic(), "Hates to lose");
+    wurmple.personality_value = 1;
+    assert_eq!(wurmple.characteristic(), "Likes to fight");
+}
+
+#[test]
+fn test_iv_ev_totals() {
+    // The fixture has mixed IVs, a single one of them perfect, and no EVs
+    let mut wurmple = read_wurmple();
+    assert_eq!(wurmple.ivs, [16, 16, 30, 15, 31, 27]);
+    assert_eq!(wurmple.iv_total(), 135);
+    assert_eq!(wurmple.perfect_iv_count(), 1);
+    assert_eq!(wurmple.ev_total(), 0);
+
+    wurmple.ivs = [31; 6];
+    wurmple.evs = [252, 252, 0, 0, 0, 6];
+    assert_eq!(wurmple.iv_total(), 186);
+    assert_eq!(wurmple.perfect_iv_count(), 6);
+    assert_eq!(wurmple.ev_total(), 510);
+}
+
+#[test]
+fn test_to_party_pk3() {
+    let wurmple = read_wurmple();
+    let stats = wurmple.computed_stats();
+    let party_pk3 = wurmple.clone().to_party_pk3();
+    assert_eq!(party_pk3.len(), 100);
+    assert_eq!(party_pk3[..80], wurmple.to_pk3());
+    assert_eq!(party_pk3[84], 3);
+    assert_eq!(party_pk3[85], 0xff);
+    assert_eq!(u16::from_le_bytes([party_pk3[86], party_pk3[87]]), stats.hp);
+    assert_eq!(u16::from_le_bytes([party_pk3[88], party_pk3[89]]), stats.hp);
+    assert_eq!(
+        u16::from_le_bytes([party_pk3[98], party_pk3[99]]),
+        stats.special_defense
+    );
+}
+
+#[test]
+fn test_to_showdown() {
+    let mut wurmple = read_wurmple();
+    assert_eq!(
+        wurmple.to_showdown(),
+        "Wurmple (M)\nAbility: Shield Dust\nLevel: 3\nNaive Nature\n\
+         IVs: 16 HP / 16 Atk / 30 Def / 27 SpD / 15 Spe\n- Tackle\n- String Shot"
+    );
+
+    wurmple.set_nickname("WORMY").unwrap();
+    wurmple.held_item = 13;
+    wurmple.evs = [252, 0, 0, 6, 252, 0];
+    wurmple.ivs = [31, 0, 31, 31, 31, 31];
+    assert_eq!(
+        wurmple.to_showdown(),
+        "WORMY (Wurmple) (M) @ Potion\nAbility: Shield Dust\nLevel: 3\n\
+         EVs: 252 HP / 252 SpA / 6 Spe\nNaive Nature\nIVs: 0 Atk\n- Tackle\n- String Shot"
+    );
+}
+
+#[test]
+fn test_to_showdown_second_ability() {
+    let mut pkmn = read_wurmple();
+    pkmn.species = Species::Rattata;
+    pkmn.ability = 0;
+    assert_eq!(pkmn.ability_name(), Some("Run Away"));
+    pkmn.ability = 1;
+    assert_eq!(pkmn.ability_name(), Some("Guts"));
+    assert_eq!(pkmn.to_showdown().lines().nth(1), Some("Ability: Guts"));
+
+    // Species with one ability have it whichever way the bit is set
+    pkmn.species = Species::Wurmple;
+    assert_eq!(pkmn.ability_name(), Some("Shield Dust"));
+}
@@ -0,0 +1,652 @@
+mod common;
+
+use common::{section_checksum, SaveBuilder};
+use pkroam::{
+    pk3::{species::Species, Language, OriginGame, Pokemon},
+    save::{DexCompletion, GameCode, Resources, SaveFile},
+    TrainerId,
+};
+
+const EMERALD_SAVE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "emerald.sav"
+);
+const WURMPLE_PK3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "wurmple.pk3"
+);
+const TREECKO_PK3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "treecko.pk3"
+);
+const RUBY_SAVE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "ruby-with-treecko.sav"
+);
+
+#[test]
+fn test_box_wallpapers() {
+    let mut save = SaveFile::new(EMERALD_SAVE).unwrap();
+    let wallpapers = save.get_box_wallpapers().unwrap();
+    // New games cycle through the first four wallpapers
+    assert_eq!(wallpapers, vec![0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3, 0, 1]);
+
+    save.set_box_wallpaper(3, 16).unwrap();
+    assert_eq!(save.get_box_wallpapers().unwrap()[2], 16);
+    assert!(save.set_box_wallpaper(0, 1).is_err());
+    assert!(save.set_box_wallpaper(15, 1).is_err());
+    assert!(save.set_box_wallpaper(1, 17).is_err());
+
+    let mut save = SaveFile::new(RUBY_SAVE).unwrap();
+    assert!(save.set_box_wallpaper(1, 15).is_ok());
+    assert!(save.set_box_wallpaper(1, 16).is_err());
+}
+
+#[test]
+fn test_get_resources() {
+    let save = SaveFile::new(EMERALD_SAVE).unwrap();
+    assert_eq!(
+        save.get_resources().unwrap(),
+        Resources {
+            money: 22045,
+            coins: 0,
+            battle_points: Some(0),
+        }
+    );
+
+    let save = SaveFile::new(RUBY_SAVE).unwrap();
+    assert_eq!(
+        save.get_resources().unwrap(),
+        Resources {
+            money: 3000,
+            coins: 0,
+            battle_points: None,
+        }
+    );
+}
+
+#[test]
+fn test_get_money() {
+    let emerald = SaveFile::new(EMERALD_SAVE).unwrap();
+    assert_ne!(emerald.read_security_key().unwrap(), 0);
+    assert_eq!(emerald.get_money().unwrap(), 22045);
+
+    let ruby = SaveFile::new(RUBY_SAVE).unwrap();
+    assert_eq!(ruby.read_security_key().unwrap(), 0);
+    assert_eq!(ruby.get_money().unwrap(), 3000);
+}
+
+/// Overwrites bytes in the trainer section of block B, the previous save in the Emerald fixture,
+/// and fixes up the section checksum.
+fn patch_previous_trainer_section(save_data: &mut [u8], offset: usize, bytes: &[u8]) {
+    let section_offset = (0..14)
+        .map(|section| 0xe000 + (section * 0x1000))
+        .find(|offset| save_data[offset + 0xff4] == 0)
+        .unwrap();
+    save_data[section_offset + offset..section_offset + offset + bytes.len()]
+        .copy_from_slice(bytes);
+    let checksum = section_checksum(&save_data[section_offset..section_offset + 3968]);
+    save_data[section_offset + 0xff6..section_offset + 0xff8]
+        .copy_from_slice(&checksum.to_le_bytes());
+}
+
+#[test]
+fn test_blocks_same_game() {
+    let save = SaveFile::new(EMERALD_SAVE).unwrap();
+    assert_eq!(save.previous_block_game_code(), Some(GameCode::Emerald));
+    assert!(save.blocks_same_game());
+
+    // Rewrite the previous block's trainer section to claim to be Ruby/Sapphire
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    patch_previous_trainer_section(&mut save_data, 0xac, &[0; 4]);
+
+    let save = SaveFile::from_bytes(save_data).unwrap();
+    assert_eq!(save.get_game_code(), GameCode::Emerald);
+    assert_eq!(
+        save.previous_block_game_code(),
+        Some(GameCode::RubySapphire)
+    );
+    assert!(!save.blocks_same_game());
+}
+
+#[test]
+fn test_blocks_consistent() {
+    let save = SaveFile::new(EMERALD_SAVE).unwrap();
+    assert!(save.blocks_consistent());
+
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    patch_previous_trainer_section(&mut save_data, 0xa, &[0x39, 0x30]);
+    let save = SaveFile::from_bytes(save_data).unwrap();
+    assert!(save.blocks_same_game());
+    assert!(!save.blocks_consistent());
+}
+
+#[test]
+fn test_anonymize() {
+    let mut save = SaveFile::new(EMERALD_SAVE).unwrap();
+    let playtime = save.get_trainer_info().time_played;
+    save.anonymize(false).unwrap();
+    save.verify_sections().unwrap();
+    // The previous block's trainer section still has a valid checksum
+    assert_eq!(save.previous_block_game_code(), Some(GameCode::Emerald));
+
+    let trainer_info = save.get_trainer_info();
+    assert_eq!(trainer_info.player_name, "");
+    assert_eq!(trainer_info.id.public_id, 0);
+    assert_eq!(trainer_info.id.secret_id, 0);
+    assert_eq!(trainer_info.time_played.hours, playtime.hours);
+    assert_eq!(trainer_info.time_played.minutes, playtime.minutes);
+
+    save.anonymize(true).unwrap();
+    save.verify_sections().unwrap();
+    assert_eq!(save.get_trainer_info().time_played.hours, 0);
+    assert_eq!(save.get_trainer_info().time_played.minutes, 0);
+}
+
+#[test]
+fn test_unwritten_save_block() {
+    let save_data = std::fs::read(EMERALD_SAVE).unwrap();
+
+    // A fresh save only has block A written
+    let mut fresh_save_data = save_data.clone();
+    fresh_save_data[0xe000..0x1c000].fill(0xff);
+    let save = SaveFile::from_bytes(fresh_save_data).unwrap();
+    assert_eq!(save.get_trainer_info().player_name, "Shane");
+    assert_eq!(save.previous_block_game_code(), None);
+
+    // Block A claims to be the latest save but was never actually written
+    let mut garbage_save_data = save_data.clone();
+    garbage_save_data[..0xe000].fill(0x00);
+    garbage_save_data[0xffc..0x1000].copy_from_slice(&100u32.to_le_bytes());
+    let save = SaveFile::from_bytes(garbage_save_data).unwrap();
+    assert_eq!(save.get_trainer_info().player_name, "Shane");
+    assert_eq!(save.get_game_code(), GameCode::Emerald);
+
+    let mut empty_save_data = save_data;
+    empty_save_data[..0x1c000].fill(0x00);
+    assert!(SaveFile::from_bytes(empty_save_data).is_err());
+}
+
+#[test]
+fn test_put_pokemon_without_dex_number() {
+    // Turn Wurmple into a species which has no national dex entry
+    let mut pkmn = Pokemon::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let wurmple_index = (Species::Wurmple as u16).to_le_bytes();
+    let growth_offset = (32..80)
+        .step_by(12)
+        .find(|offset| pkmn.source_data[*offset..*offset + 2] == wurmple_index)
+        .unwrap();
+    pkmn.source_data[growth_offset..growth_offset + 2]
+        .copy_from_slice(&(Species::Egg as u16).to_le_bytes());
+    let pk3_data = pkmn.to_pk3();
+
+    let mut save = SaveFile::new(EMERALD_SAVE).unwrap();
+    assert!(save.get_pokemon_from_box(2, 1).unwrap().is_none());
+    assert!(save.put_pokemon_in_box(2, 1, &pk3_data, false).unwrap());
+    let stored = save.get_pokemon_from_box(2, 1).unwrap().unwrap();
+    assert_eq!(stored.species, Species::Egg);
+}
+
+#[test]
+fn test_national_dex_unlocked() {
+    let save = SaveFile::new(EMERALD_SAVE).unwrap();
+    assert!(!save.national_dex_unlocked().unwrap());
+    let save = SaveFile::new(RUBY_SAVE).unwrap();
+    assert!(!save.national_dex_unlocked().unwrap());
+
+    // Block A holds the latest Emerald save, with its trainer section at physical section 8
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    save_data[0x8000 + 0x1a] = 0xda;
+    let save = SaveFile::from_bytes(save_data).unwrap();
+    assert!(save.national_dex_unlocked().unwrap());
+}
+
+#[test]
+fn test_minimum_length_save() {
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    save_data.truncate(131072);
+    let mut save = SaveFile::from_bytes(save_data).unwrap();
+    save.verify_sections().unwrap();
+    save.get_party().unwrap();
+    for box_number in 1..=14 {
+        save.get_box(box_number).unwrap();
+    }
+    assert_eq!(save.get_box_wallpapers().unwrap().len(), 14);
+
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    assert!(save
+        .put_pokemon_in_box(14, 30, &wurmple_pk3, false)
+        .unwrap());
+    assert_eq!(save.get_box(14).unwrap()[0].0, 30);
+}
+
+#[test]
+fn test_single_block_save() {
+    // The latest block of the fixture is block A, which is all a 64KB dump holds
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    save_data.truncate(0x10000);
+    let dump = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    std::fs::write(&dump, &save_data).unwrap();
+
+    let mut save = SaveFile::new(&dump).unwrap();
+    save.verify_sections().unwrap();
+    assert_eq!(save.get_trainer_info().player_name, "Shane");
+    assert_eq!(save.get_party().unwrap().len(), 6);
+    assert!(save.previous_block_game_code().is_none());
+
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    assert!(save.put_pokemon_in_box(2, 20, &wurmple_pk3, false).unwrap());
+    save.set_backup_dir(None);
+    save.write_in_place().unwrap();
+    assert_eq!(std::fs::metadata(&dump).unwrap().len(), 0x10000);
+    let save = SaveFile::new(&dump).unwrap();
+    save.verify_sections().unwrap();
+    assert!(save.get_pokemon_from_box(2, 20).unwrap().is_some());
+
+    // Less than a whole block is still rejected
+    assert!(SaveFile::from_bytes(save_data[..0xd000].to_vec()).is_err());
+}
+
+#[test]
+fn test_save_with_footer() {
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    save_data.truncate(131072);
+    let footer = (0..16).collect::<Vec<u8>>();
+    save_data.extend_from_slice(&footer);
+    let dump = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    std::fs::write(&dump, &save_data).unwrap();
+
+    let mut save = SaveFile::new(&dump).unwrap();
+    save.verify_sections().unwrap();
+    assert_eq!(save.footer(), footer);
+    assert_eq!(save.get_trainer_info().player_name, "Shane");
+
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    assert!(save.put_pokemon_in_box(2, 20, &wurmple_pk3, false).unwrap());
+    save.set_backup_dir(None);
+    save.write_in_place().unwrap();
+    let written = std::fs::read(&dump).unwrap();
+    assert_eq!(written.len(), 131072 + footer.len());
+    assert_eq!(written[131072..], footer);
+    assert!(SaveFile::new(&dump)
+        .unwrap()
+        .get_pokemon_from_box(2, 20)
+        .unwrap()
+        .is_some());
+}
+
+#[test]
+fn test_section_checksums() {
+    let save = SaveFile::new(EMERALD_SAVE).unwrap();
+    let checksums = save.section_checksums().unwrap();
+    assert_eq!(checksums.len(), 14);
+    assert!(checksums
+        .iter()
+        .enumerate()
+        .all(
+            |(idx, (section_id, stored, computed))| usize::from(*section_id) == idx
+                && stored == computed
+        ));
+
+    // Section 0 of the latest block is at 0x8000 in this fixture
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    save_data[0x8000 + 0x20] ^= 0xff;
+    let save = SaveFile::from_bytes(save_data).unwrap();
+    let (section_id, stored, computed) = save.section_checksums().unwrap()[0];
+    assert_eq!(section_id, 0);
+    assert_ne!(stored, computed);
+    assert!(save.verify_sections().is_err());
+}
+
+#[test]
+fn test_trainer_card_stars() {
+    assert_eq!(
+        SaveFile::new(EMERALD_SAVE)
+            .unwrap()
+            .trainer_card_stars()
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        SaveFile::new(RUBY_SAVE)
+            .unwrap()
+            .trainer_card_stars()
+            .unwrap(),
+        0
+    );
+
+    // Set the game clear flag, 0x864 in the flags at 0x1270 of the save block starting at
+    // section 1, which lands in section 2 (physically at 0xa000 in the latest block)
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    let section_offset = 0xa000;
+    assert_eq!(save_data[section_offset + 0xff4], 2);
+    save_data[section_offset + 0x1270 + (0x864 >> 3) - 3968] |= 1 << (0x864 & 0b111);
+    let checksum = section_checksum(&save_data[section_offset..section_offset + 3968]);
+    save_data[section_offset + 0xff6..section_offset + 0xff8]
+        .copy_from_slice(&checksum.to_le_bytes());
+
+    let save = SaveFile::from_bytes(save_data).unwrap();
+    save.verify_sections().unwrap();
+    assert_eq!(save.trainer_card_stars().unwrap(), 1);
+}
+
+#[test]
+fn test_japanese_save_trainer_name() {
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    let save = SaveFile::from_bytes(save_data.clone()).unwrap();
+    assert_eq!(save.get_trainer_info().language, Language::English);
+
+    // "ユウキ" in section 0, and the player's first party mon (in section 1) marked Japanese
+    save_data[0x8000..0x8007].copy_from_slice(&[0x75, 0x53, 0x57, 0xff, 0xff, 0xbb, 0xbb]);
+    save_data[0x9000 + 0x238 + 18] = 1;
+    let mut save = SaveFile::from_bytes(save_data).unwrap();
+    let trainer_info = save.get_trainer_info();
+    assert_eq!(trainer_info.language, Language::Japanese);
+    assert_eq!(trainer_info.player_name, "ユウキ");
+
+    // Anonymizing keeps the detected language
+    save.anonymize(false).unwrap();
+    assert_eq!(save.get_trainer_info().language, Language::Japanese);
+}
+
+#[test]
+fn test_set_party_pokemon() {
+    let mut save = SaveFile::new(EMERALD_SAVE).unwrap();
+    let party = save.get_party().unwrap();
+    assert_eq!(party.len(), 6);
+
+    let mut lead = party[0].clone();
+    lead.clear_evs();
+    let lead_pk3 = lead.to_pk3();
+    save.set_party_pokemon(1, &lead_pk3).unwrap();
+    save.verify_sections().unwrap();
+    let party = save.get_party().unwrap();
+    assert_eq!(party.len(), 6);
+    assert_eq!(party[0].evs, [0; 6]);
+
+    // The party is already full and box data is missing the party stats
+    assert!(save.set_party_pokemon(7, &lead_pk3).is_err());
+    assert!(save.set_party_pokemon(2, &lead_pk3[..80]).is_err());
+}
+
+#[test]
+fn test_pokedex_counts() {
+    let mut save = SaveFile::new(EMERALD_SAVE).unwrap();
+    assert_eq!(save.pokedex_owned_count().unwrap(), 12);
+    assert_eq!(save.pokedex_seen_count().unwrap(), 45);
+
+    let treecko_pk3 = std::fs::read(TREECKO_PK3).unwrap();
+    assert!(save.put_pokemon_in_box(2, 1, &treecko_pk3, false).unwrap());
+    // Treecko was already seen when picking a starter
+    assert_eq!(save.pokedex_owned_count().unwrap(), 13);
+    assert_eq!(save.pokedex_seen_count().unwrap(), 45);
+
+    // Section 0 holds the first copy of the seen flags, Bulbasaur is the first flag
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    save_data[0x8000 + 0x5c] |= 1;
+    let save = SaveFile::from_bytes(save_data).unwrap();
+    assert_eq!(save.pokedex_seen_count().unwrap(), 45);
+}
+
+#[test]
+fn test_straddling_slot_wraps_around_save_block() {
+    // The latest block's sections are rotated so that section 5 is physically last and section
+    // 6 is at the start of the block. Box 2 slot 20 starts in section 5 and ends in section 6.
+    let save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    assert_eq!(save_data[0xdff4], 5);
+    assert_eq!(save_data[0x0ff4], 6);
+
+    let mut save = SaveFile::from_bytes(save_data).unwrap();
+    assert!(save.get_pokemon_from_box(2, 20).unwrap().is_none());
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    assert!(save.put_pokemon_in_box(2, 20, &wurmple_pk3, false).unwrap());
+    assert!(!save.put_pokemon_in_box(2, 20, &wurmple_pk3, false).unwrap());
+
+    let save_path = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    save.write_to_file(&save_path).unwrap();
+    let save_data = std::fs::read(&save_path).unwrap();
+    assert_eq!(save_data[0xd000 + 3924..0xd000 + 3968], wurmple_pk3[..44]);
+    assert_eq!(save_data[..36], wurmple_pk3[44..]);
+    let stored = SaveFile::from_bytes(save_data)
+        .unwrap()
+        .get_pokemon_from_box(2, 20)
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.to_pk3(), wurmple_pk3);
+}
+
+#[test]
+fn test_detect_game() {
+    let save = SaveFile::new(EMERALD_SAVE).unwrap();
+    assert_eq!(save.detect_game(), OriginGame::Emerald);
+    // Ruby and Sapphire share a layout, the version comes from the player's Treecko
+    let save = SaveFile::new(RUBY_SAVE).unwrap();
+    assert_eq!(save.detect_game(), OriginGame::Ruby);
+}
+
+#[test]
+fn test_add_to_party() {
+    let wurmple = Pokemon::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    let party_pk3 = wurmple.to_party_pk3();
+
+    let mut save = SaveFile::new(EMERALD_SAVE).unwrap();
+    assert_eq!(save.party_size().unwrap(), 6);
+    let err = save.add_to_party(&party_pk3).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::StorageFull);
+    assert_eq!(save.party_size().unwrap(), 6);
+
+    let mut save = SaveFile::new(RUBY_SAVE).unwrap();
+    let party_size = save.party_size().unwrap();
+    assert_eq!(save.add_to_party(&party_pk3).unwrap(), party_size + 1);
+    save.verify_sections().unwrap();
+    let party = save.get_party().unwrap();
+    assert_eq!(party.len(), usize::from(party_size) + 1);
+    assert_eq!(party.last().unwrap().species, Species::Wurmple);
+}
+
+#[test]
+fn test_write_in_place_backs_up() {
+    let dir = tempfile::tempdir().unwrap();
+    let backup_dir = dir.path().join("backups");
+    std::fs::create_dir(&backup_dir).unwrap();
+    let save_path = dir.path().join("emerald.sav");
+    let original = std::fs::read(EMERALD_SAVE).unwrap();
+    std::fs::write(&save_path, &original).unwrap();
+
+    let mut save = SaveFile::new(&save_path).unwrap();
+    save.set_backup_dir(Some(backup_dir.clone()));
+    save.take_pokemon_from_box(1, 1).unwrap().unwrap();
+    save.write_in_place().unwrap();
+    let backups = std::fs::read_dir(&backup_dir).unwrap().collect::<Vec<_>>();
+    assert_eq!(backups.len(), 1);
+    assert_eq!(
+        std::fs::read(backups[0].as_ref().unwrap().path()).unwrap(),
+        original
+    );
+
+    // By default the backup goes next to the save
+    let mut save = SaveFile::new(&save_path).unwrap();
+    save.write_in_place().unwrap();
+    let backups = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "bak"))
+        .collect::<Vec<_>>();
+    assert_eq!(backups.len(), 1);
+
+    // Backups can be turned off
+    let mut save = SaveFile::new(&save_path).unwrap();
+    save.set_backup_dir(None);
+    save.write_in_place().unwrap();
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 3);
+}
+
+#[test]
+fn test_synthetic_save() {
+    for game_code in [
+        GameCode::RubySapphire,
+        GameCode::Emerald,
+        GameCode::FireRedLeafGreen,
+    ] {
+        let trainer_id = TrainerId {
+            public_id: 4437,
+            secret_id: 28113,
+        };
+        let save_data = SaveBuilder::new(game_code)
+            .trainer("Brendan", trainer_id)
+            .build();
+        let save = SaveFile::from_bytes(save_data).unwrap();
+        save.verify_sections().unwrap();
+        assert_eq!(save.get_game_code(), game_code);
+        let trainer_info = save.get_trainer_info();
+        assert_eq!(trainer_info.player_name, "Brendan");
+        assert_eq!(trainer_info.id.public_id, 4437);
+        assert_eq!(trainer_info.id.secret_id, 28113);
+        assert_eq!(save.party_size().unwrap(), 0);
+        assert!((1..=14).all(|box_number| save.get_box(box_number).unwrap().is_empty()));
+    }
+}
+
+#[test]
+fn test_straddling_slot_in_every_rotation() {
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    for rotation in 0..14 {
+        let mut save = SaveFile::from_bytes(
+            SaveBuilder::new(GameCode::Emerald)
+                .rotation(rotation)
+                .build(),
+        )
+        .unwrap();
+        assert!(save.put_pokemon_in_box(2, 20, &wurmple_pk3, false).unwrap());
+        assert!(!save.put_pokemon_in_box(2, 20, &wurmple_pk3, false).unwrap());
+        let mut stored = save.get_box(2).unwrap();
+        assert_eq!(stored.len(), 1, "rotation {rotation}");
+        let (slot, mon) = stored.remove(0);
+        assert_eq!(slot, 20);
+        assert_eq!(mon.to_pk3(), wurmple_pk3);
+        assert!(save.get_box(1).unwrap().is_empty());
+        assert!(save.get_box(3).unwrap().is_empty());
+    }
+}
+
+#[test]
+fn test_synthetic_save_corrupt_checksum() {
+    let builder = SaveBuilder::new(GameCode::FireRedLeafGreen).rotation(3);
+    let mut save_data = builder.build();
+    save_data[builder.section_offset(4) + 0x100] ^= 0xff;
+    let save = SaveFile::from_bytes(save_data).unwrap();
+    let (section_id, stored, computed) = save.section_checksums().unwrap()[4];
+    assert_eq!(section_id, 4);
+    assert_ne!(stored, computed);
+    assert!(save.verify_sections().is_err());
+}
+
+#[test]
+fn test_write_to_file_replaces_save() {
+    let dir = tempfile::tempdir().unwrap();
+    let save_path = dir.path().join("emerald.sav");
+    std::fs::write(&save_path, b"not a save").unwrap();
+
+    let mut save = SaveFile::new(EMERALD_SAVE).unwrap();
+    save.write_to_file(&save_path).unwrap();
+    assert_eq!(
+        std::fs::read(&save_path).unwrap(),
+        std::fs::read(EMERALD_SAVE).unwrap()
+    );
+    // Only the save is left behind, not the temporary file it was written through
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn test_dex_completion() {
+    // Own Bulbasaur, which is outside of the Hoenn dex, on top of the fixture's progress
+    let mut save_data = std::fs::read(EMERALD_SAVE).unwrap();
+    save_data[0x8000 + 0x28] |= 0x01;
+    let save = SaveFile::from_bytes(save_data).unwrap();
+    assert_eq!(
+        save.dex_completion().unwrap(),
+        DexCompletion {
+            national_dex_unlocked: false,
+            regional_owned: 12,
+            regional_seen: 45,
+            regional_total: 202,
+            national_owned: 13,
+            national_seen: 45,
+            national_total: 386,
+        }
+    );
+}
+
+#[test]
+fn test_set_money() {
+    for save_path in [EMERALD_SAVE, RUBY_SAVE] {
+        let mut save = SaveFile::new(save_path).unwrap();
+        save.set_money(123_456).unwrap();
+        assert!(save.set_money(1_000_000).is_err());
+        assert_eq!(save.get_money().unwrap(), 123_456);
+
+        let written = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+        save.write_to_file(&written).unwrap();
+        let save = SaveFile::new(&written).unwrap();
+        save.verify_sections().unwrap();
+        assert_eq!(save.get_money().unwrap(), 123_456);
+    }
+}
+
+#[test]
+fn test_get_items() {
+    let items = SaveFile::new(EMERALD_SAVE).unwrap().get_items().unwrap();
+    assert_eq!(items.len(), 42);
+    // Potions are first in the items pocket and Nanab Berries last in the berries pocket
+    assert_eq!(items[0], (13, 10));
+    assert_eq!(items[41], (150, 9));
+    assert!(items.contains(&(259, 1)));
+
+    assert!(SaveFile::new(RUBY_SAVE)
+        .unwrap()
+        .get_items()
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_get_badges() {
+    let save = SaveFile::new(EMERALD_SAVE).unwrap();
+    let badges = save.get_badges().unwrap();
+    assert_eq!(
+        badges,
+        [true, true, false, false, false, false, false, false]
+    );
+    assert_eq!(save.get_game_code().badge_names()[1], "Knuckle");
+    assert_eq!(GameCode::FireRedLeafGreen.badge_names()[0], "Boulder");
+
+    assert_eq!(
+        SaveFile::new(RUBY_SAVE).unwrap().get_badges().unwrap(),
+        [false; 8]
+    );
+}
+
+#[test]
+fn test_invalid_box_slots() {
+    let mut save = SaveFile::new(EMERALD_SAVE).unwrap();
+    let wallpapers = save.get_box_wallpapers().unwrap();
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    for box_number in [0, 15, 16] {
+        let err = save.get_box(box_number).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        let err = save
+            .put_pokemon_in_box(box_number, 1, &wurmple_pk3[..80], true)
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+    for slot_number in [0, 31] {
+        let err = save.get_pokemon_from_box(1, slot_number).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+    // Boxes past the last one would overlap the box names and wallpapers
+    assert_eq!(save.get_box_wallpapers().unwrap(), wallpapers);
+}
@@ -0,0 +1,123 @@
+use pkroam::{
+    pk3::{gender::Gender, nature::Nature, showdown::parse_showdown, species::Species, OriginGame},
+    save::{SaveFile, TrainerInfo},
+};
+
+const EMERALD_SAVE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../pktools/tests/data/",
+    "emerald.sav"
+);
+
+fn trainer() -> TrainerInfo {
+    SaveFile::new(EMERALD_SAVE).unwrap().get_trainer_info()
+}
+
+#[test]
+fn test_parse_showdown() {
+    let set = "Sparky (Pikachu) (F) @ Light Ball\n\
+               Ability: Static\n\
+               Level: 50\n\
+               Shiny: Yes\n\
+               EVs: 4 HP / 252 SpA / 252 Spe\n\
+               Timid Nature\n\
+               IVs: 0 Atk\n\
+               - Thunderbolt\n\
+               - Hidden Power [Ice]\n\
+               - Thunder Wave\n\
+               - Quick Attack";
+    let trainer = trainer();
+    let pkmn = parse_showdown(set, &trainer, OriginGame::Emerald).unwrap();
+    assert!(pkmn.is_valid());
+    assert_eq!(pkmn.species, Species::Pikachu);
+    assert_eq!(pkmn.nickname, "Sparky");
+    assert_eq!(pkmn.held_item_name(), Some("Light Ball"));
+    assert_eq!(pkmn.level(), 50);
+    assert!(pkmn.is_shiny());
+    assert_eq!(pkmn.gender(), Gender::Female);
+    assert_eq!(pkmn.nature(), Nature::Timid);
+    assert_eq!(pkmn.evs, [4, 0, 0, 252, 252, 0]);
+    assert_eq!(pkmn.ivs, [31, 0, 31, 31, 31, 31]);
+    assert_eq!(pkmn.moves, [85, 237, 86, 98]);
+    assert_eq!(pkmn.original_trainer_id, trainer.id);
+    assert_eq!(pkmn.original_trainer_name, trainer.player_name);
+    assert_eq!(pkmn.met_info.origin_game, OriginGame::Emerald);
+
+    // The encrypted party blob reads back as the same Pokemon
+    let party = pkroam::pk3::Pokemon::from_pk3(&pkmn.to_party_pk3()).unwrap();
+    assert!(party.is_valid());
+    assert_eq!(party.nature(), Nature::Timid);
+}
+
+#[test]
+fn test_parse_showdown_round_trip() {
+    let trainer = trainer();
+    let pkmn = parse_showdown(
+        "Wurmple (M)\nLevel: 3\nNaive Nature\n\
+         IVs: 16 HP / 16 Atk / 30 Def / 27 SpD / 15 Spe\n- Tackle\n- String Shot",
+        &trainer,
+        OriginGame::Emerald,
+    )
+    .unwrap();
+    let exported = pkmn.to_showdown();
+    let reparsed = parse_showdown(&exported, &trainer, OriginGame::Emerald).unwrap();
+    assert_eq!(reparsed.to_showdown(), exported);
+    assert_eq!(reparsed.ivs, [16, 16, 30, 15, 31, 27]);
+}
+
+#[test]
+fn test_parse_showdown_ability() {
+    let trainer = trainer();
+    let set = "Rattata\nAbility: Guts\n- Tackle";
+    let pkmn = parse_showdown(set, &trainer, OriginGame::Emerald).unwrap();
+    assert_eq!(pkmn.ability, 1);
+    assert_eq!(pkmn.ability_name(), Some("Guts"));
+    assert_eq!(pkmn.personality_value & 1, 1);
+    let pkmn = parse_showdown(
+        "Rattata\nAbility: Run Away\n- Tackle",
+        &trainer,
+        OriginGame::Emerald,
+    )
+    .unwrap();
+    assert_eq!(pkmn.ability, 0);
+    assert_eq!(pkmn.personality_value & 1, 0);
+}
+
+#[test]
+fn test_parse_showdown_errors() {
+    let trainer = trainer();
+    let error = |set: &str| {
+        parse_showdown(set, &trainer, OriginGame::Emerald)
+            .unwrap_err()
+            .to_string()
+    };
+    assert_eq!(error("Pikablu\n- Tackle"), "Unknown species \"Pikablu\"");
+    assert_eq!(
+        error("Pikachu @ Choice Specs\n- Tackle"),
+        "Unknown item \"Choice Specs\""
+    );
+    assert_eq!(
+        error("Pikachu\n- Volt Switch"),
+        "Unknown move \"Volt Switch\""
+    );
+    assert_eq!(
+        error("Pikachu\nEVs: 252 HP / 252 Atk / 252 Spe\n- Tackle"),
+        "EV total 756 is over the limit of 510"
+    );
+    assert_eq!(
+        error("Pikachu\nIVs: 32 HP\n- Tackle"),
+        "32 HP is over the Gen 3 limit of 31"
+    );
+    assert_eq!(
+        error("Tauros (F)\n- Tackle"),
+        "Tauros can't have the requested nature, gender and ability"
+    );
+    assert_eq!(
+        error("Pikachu\nAbility: Lightning Rod\n- Tackle"),
+        "Pikachu can't have the ability \"Lightning Rod\""
+    );
+    assert_eq!(
+        error("Pikachu"),
+        "A set needs between 1 and 4 moves, found 0"
+    );
+}
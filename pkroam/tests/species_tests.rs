@@ -0,0 +1,198 @@
+use pkroam::{
+    pk3::{
+        gender::{Gender, GenderRatio},
+        species::{NationalDexNumber, Species},
+        stats::BaseStats,
+    },
+    save::GameCode,
+};
+
+#[test]
+fn test_hoenn_national_dex_numbers() {
+    let dex_number = |species: Species| species.national_dex_number().unwrap().get();
+    assert_eq!(dex_number(Species::Nincada), 290);
+    assert_eq!(dex_number(Species::Ninjask), 291);
+    assert_eq!(dex_number(Species::Shedinja), 292);
+    assert_eq!(dex_number(Species::Shroomish), 285);
+    assert_eq!(dex_number(Species::Breloom), 286);
+    assert_eq!(dex_number(Species::Corphish), 341);
+    assert_eq!(dex_number(Species::Crawdaunt), 342);
+}
+
+#[test]
+fn test_base_stats() {
+    assert_eq!(
+        Species::Bulbasaur.base_stats(),
+        BaseStats {
+            hp: 45,
+            attack: 49,
+            defense: 49,
+            special_attack: 65,
+            special_defense: 65,
+            speed: 45,
+        }
+    );
+    assert_eq!(
+        Species::Wurmple.base_stats(),
+        BaseStats {
+            hp: 45,
+            attack: 45,
+            defense: 35,
+            special_attack: 20,
+            special_defense: 30,
+            speed: 20,
+        }
+    );
+    assert_eq!(Species::Shedinja.base_stats().hp, 1);
+    assert_eq!(Species::Breloom.base_stats().attack, 130);
+    assert_eq!(Species::Crawdaunt.base_stats().attack, 120);
+    assert_eq!(Species::Deoxys.base_stats().speed, 150);
+}
+
+#[test]
+fn test_base_stat_total() {
+    assert_eq!(Species::Treecko.base_stat_total(), 310);
+    assert_eq!(Species::Slaking.base_stat_total(), 670);
+    assert_eq!(Species::Rayquaza.base_stat_total(), 680);
+    assert_eq!(
+        Species::UnownQMARK.base_stat_total(),
+        Species::Unown.base_stat_total()
+    );
+    assert_eq!(Species::Egg.base_stat_total(), 0);
+}
+
+#[test]
+fn test_species_name() {
+    assert_eq!(Species::Bulbasaur.name(), "Bulbasaur");
+    assert_eq!(Species::MrMime.name(), "Mr. Mime");
+    assert_eq!(Species::Wurmple.name(), "Wurmple");
+    assert_eq!(Species::UnownEMARK.name(), "Unown");
+    assert_eq!(Species::Deoxys.name(), "Deoxys");
+    assert_eq!(Species::Egg.name(), "Egg");
+}
+
+#[test]
+fn test_species_display() {
+    assert_eq!(Species::MrMime.to_string(), "Mr. Mime");
+    assert_eq!(Species::UnownB.to_string(), "Unown");
+    // Gen 3 internal indices skip ahead after Celebi and the Hoenn species are out of dex order
+    assert_eq!(Species::try_from(251).unwrap().to_string(), "Celebi");
+    assert_eq!(Species::try_from(277).unwrap().to_string(), "Treecko");
+    assert_eq!(Species::try_from(298).unwrap().to_string(), "Seedot");
+    assert_eq!(Species::try_from(411).unwrap().to_string(), "Chimecho");
+}
+
+#[test]
+fn test_nidoran_gender_symbols() {
+    assert_eq!(Species::NidoranF.name(), "Nidoran♀");
+    assert_eq!(Species::NidoranM.name(), "Nidoran♂");
+    assert_eq!(Species::try_from(29).unwrap().to_string(), "Nidoran♀");
+    assert_eq!(Species::try_from(32).unwrap().to_string(), "Nidoran♂");
+
+    // The games' default nickname is the uppercased name, which must survive a round trip
+    let encoded = pkroam::encode_text(&Species::NidoranM.name().to_uppercase(), 10).unwrap();
+    assert_eq!(pkroam::decode_text(&encoded), "NIDORAN♂");
+}
+
+#[test]
+fn test_national_dex_number() {
+    let bulbasaur = Species::Bulbasaur.national_dex_number().unwrap();
+    assert_eq!(bulbasaur.get(), 1);
+    assert_eq!(bulbasaur.index(), 0);
+    let deoxys = Species::Deoxys.national_dex_number().unwrap();
+    assert_eq!(deoxys.get(), 386);
+    assert_eq!(deoxys.index(), 385);
+    assert_eq!(Species::UnownB.national_dex_number().unwrap().get(), 201);
+    assert!(Species::Egg.national_dex_number().is_err());
+
+    assert!(NationalDexNumber::new(0).is_err());
+    assert!(NationalDexNumber::new(1).is_ok());
+    assert!(NationalDexNumber::new(386).is_ok());
+    assert!(NationalDexNumber::new(387).is_err());
+}
+
+#[test]
+fn test_gender_ratio() {
+    assert_eq!(Species::Bulbasaur.gender_ratio(), GenderRatio::Mixed(31));
+    assert_eq!(Species::Ralts.gender_ratio(), GenderRatio::Mixed(127));
+    assert_eq!(Species::Azurill.gender_ratio(), GenderRatio::Mixed(191));
+    assert_eq!(Species::NidoranM.gender_ratio(), GenderRatio::MaleOnly);
+    assert_eq!(Species::Chansey.gender_ratio(), GenderRatio::FemaleOnly);
+    assert_eq!(Species::Deoxys.gender_ratio(), GenderRatio::Genderless);
+    assert_eq!(Species::Egg.gender_ratio(), GenderRatio::Genderless);
+
+    let ralts = Species::Ralts.gender_ratio();
+    assert_eq!(
+        ralts.gender_for_personality_value(0x1234_567e),
+        Gender::Female
+    );
+    assert_eq!(
+        ralts.gender_for_personality_value(0x1234_567f),
+        Gender::Male
+    );
+    assert_eq!(
+        GenderRatio::Genderless.gender_for_personality_value(0),
+        Gender::Genderless
+    );
+}
+
+#[test]
+fn test_regional_dex_number() {
+    assert_eq!(
+        Species::Treecko.regional_dex_number(GameCode::Emerald),
+        Some(1)
+    );
+    assert_eq!(
+        Species::Wurmple.regional_dex_number(GameCode::RubySapphire),
+        Some(14)
+    );
+    assert_eq!(
+        Species::Absol.regional_dex_number(GameCode::Emerald),
+        Some(152)
+    );
+    assert_eq!(
+        Species::Deoxys.regional_dex_number(GameCode::RubySapphire),
+        Some(202)
+    );
+    assert_eq!(
+        Species::Bulbasaur.regional_dex_number(GameCode::Emerald),
+        None
+    );
+
+    assert_eq!(
+        Species::Bulbasaur.regional_dex_number(GameCode::FireRedLeafGreen),
+        Some(1)
+    );
+    assert_eq!(
+        Species::Mew.regional_dex_number(GameCode::FireRedLeafGreen),
+        Some(151)
+    );
+    assert_eq!(
+        Species::Treecko.regional_dex_number(GameCode::FireRedLeafGreen),
+        None
+    );
+    assert_eq!(Species::Egg.regional_dex_number(GameCode::Emerald), None);
+}
+
+#[test]
+fn test_from_national_dex() {
+    for species in [
+        Species::Bulbasaur,
+        Species::Mew,
+        Species::Chikorita,
+        Species::Celebi,
+        Species::Treecko,
+        Species::Wurmple,
+        Species::Chimecho,
+        Species::Deoxys,
+    ] {
+        let national_dex_number = species.national_dex_number().unwrap().get();
+        assert_eq!(
+            Species::from_national_dex(national_dex_number),
+            Some(species)
+        );
+    }
+    assert_eq!(Species::from_national_dex(201), Some(Species::Unown));
+    assert_eq!(Species::from_national_dex(0), None);
+    assert_eq!(Species::from_national_dex(387), None);
+}
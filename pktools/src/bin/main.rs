@@ -1,5 +1,5 @@
 use clap::{Parser, Subcommand};
-use pktools::{extract, insert, inspect};
+use pktools::{extract, insert, inspect, summary, verify, verify_dir};
 
 #[derive(Parser)]
 struct Opts {
@@ -12,6 +12,11 @@ enum ToolOpts {
     Extract(extract::Opts),
     Insert(insert::Opts),
     Inspect(inspect::Opts),
+    /// Give an overview of a save's trainer, progress and party
+    Summary(summary::Opts),
+    /// Check the section checksums of a save, exiting with an error if any are invalid
+    Verify(verify::Opts),
+    VerifyDir(verify_dir::Opts),
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -22,5 +27,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         ToolOpts::Extract(opts) => extract::run(opts),
         ToolOpts::Insert(opts) => insert::run(opts),
         ToolOpts::Inspect(opts) => inspect::run(opts),
+        ToolOpts::Summary(opts) => summary::run(opts),
+        ToolOpts::Verify(opts) => verify::run(opts),
+        ToolOpts::VerifyDir(opts) => verify_dir::run(opts),
     }
 }
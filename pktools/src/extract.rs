@@ -1,5 +1,5 @@
 use clap::Args;
-use pkroam::save::SaveFile;
+use pkroam::{pk3::Pokemon, save::SaveFile};
 use std::path::PathBuf;
 
 #[derive(Debug, Args)]
@@ -10,20 +10,41 @@ pub struct Opts {
     pub box_number: u8,
     #[arg(long)]
     pub slot: u8,
+    /// File to write the PK3 to, or a directory to write it to with a name generated from the
+    /// Pokemon
     #[arg(long)]
     pub dest: PathBuf,
+    /// Leave the Pokemon in the save file instead of removing it
+    #[arg(long)]
+    pub copy: bool,
 }
 
 pub fn run(opts: Opts) -> Result<(), Box<dyn std::error::Error>> {
     let mut save_file = SaveFile::new(&opts.sav)?;
     save_file.verify_sections()?;
 
-    match save_file.take_pokemon_from_box(opts.box_number, opts.slot)? {
+    let pokemon = if opts.copy {
+        save_file.get_pokemon_from_box(opts.box_number, opts.slot)?
+    } else {
+        save_file.take_pokemon_from_box(opts.box_number, opts.slot)?
+    };
+
+    match pokemon {
         Some(pokemon) => {
+            if let Some(item_name) = pokemon.held_item_name() {
+                println!("{} is holding {item_name}", pokemon.species);
+            }
+            let dest = if opts.dest.is_dir() {
+                opts.dest.join(pk3_filename(&pokemon))
+            } else {
+                opts.dest
+            };
             let pk3_data = pokemon.to_pk3();
-            println!("Saving to {}", opts.dest.display());
-            std::fs::write(opts.dest, pk3_data)?;
-            save_file.write_to_file(&opts.sav)?;
+            println!("Saving to {}", dest.display());
+            std::fs::write(dest, pk3_data)?;
+            if !opts.copy {
+                save_file.write_to_file(&opts.sav)?;
+            }
         }
         None => {
             println!("No Pokemon in that location!");
@@ -32,3 +53,20 @@ pub fn run(opts: Opts) -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// Names a PK3 file as `{species}_{nickname}_Lv{level}.pk3`, with any characters in the
+/// nickname which aren't safe in a filename replaced.
+pub fn pk3_filename(pokemon: &Pokemon) -> String {
+    let nickname = pokemon
+        .nickname
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    format!("{}_{nickname}_Lv{}.pk3", pokemon.species, pokemon.level())
+}
@@ -1,26 +1,26 @@
 use clap::Args;
-use pkroam::save::SaveFile;
+use pkroam::{pk3::Pokemon, save::SaveFile};
 use std::path::PathBuf;
 
 #[derive(Debug, Args)]
 pub struct Opts {
     #[arg(short, long)]
-    sav: PathBuf,
+    pub sav: PathBuf,
     #[arg(long)]
-    box_number: u8,
+    pub box_number: u8,
     #[arg(long)]
-    slot: u8,
+    pub slot: u8,
     #[arg(long)]
-    pk3: PathBuf,
+    pub pk3: PathBuf,
     #[arg(short, long)]
-    force: Option<bool>,
+    pub force: Option<bool>,
 }
 
 pub fn run(opts: Opts) -> Result<(), Box<dyn std::error::Error>> {
     let mut save_file = SaveFile::new(&opts.sav)?;
     save_file.verify_sections()?;
 
-    let pk3_data = std::fs::read(opts.pk3)?;
+    let pk3_data = encrypted_pk3(&std::fs::read(opts.pk3)?)?;
     if save_file.put_pokemon_in_box(
         opts.box_number,
         opts.slot,
@@ -35,3 +35,21 @@ pub fn run(opts: Opts) -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// Accepts PK3 data in either the on-disk encrypted form or with its data region decrypted,
+/// returning the encrypted form. Whichever form has a valid checksum is used.
+pub fn encrypted_pk3(pk3_data: &[u8]) -> std::io::Result<Vec<u8>> {
+    if Pokemon::from_pk3(pk3_data).is_ok_and(|pkmn| pkmn.checksum_is_valid()) {
+        return Ok(pk3_data.to_vec());
+    }
+    match Pokemon::from_decrypted_pk3(pk3_data) {
+        Ok(pkmn) if pkmn.checksum_is_valid() => {
+            log::info!("Encrypting decrypted PK3 data");
+            Ok(pkmn.to_pk3())
+        }
+        _ => {
+            log::error!("PK3 data has an invalid checksum in both encrypted and decrypted form");
+            Err(std::io::ErrorKind::InvalidData.into())
+        }
+    }
+}
@@ -1,36 +1,159 @@
-use clap::Args;
-use pkroam::save::SaveFile;
+use clap::{Args, ValueEnum};
+use pkroam::{
+    pk3::{items::item_name, moves::move_display_name, Pokemon},
+    save::{SaveFile, NUMBER_OF_BOXES},
+};
+use serde_json::json;
 use std::path::PathBuf;
 
 #[derive(Debug, Args)]
 pub struct Opts {
     #[arg(short, long)]
     sav: PathBuf,
-    #[arg(short, long)]
-    location: String,
+    #[arg(short, long, required_unless_present = "box_number")]
+    location: Option<String>,
+    /// Shorthand for `--location boxN`
+    #[arg(
+        long = "box",
+        conflicts_with = "location",
+        value_parser = clap::value_parser!(u8).range(1..=i64::from(NUMBER_OF_BOXES))
+    )]
+    box_number: Option<u8>,
     #[arg(long)]
     slot: Option<u8>,
+    /// Print diagnostics about the save's structure
+    #[arg(long)]
+    debug: bool,
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
 }
 
 pub fn run(opts: Opts) -> Result<(), Box<dyn std::error::Error>> {
     let save_file = SaveFile::new(opts.sav)?;
-    save_file.verify_sections()?;
+
+    let location = match opts.box_number {
+        Some(box_number) => format!("box{box_number}"),
+        None => opts.location.unwrap_or_default(),
+    };
+    if opts.format == Format::Json {
+        let box_number = location
+            .strip_prefix("box")
+            .ok_or("JSON output is only supported for boxes")?
+            .parse::<u8>()?;
+        save_file.verify_sections()?;
+        println!("{}", box_status_json(&save_file, box_number)?);
+        return Ok(());
+    }
 
     let trainer_info = save_file.get_trainer_info();
     println!("Trainer Info: {trainer_info:?}");
 
-    if opts.location == "party" {
+    if opts.debug {
+        println!("Game: {:?}", save_file.get_game_code());
+        match save_file.previous_block_game_code() {
+            Some(game_code) if !save_file.blocks_same_game() => {
+                println!("WARNING: Previous save block is from a different game: {game_code:?}")
+            }
+            Some(game_code) => println!("Previous save block game: {game_code:?}"),
+            None => println!("Previous save block is unwritten or corrupt"),
+        }
+        println!("Section checksums (id, stored, computed):");
+        for (section_id, stored_checksum, computed_checksum) in save_file.section_checksums()? {
+            let marker = if stored_checksum != computed_checksum {
+                " MISMATCH"
+            } else {
+                ""
+            };
+            println!("  {section_id:2}: 0x{stored_checksum:04x} 0x{computed_checksum:04x}{marker}");
+        }
+    }
+    save_file.verify_sections()?;
+
+    if location == "party" {
         let party_pkmn = save_file.get_party()?;
         for pkmn in party_pkmn {
-            println!("{pkmn:?}");
+            if pkmn.is_valid() {
+                println!("{pkmn:?}{}", shiny_marker(&pkmn));
+                println!("  Moves: {}", move_list(&pkmn));
+                println!("  Characteristic: {}", pkmn.characteristic());
+                println!("  {}", iv_ev_totals(&pkmn));
+            } else {
+                println!("WARNING: Bad egg");
+            }
         }
-    } else if opts.location.starts_with("box") {
-        let box_number = opts.location[3..].parse::<u8>()?;
+    } else if location == "bag" {
+        for (item_id, quantity) in save_file.get_items()? {
+            match item_name(item_id) {
+                Some(name) => println!("{name} x{quantity}"),
+                None => println!("Item #{item_id} x{quantity}"),
+            }
+        }
+    } else if let Some(box_number) = location.strip_prefix("box") {
+        let box_number = box_number.parse::<u8>()?;
         let boxed_pkmn = save_file.get_box(box_number)?;
         for (slot, pkmn) in boxed_pkmn {
-            println!("Slot {slot}: {pkmn:?}");
+            if pkmn.is_valid() {
+                println!("Slot {slot}: {pkmn:?}{}", shiny_marker(&pkmn));
+                println!("  Moves: {}", move_list(&pkmn));
+                println!("  Characteristic: {}", pkmn.characteristic());
+                println!("  {}", iv_ev_totals(&pkmn));
+            } else {
+                println!("Slot {slot}: WARNING: Bad egg");
+            }
         }
     }
 
     Ok(())
 }
+
+/// Describes every slot of a box, with `null` for empty slots.
+pub fn box_status_json(save_file: &SaveFile, box_number: u8) -> std::io::Result<serde_json::Value> {
+    if !(1..=NUMBER_OF_BOXES).contains(&box_number) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Invalid box number {box_number}, must be in [1, {NUMBER_OF_BOXES}]"),
+        ));
+    }
+    let mut slots = vec![serde_json::Value::Null; 30];
+    for (slot, pkmn) in save_file.get_box(box_number)? {
+        slots[usize::from(slot) - 1] = json!({
+            "slot": slot,
+            "species": pkmn.species.to_string(),
+            "level": pkmn.level(),
+            "shiny": pkmn.is_shiny(),
+        });
+    }
+    Ok(serde_json::Value::Array(slots))
+}
+
+fn move_list(pkmn: &Pokemon) -> String {
+    pkmn.moves
+        .iter()
+        .filter(|move_id| **move_id != 0)
+        .map(|move_id| move_display_name(*move_id))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn iv_ev_totals(pkmn: &Pokemon) -> String {
+    format!(
+        "IV total: {} ({} perfect), EV total: {}",
+        pkmn.iv_total(),
+        pkmn.perfect_iv_count(),
+        pkmn.ev_total()
+    )
+}
+
+fn shiny_marker(pkmn: &Pokemon) -> &'static str {
+    if pkmn.is_shiny() {
+        " [shiny]"
+    } else {
+        ""
+    }
+}
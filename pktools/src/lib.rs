@@ -1,3 +1,6 @@
 pub mod extract;
 pub mod insert;
 pub mod inspect;
+pub mod summary;
+pub mod verify;
+pub mod verify_dir;
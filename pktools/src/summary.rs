@@ -0,0 +1,104 @@
+use clap::Args;
+use pkroam::save::{GameCode, SaveFile};
+use prettytable::{format, row, Table};
+use std::path::PathBuf;
+
+#[derive(Debug, Args)]
+pub struct Opts {
+    pub sav: PathBuf,
+}
+
+pub fn run(opts: Opts) -> Result<(), Box<dyn std::error::Error>> {
+    let save_file = SaveFile::new(&opts.sav)?;
+    if save_file.verify_sections().is_err() {
+        println!("WARNING: Some section checksums are invalid");
+    }
+
+    summary_table(&save_file)?.printstd();
+    Ok(())
+}
+
+/// An at-a-glance overview of the trainer and their party.
+pub fn summary_table(save_file: &SaveFile) -> std::io::Result<Table> {
+    let trainer_info = save_file.get_trainer_info();
+    let resources = save_file.get_resources()?;
+    let time_played = trainer_info.time_played;
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["Game", game_name(save_file.get_game_code())]);
+    table.add_row(row![
+        "Trainer",
+        format!(
+            "{} ({:?})",
+            trainer_info.player_name, trainer_info.player_gender
+        )
+    ]);
+    table.add_row(row![
+        "ID",
+        format!(
+            "{:05} / {:05}",
+            trainer_info.id.public_id, trainer_info.id.secret_id
+        )
+    ]);
+    table.add_row(row![
+        "Playtime",
+        format!("{}:{:02}", time_played.hours, time_played.minutes)
+    ]);
+    table.add_row(row!["Money", resources.money]);
+    table.add_row(row!["Coins", resources.coins]);
+    if let Some(battle_points) = resources.battle_points {
+        table.add_row(row!["Battle Points", battle_points]);
+    }
+    table.add_row(row![
+        "Pokedex",
+        format!(
+            "{} seen, {} owned{}",
+            save_file.pokedex_seen_count()?,
+            save_file.pokedex_owned_count()?,
+            if save_file.national_dex_unlocked()? {
+                " (national)"
+            } else {
+                ""
+            }
+        )
+    ]);
+    let badges = save_file
+        .get_badges()?
+        .iter()
+        .zip(save_file.get_game_code().badge_names())
+        .filter(|(obtained, _)| **obtained)
+        .map(|(_, name)| name)
+        .collect::<Vec<_>>();
+    table.add_row(row![
+        "Badges",
+        format!("{} ({})", badges.len(), badges.join(", "))
+    ]);
+    table.add_row(row![
+        "Trainer Card",
+        format!("{} stars", save_file.trainer_card_stars()?)
+    ]);
+
+    let party = save_file
+        .get_party()?
+        .iter()
+        .map(|pkmn| {
+            if pkmn.is_valid() {
+                format!("{} Lv.{}", pkmn.species, pkmn.level())
+            } else {
+                "Bad egg".to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+    table.add_row(row!["Party", party.join("\n")]);
+
+    Ok(table)
+}
+
+fn game_name(game_code: GameCode) -> &'static str {
+    match game_code {
+        GameCode::RubySapphire => "Ruby/Sapphire",
+        GameCode::Emerald => "Emerald",
+        GameCode::FireRedLeafGreen => "FireRed/LeafGreen",
+    }
+}
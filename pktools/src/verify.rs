@@ -0,0 +1,46 @@
+use clap::Args;
+use pkroam::save::SaveFile;
+use prettytable::{format, row, Table};
+use std::path::PathBuf;
+
+#[derive(Debug, Args)]
+pub struct Opts {
+    pub sav: PathBuf,
+}
+
+pub fn run(opts: Opts) -> Result<(), Box<dyn std::error::Error>> {
+    let save_file = SaveFile::new(&opts.sav)?;
+    println!("Active save block: {:?}", save_file.latest_block());
+
+    let (table, mismatched) = verify_table(&save_file)?;
+    table.printstd();
+    if mismatched > 0 {
+        return Err(format!("{mismatched} section checksums are invalid").into());
+    }
+    Ok(())
+}
+
+/// The checksum status of each section of the latest save block, along with the number of
+/// sections which failed.
+pub fn verify_table(save_file: &SaveFile) -> std::io::Result<(Table, usize)> {
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["SECTION", "STATUS", "STORED", "COMPUTED"]);
+
+    let mut mismatched = 0;
+    for (section_id, stored_checksum, computed_checksum) in save_file.section_checksums()? {
+        let status = if stored_checksum == computed_checksum {
+            "OK"
+        } else {
+            mismatched += 1;
+            "FAIL"
+        };
+        table.add_row(row![
+            section_id,
+            status,
+            format!("0x{stored_checksum:04x}"),
+            format!("0x{computed_checksum:04x}")
+        ]);
+    }
+    Ok((table, mismatched))
+}
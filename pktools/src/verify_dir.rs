@@ -0,0 +1,41 @@
+use clap::Args;
+use pkroam::pk3::Pokemon;
+use prettytable::{format, row, Table};
+use std::path::PathBuf;
+
+#[derive(Debug, Args)]
+pub struct Opts {
+    dir: PathBuf,
+}
+
+pub fn run(opts: Opts) -> Result<(), Box<dyn std::error::Error>> {
+    let mut pk3_paths = std::fs::read_dir(&opts.dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    pk3_paths.retain(|path| {
+        path.is_file()
+            && path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("pk3"))
+    });
+    pk3_paths.sort();
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.add_row(row!["FILE", "STATUS", "DETAILS"]);
+
+    for path in pk3_paths {
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let (status, details) = match std::fs::read(&path).map(|data| Pokemon::from_pk3(&data)) {
+            Ok(Ok(pkmn)) if !pkmn.checksum_is_valid() => {
+                ("WARN", format!("{} has an invalid checksum", pkmn.species))
+            }
+            Ok(Ok(pkmn)) => ("OK", format!("{} Lv.{}", pkmn.species, pkmn.level())),
+            Ok(Err(err)) | Err(err) => ("FAIL", err.to_string()),
+        };
+        table.add_row(row![file_name, status, details]);
+    }
+
+    table.printstd();
+    Ok(())
+}
@@ -21,6 +21,7 @@ fn test_extract_pk3() {
         box_number: 1,
         slot: 1,
         dest: PathBuf::from(wurmple_output.path()),
+        copy: false,
     })
     .unwrap();
 
@@ -38,6 +39,53 @@ fn test_extract_pk3() {
     }
 }
 
+#[test]
+fn test_extract_pk3_copy() {
+    let input_save = create_temp_save(EMERALD_SAV);
+    let original_sav = std::fs::read(EMERALD_SAV).unwrap();
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    let wurmple_output = tempfile::NamedTempFile::new().unwrap();
+    extract::run(extract::Opts {
+        sav: PathBuf::from(input_save.path()),
+        box_number: 1,
+        slot: 1,
+        dest: PathBuf::from(wurmple_output.path()),
+        copy: true,
+    })
+    .unwrap();
+
+    let generated_pk3 = std::fs::read(wurmple_output.path()).unwrap();
+    assert_eq!(generated_pk3, wurmple_pk3);
+
+    let generated_sav = std::fs::read(input_save.path()).unwrap();
+    assert!(generated_sav == original_sav, "SAV file was modified");
+}
+
+#[test]
+fn test_extract_pk3_to_directory() {
+    let input_save = create_temp_save(EMERALD_SAV);
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    let dest_dir = tempfile::tempdir().unwrap();
+    extract::run(extract::Opts {
+        sav: PathBuf::from(input_save.path()),
+        box_number: 1,
+        slot: 1,
+        dest: dest_dir.path().to_path_buf(),
+        copy: true,
+    })
+    .unwrap();
+
+    let generated_pk3 = std::fs::read(dest_dir.path().join("Wurmple_WURMPLE_Lv3.pk3")).unwrap();
+    assert_eq!(generated_pk3, wurmple_pk3);
+}
+
+#[test]
+fn test_pk3_filename_sanitizes_nickname() {
+    let mut wurmple = pkroam::pk3::Pokemon::from_pk3(&std::fs::read(WURMPLE_PK3).unwrap()).unwrap();
+    wurmple.set_nickname("a/b.c?").unwrap();
+    assert_eq!(extract::pk3_filename(&wurmple), "Wurmple_a_b_c__Lv3.pk3");
+}
+
 fn create_temp_save(save_path: impl AsRef<Path>) -> NamedTempFile {
     let mut save_file = std::fs::File::open(save_path).unwrap();
     let mut save_data = Vec::new();
@@ -0,0 +1,44 @@
+use pkroam::save::SaveFile;
+use pktools::insert;
+use std::path::PathBuf;
+
+const EMERALD_SAV: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/", "emerald.sav");
+const WURMPLE_PK3: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/", "wurmple.pk3");
+const WURMPLE_DECRYPTED_PK3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/data/",
+    "wurmple-decrypted.pk3"
+);
+
+#[test]
+fn test_encrypted_pk3_detection() {
+    let wurmple_pk3 = std::fs::read(WURMPLE_PK3).unwrap();
+    let decrypted_pk3 = std::fs::read(WURMPLE_DECRYPTED_PK3).unwrap();
+    assert_ne!(wurmple_pk3, decrypted_pk3);
+
+    assert_eq!(insert::encrypted_pk3(&wurmple_pk3).unwrap(), wurmple_pk3);
+    assert_eq!(insert::encrypted_pk3(&decrypted_pk3).unwrap(), wurmple_pk3);
+    assert!(insert::encrypted_pk3(&[0xaa; 80]).is_err());
+}
+
+#[test]
+fn test_insert_decrypted_pk3() {
+    let dir = tempfile::tempdir().unwrap();
+    let sav = dir.path().join("emerald.sav");
+    std::fs::copy(EMERALD_SAV, &sav).unwrap();
+
+    insert::run(insert::Opts {
+        sav: sav.clone(),
+        box_number: 1,
+        slot: 5,
+        pk3: PathBuf::from(WURMPLE_DECRYPTED_PK3),
+        force: None,
+    })
+    .unwrap();
+
+    let save_file = SaveFile::new(&sav).unwrap();
+    let (slot, pkmn) = save_file.get_box(1).unwrap().pop().unwrap();
+    assert_eq!(slot, 5);
+    assert!(pkmn.checksum_is_valid());
+    assert_eq!(pkmn.to_pk3(), std::fs::read(WURMPLE_PK3).unwrap());
+}
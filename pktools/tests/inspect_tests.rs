@@ -0,0 +1,40 @@
+use pkroam::save::SaveFile;
+use pktools::inspect;
+
+const EMERALD_SAV: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/", "emerald.sav");
+
+#[test]
+fn test_box_status_json() {
+    let save_file = SaveFile::new(EMERALD_SAV).unwrap();
+    let status = inspect::box_status_json(&save_file, 1).unwrap();
+    let slots = status.as_array().unwrap();
+    assert_eq!(slots.len(), 30);
+    assert_eq!(
+        slots[0],
+        serde_json::json!({
+            "slot": 1,
+            "species": "Wurmple",
+            "level": 3,
+            "shiny": false,
+        })
+    );
+    let species = slots
+        .iter()
+        .filter_map(|slot| slot.get("species"))
+        .collect::<Vec<_>>();
+    assert_eq!(species, ["Wurmple", "Whismur", "Lotad", "Nincada"]);
+    assert!(slots[4..].iter().all(|slot| slot.is_null()));
+}
+
+#[test]
+fn test_box_status_json_invalid_box() {
+    let save_file = SaveFile::new(EMERALD_SAV).unwrap();
+    for box_number in [0, 15, 16] {
+        let err = inspect::box_status_json(&save_file, box_number).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            format!("Invalid box number {box_number}, must be in [1, 14]")
+        );
+    }
+}
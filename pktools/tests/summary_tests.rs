@@ -0,0 +1,19 @@
+use pkroam::save::SaveFile;
+use pktools::summary;
+
+const EMERALD_SAV: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/", "emerald.sav");
+
+#[test]
+fn test_summary_table() {
+    let save_file = SaveFile::new(EMERALD_SAV).unwrap();
+    let table = summary::summary_table(&save_file).unwrap();
+    let summary = table.to_string();
+
+    assert!(summary.contains("Shane (Male)"));
+    assert!(summary.contains("04437 / 28113"));
+    assert!(summary.contains("22045"));
+    assert!(summary.contains("45 seen, 12 owned"));
+    assert!(summary.contains("2 (Stone, Knuckle)"));
+    assert!(summary.contains("Marshtomp Lv.26"));
+    assert!(summary.contains("Shroomish Lv.5"));
+}
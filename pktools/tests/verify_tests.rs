@@ -0,0 +1,30 @@
+use pkroam::save::{SaveBlock, SaveFile};
+use pktools::verify;
+use std::path::PathBuf;
+
+const EMERALD_SAV: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/", "emerald.sav");
+
+#[test]
+fn test_verify() {
+    let save_file = SaveFile::new(EMERALD_SAV).unwrap();
+    assert_eq!(save_file.latest_block(), SaveBlock::A);
+    let (table, mismatched) = verify::verify_table(&save_file).unwrap();
+    assert_eq!(mismatched, 0);
+    assert_eq!(table.len(), 15);
+    verify::run(verify::Opts {
+        sav: PathBuf::from(EMERALD_SAV),
+    })
+    .unwrap();
+
+    // Section 0 of the latest block is at 0x8000 in this fixture
+    let mut save_data = std::fs::read(EMERALD_SAV).unwrap();
+    save_data[0x8000 + 0x20] ^= 0xff;
+    let corrupt_save = tempfile::NamedTempFile::new().unwrap().into_temp_path();
+    std::fs::write(&corrupt_save, save_data).unwrap();
+    let (_, mismatched) = verify::verify_table(&SaveFile::new(&corrupt_save).unwrap()).unwrap();
+    assert_eq!(mismatched, 1);
+    assert!(verify::run(verify::Opts {
+        sav: corrupt_save.to_path_buf(),
+    })
+    .is_err());
+}